@@ -0,0 +1,191 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::Duration;
+use std::time::Instant;
+
+use clap::Parser;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_log::set_log_level;
+use iceoryx2_tunnels_zenoh::EventBatching;
+use iceoryx2_tunnels_zenoh::Scope;
+use iceoryx2_tunnels_zenoh::Tunnel;
+use iceoryx2_tunnels_zenoh::TunnelConfig;
+
+const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn isolated_config(prefix: &str) -> Result<Config, Box<dyn core::error::Error>> {
+    let mut config = Config::default();
+    config.global.prefix = FileName::new(prefix.as_bytes())?;
+    Ok(config)
+}
+
+fn perform_benchmark<T: Service>(
+    args: &Args,
+    batching: Option<EventBatching>,
+) -> Result<(), Box<dyn core::error::Error>> {
+    let service_name = ServiceName::new("a2b")?;
+    let z_config = zenoh::Config::default();
+
+    // host a notifies locally, its tunnel propagates the events to host b
+    let iox_config_a = isolated_config("benchmark_tunnel_event_a_")?;
+    let tunnel_config_a = TunnelConfig {
+        event_batching: batching,
+        ..Default::default()
+    };
+    let mut tunnel_a = Tunnel::<T>::create(&tunnel_config_a, &iox_config_a, &z_config)?;
+    let node_a = NodeBuilder::new().config(&iox_config_a).create::<T>()?;
+    let service_a = node_a
+        .service_builder(&service_name)
+        .event()
+        .event_id_max_value(args.max_event_id)
+        .open_or_create()?;
+    let notifier_a = service_a.notifier_builder().create()?;
+    tunnel_a.discover(Scope::Iceoryx)?;
+
+    // host b receives the events from its tunnel
+    let iox_config_b = isolated_config("benchmark_tunnel_event_b_")?;
+    let mut tunnel_b = Tunnel::<T>::create(&TunnelConfig::default(), &iox_config_b, &z_config)?;
+    let start = Instant::now();
+    while tunnel_b.num_total_event_connections() == 0 {
+        if start.elapsed() > DISCOVERY_TIMEOUT {
+            return Err("failed to discover the service of host a".into());
+        }
+        tunnel_b.discover(Scope::Zenoh)?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let node_b = NodeBuilder::new().config(&iox_config_b).create::<T>()?;
+    let service_b = node_b
+        .service_builder(&service_name)
+        .event()
+        .event_id_max_value(args.max_event_id)
+        .open_or_create()?;
+    let listener_b = service_b.listener_builder().create()?;
+
+    // wait until the zenoh entities of both tunnels are matched
+    let start = Instant::now();
+    loop {
+        if start.elapsed() > DISCOVERY_TIMEOUT {
+            return Err("failed to propagate an event to host b".into());
+        }
+        notifier_a.notify()?;
+        if let Some(batching) = batching {
+            std::thread::sleep(batching.batch_timeout);
+        }
+        tunnel_a.propagate();
+        tunnel_b.propagate();
+        if listener_b
+            .timed_wait_one(Duration::from_millis(10))?
+            .is_some()
+        {
+            break;
+        }
+    }
+    listener_b.try_wait_all(|_| {})?;
+
+    let mut received = 0;
+    let start = Instant::now();
+    for n in 0..args.iterations {
+        notifier_a.notify_with_custom_event_id(EventId::new(n % (args.max_event_id + 1)))?;
+        tunnel_a.propagate();
+        tunnel_b.propagate();
+        listener_b.try_wait_all(|_| received += 1)?;
+    }
+    let stop = start.elapsed();
+
+    println!(
+        "{} ::: {}, Iterations: {}, Received: {}, Time: {} s, Throughput: {:.0} events/s",
+        core::any::type_name::<T>(),
+        match batching {
+            Some(batching) => format!(
+                "Batched (size: {}, timeout: {} us)",
+                batching.batch_size,
+                batching.batch_timeout.as_micros()
+            ),
+            None => String::from("Single"),
+        },
+        args.iterations,
+        received,
+        stop.as_secs_f64(),
+        args.iterations as f64 / stop.as_secs_f64()
+    );
+
+    Ok(())
+}
+
+const ITERATIONS: usize = 100000;
+const EVENT_ID_MAX_VALUE: usize = 128;
+const BATCH_SIZE: usize = 64;
+const BATCH_TIMEOUT_IN_US: u64 = 1000;
+
+#[derive(Parser, Debug)]
+#[clap(version, about, long_about = None)]
+struct Args {
+    /// Number of events propagated from host A to host B
+    #[clap(short, long, default_value_t = ITERATIONS)]
+    iterations: usize,
+    /// Run benchmark for every setup
+    #[clap(short, long)]
+    bench_all: bool,
+    /// Run benchmark for propagating every event on its own
+    #[clap(long)]
+    bench_single: bool,
+    /// Run benchmark for propagating the events in batches
+    #[clap(long)]
+    bench_batched: bool,
+    /// The number of distinct events after which a batch is propagated
+    #[clap(long, default_value_t = BATCH_SIZE)]
+    batch_size: usize,
+    /// The maximum duration in microseconds the first event of a batch waits to be propagated
+    #[clap(long, default_value_t = BATCH_TIMEOUT_IN_US)]
+    batch_timeout_in_us: u64,
+    /// The greatest supported EventId
+    #[clap(short, long, default_value_t = EVENT_ID_MAX_VALUE)]
+    max_event_id: usize,
+    /// Activate full log output
+    #[clap(short, long)]
+    debug_mode: bool,
+}
+
+fn main() -> Result<(), Box<dyn core::error::Error>> {
+    let args = Args::parse();
+
+    if args.debug_mode {
+        set_log_level(iceoryx2_bb_log::LogLevel::Trace);
+    } else {
+        set_log_level(iceoryx2_bb_log::LogLevel::Error);
+    }
+
+    let mut at_least_one_benchmark_did_run = false;
+
+    if args.bench_single || args.bench_all {
+        perform_benchmark::<ipc::Service>(&args, None)?;
+        at_least_one_benchmark_did_run = true;
+    }
+
+    if args.bench_batched || args.bench_all {
+        let batching = EventBatching {
+            batch_size: args.batch_size,
+            batch_timeout: Duration::from_micros(args.batch_timeout_in_us),
+        };
+        perform_benchmark::<ipc::Service>(&args, Some(batching))?;
+        at_least_one_benchmark_did_run = true;
+    }
+
+    if !at_least_one_benchmark_did_run {
+        println!(
+            "Please use either '--bench-all' or select a specific benchmark. See `--help` for details."
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,296 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use clap::Parser;
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_log::set_log_level;
+#[cfg(feature = "compression")]
+use iceoryx2_tunnels_zenoh::CompressionCodec;
+use iceoryx2_tunnels_zenoh::Scope;
+use iceoryx2_tunnels_zenoh::Tunnel;
+use iceoryx2_tunnels_zenoh::TunnelConfig;
+use zenoh::Wait;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn isolated_config(prefix: &str) -> Result<Config, Box<dyn core::error::Error>> {
+    let mut config = Config::default();
+    config.global.prefix = FileName::new(prefix.as_bytes())?;
+    Ok(config)
+}
+
+fn send<T: Service>(
+    publisher: &Publisher<T, [u8], ()>,
+    payload: &[u8],
+) -> Result<(), Box<dyn core::error::Error>> {
+    let sample = publisher.loan_slice_uninit(payload.len())?;
+    sample.write_from_slice(payload).send()?;
+    Ok(())
+}
+
+#[cfg(feature = "compression")]
+fn compressed_tunnel_config(codec: CompressionCodec) -> TunnelConfig {
+    TunnelConfig {
+        compression: true,
+        compression_codec: codec,
+        ..Default::default()
+    }
+}
+
+fn perform_benchmark<T: Service>(
+    args: &Args,
+    tunnel_config: &TunnelConfig,
+    codec: &str,
+) -> Result<(), Box<dyn core::error::Error>> {
+    let service_name_a2b = ServiceName::new("a2b")?;
+    let service_name_b2a = ServiceName::new("b2a")?;
+    let z_config = zenoh::Config::default();
+
+    // slowly varying bytes, compressible like the samples of a sensor
+    let payload: Vec<u8> = (0..args.payload_size)
+        .map(|n| ((n / 64) ^ (n % 7)) as u8)
+        .collect();
+
+    // host a publishes on a2b, its tunnel propagates the payloads to host b
+    let iox_config_a = isolated_config("benchmark_tunnel_publish_subscribe_a_")?;
+    let mut tunnel_a = Tunnel::<T>::create(tunnel_config, &iox_config_a, &z_config)?;
+    let node_a = NodeBuilder::new().config(&iox_config_a).create::<T>()?;
+    let service_a2b_a = node_a
+        .service_builder(&service_name_a2b)
+        .publish_subscribe::<[u8]>()
+        .open_or_create()?;
+    let publisher_a = service_a2b_a
+        .publisher_builder()
+        .initial_max_slice_len(args.payload_size)
+        .create()?;
+    tunnel_a.discover(Scope::Iceoryx)?;
+
+    // host b receives the payloads and sends them back on b2a
+    let iox_config_b = isolated_config("benchmark_tunnel_publish_subscribe_b_")?;
+    let mut tunnel_b = Tunnel::<T>::create(tunnel_config, &iox_config_b, &z_config)?;
+    let start = Instant::now();
+    while tunnel_b.num_total_publish_subscribe_connections() == 0 {
+        if start.elapsed() > TIMEOUT {
+            return Err("failed to discover the service of host a".into());
+        }
+        tunnel_b.discover(Scope::Zenoh)?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let node_b = NodeBuilder::new().config(&iox_config_b).create::<T>()?;
+    let service_a2b_b = node_b
+        .service_builder(&service_name_a2b)
+        .publish_subscribe::<[u8]>()
+        .open_or_create()?;
+    let subscriber_b = service_a2b_b.subscriber_builder().create()?;
+    let service_b2a_b = node_b
+        .service_builder(&service_name_b2a)
+        .publish_subscribe::<[u8]>()
+        .open_or_create()?;
+    let publisher_b = service_b2a_b
+        .publisher_builder()
+        .initial_max_slice_len(args.payload_size)
+        .create()?;
+    tunnel_b.discover(Scope::Iceoryx)?;
+
+    let start = Instant::now();
+    while tunnel_a.num_total_publish_subscribe_connections() < 2 {
+        if start.elapsed() > TIMEOUT {
+            return Err("failed to discover the service of host b".into());
+        }
+        tunnel_a.discover(Scope::Zenoh)?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let service_b2a_a = node_a
+        .service_builder(&service_name_b2a)
+        .publish_subscribe::<[u8]>()
+        .open_or_create()?;
+    let subscriber_a = service_b2a_a.subscriber_builder().create()?;
+
+    // wait until the zenoh entities of both tunnels are matched in both directions
+    let start = Instant::now();
+    loop {
+        if start.elapsed() > TIMEOUT {
+            return Err("failed to propagate a payload to host b".into());
+        }
+        send(&publisher_a, &payload)?;
+        tunnel_a.propagate();
+        tunnel_b.propagate();
+        std::thread::sleep(Duration::from_millis(10));
+        if subscriber_b.receive()?.is_some() {
+            break;
+        }
+    }
+    while subscriber_b.receive()?.is_some() {}
+
+    let start = Instant::now();
+    loop {
+        if start.elapsed() > TIMEOUT {
+            return Err("failed to propagate a payload to host a".into());
+        }
+        send(&publisher_b, &payload)?;
+        tunnel_b.propagate();
+        tunnel_a.propagate();
+        std::thread::sleep(Duration::from_millis(10));
+        if subscriber_a.receive()?.is_some() {
+            break;
+        }
+    }
+    while subscriber_a.receive()?.is_some() {}
+
+    // measure the size of the payloads on zenoh
+    let z_bytes = Arc::new(AtomicU64::new(0));
+    let z_session = zenoh::open(z_config.clone()).wait()?;
+    let _z_subscriber = {
+        let z_bytes = z_bytes.clone();
+        z_session
+            .declare_subscriber("iox2/services/*/publish_subscribe")
+            .callback(move |sample| {
+                z_bytes.fetch_add(sample.payload().len() as u64, Ordering::Relaxed);
+            })
+            .wait()?
+    };
+
+    let start = Instant::now();
+    for _ in 0..args.iterations {
+        send(&publisher_a, &payload)?;
+        let sent = Instant::now();
+        loop {
+            tunnel_a.propagate();
+            tunnel_b.propagate();
+            if let Some(sample) = subscriber_b.receive()? {
+                send(&publisher_b, sample.payload())?;
+                break;
+            }
+            if sent.elapsed() > TIMEOUT {
+                return Err("lost a payload on the way to host b".into());
+            }
+        }
+
+        let sent = Instant::now();
+        loop {
+            tunnel_b.propagate();
+            tunnel_a.propagate();
+            if subscriber_a.receive()?.is_some() {
+                break;
+            }
+            if sent.elapsed() > TIMEOUT {
+                return Err("lost a payload on the way to host a".into());
+            }
+        }
+    }
+    let stop = start.elapsed();
+
+    // the subscriber on zenoh may lag behind the tunnels
+    std::thread::sleep(Duration::from_millis(100));
+    let bytes = 2 * args.iterations as u64 * args.payload_size as u64;
+    let z_bytes = z_bytes.load(Ordering::Relaxed);
+
+    println!(
+        "{} ::: {}, Iterations: {}, Payload: {} bytes, Time: {} s, Throughput: {:.2} round trips/s ({:.2} MB/s), Compression Ratio: {:.2}",
+        core::any::type_name::<T>(),
+        codec,
+        args.iterations,
+        args.payload_size,
+        stop.as_secs_f64(),
+        args.iterations as f64 / stop.as_secs_f64(),
+        bytes as f64 / stop.as_secs_f64() / 1_000_000.0,
+        bytes as f64 / z_bytes.max(1) as f64
+    );
+
+    Ok(())
+}
+
+const ITERATIONS: usize = 100;
+const PAYLOAD_SIZE: usize = 1_000_000;
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 3;
+
+#[derive(Parser, Debug)]
+#[clap(version, about, long_about = None)]
+struct Args {
+    /// Number of round trips between host A and host B
+    #[clap(short, long, default_value_t = ITERATIONS)]
+    iterations: usize,
+    /// Size of the payloads in bytes
+    #[clap(short, long, default_value_t = PAYLOAD_SIZE)]
+    payload_size: usize,
+    /// Run benchmark for every codec
+    #[clap(short, long)]
+    bench_all: bool,
+    /// Run benchmark for propagating the payloads uncompressed
+    #[clap(long)]
+    bench_none: bool,
+    /// Run benchmark for compressing the payloads with LZ4
+    #[cfg(feature = "compression")]
+    #[clap(long)]
+    bench_lz4: bool,
+    /// Run benchmark for compressing the payloads with Zstandard
+    #[cfg(feature = "zstd")]
+    #[clap(long)]
+    bench_zstd: bool,
+    /// The Zstandard compression level
+    #[cfg(feature = "zstd")]
+    #[clap(long, default_value_t = ZSTD_LEVEL)]
+    zstd_level: i32,
+    /// Activate full log output
+    #[clap(short, long)]
+    debug_mode: bool,
+}
+
+fn main() -> Result<(), Box<dyn core::error::Error>> {
+    let args = Args::parse();
+
+    if args.debug_mode {
+        set_log_level(iceoryx2_bb_log::LogLevel::Trace);
+    } else {
+        set_log_level(iceoryx2_bb_log::LogLevel::Error);
+    }
+
+    let mut at_least_one_benchmark_did_run = false;
+
+    if args.bench_none || args.bench_all {
+        perform_benchmark::<ipc::Service>(&args, &TunnelConfig::default(), "None")?;
+        at_least_one_benchmark_did_run = true;
+    }
+
+    #[cfg(feature = "compression")]
+    if args.bench_lz4 || args.bench_all {
+        let tunnel_config = compressed_tunnel_config(CompressionCodec::Lz4);
+        perform_benchmark::<ipc::Service>(&args, &tunnel_config, "Lz4")?;
+        at_least_one_benchmark_did_run = true;
+    }
+
+    #[cfg(feature = "zstd")]
+    if args.bench_zstd || args.bench_all {
+        let codec = CompressionCodec::Zstd {
+            level: args.zstd_level,
+        };
+        let tunnel_config = compressed_tunnel_config(codec);
+        perform_benchmark::<ipc::Service>(&args, &tunnel_config, &format!("{codec:?}"))?;
+        at_least_one_benchmark_did_run = true;
+    }
+
+    if !at_least_one_benchmark_did_run {
+        println!(
+            "Please use either '--bench-all' or select a specific benchmark. See `--help` for details."
+        );
+    }
+
+    Ok(())
+}
@@ -74,6 +74,7 @@ mod supported_platform {
                 Transport::Zenoh(_zenoh_options) => {
                     let tunnel_config = TunnelConfig {
                         discovery_service: cli.discovery_service,
+                        ..Default::default()
                     };
                     let iox_config = iceoryx2::config::Config::default();
                     let zenoh_config = zenoh::Config::default();
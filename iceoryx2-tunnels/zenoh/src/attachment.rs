@@ -0,0 +1,64 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+
+/// Identifies the iceoryx2 service a payload propagated to zenoh originates from.
+///
+/// The tunnel attaches it to every publish-subscribe payload it publishes on zenoh as a JSON
+/// object, e.g. `{"service_id":"...","service_name":"My/Service"}`, so that zenoh-native
+/// subscribers can inspect the origin of a payload without the iceoryx2 stack.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ServiceAttachment {
+    /// The name of the originating service.
+    pub service_name: String,
+    /// The id of the originating service.
+    pub service_id: String,
+}
+
+impl ServiceAttachment {
+    /// Creates the attachment identifying the provided service.
+    pub fn new(iox_service_config: &IceoryxServiceConfig) -> Self {
+        Self {
+            service_name: iox_service_config.name().to_string(),
+            service_id: iox_service_config.service_id().as_str().to_string(),
+        }
+    }
+
+    /// Serializes the attachment into the JSON format attached to the zenoh payloads.
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "service_id": self.service_id,
+            "service_name": self.service_name,
+        })
+        .to_string()
+    }
+
+    /// Deserializes the attachment of a zenoh payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The attachment of the zenoh payload
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ServiceAttachment)` - The origin of the payload
+    /// * `None` - If the attachment does not identify an iceoryx2 service
+    pub fn from_json(json: &[u8]) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_slice(json).ok()?;
+
+        Some(Self {
+            service_name: value.get("service_name")?.as_str()?.to_string(),
+            service_id: value.get("service_id")?.as_str()?.to_string(),
+        })
+    }
+}
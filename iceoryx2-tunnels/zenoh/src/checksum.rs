@@ -0,0 +1,95 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Publish-subscribe payloads on zenoh can be suffixed with a little-endian checksum over the
+//! preceding bytes to detect payloads corrupted in transport.
+
+use core::hash::Hasher;
+
+/// The reflected polynomial of CRC-32C (Castagnoli).
+const CRC32C_POLYNOMIAL: u32 = 0x82f6_3b78;
+
+/// The CRC-32C of every byte value, to compute the checksum bytewise instead of bitwise.
+const CRC32C_TABLE: [u32; 256] = crc32c_table();
+
+/// The algorithms the checksums of publish-subscribe payloads are computed with, see
+/// [`TunnelConfig::checksum_verification`](crate::TunnelConfig::checksum_verification).
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChecksumAlgorithm {
+    /// CRC-32C (Castagnoli), a 4 byte checksum.
+    Crc32c,
+    /// XXH64, an 8 byte checksum.
+    Xxhash,
+}
+
+impl ChecksumAlgorithm {
+    /// The number of bytes the checksum adds to each payload.
+    pub fn size(&self) -> usize {
+        match self {
+            ChecksumAlgorithm::Crc32c => core::mem::size_of::<u32>(),
+            ChecksumAlgorithm::Xxhash => core::mem::size_of::<u64>(),
+        }
+    }
+
+    fn checksum(&self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            ChecksumAlgorithm::Crc32c => crc32c(bytes).to_le_bytes().to_vec(),
+            ChecksumAlgorithm::Xxhash => {
+                let mut hasher = twox_hash::XxHash64::with_seed(0);
+                hasher.write(bytes);
+                hasher.finish().to_le_bytes().to_vec()
+            }
+        }
+    }
+}
+
+/// Appends the checksum over the provided payload to it.
+pub(crate) fn append(algorithm: ChecksumAlgorithm, mut bytes: Vec<u8>) -> Vec<u8> {
+    let checksum = algorithm.checksum(&bytes);
+    bytes.extend_from_slice(&checksum);
+    bytes
+}
+
+/// Returns whether the checksum suffixed to the provided payload matches the preceding bytes.
+pub(crate) fn verify(algorithm: ChecksumAlgorithm, bytes: &[u8]) -> bool {
+    match bytes.len().checked_sub(algorithm.size()) {
+        Some(len) => {
+            let (payload, checksum) = bytes.split_at(len);
+            algorithm.checksum(payload) == checksum
+        }
+        None => false,
+    }
+}
+
+fn crc32c(bytes: &[u8]) -> u32 {
+    let crc = bytes.iter().fold(!0u32, |crc, byte| {
+        CRC32C_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    });
+    !crc
+}
+
+const fn crc32c_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut byte = 0;
+    while byte < table.len() {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = (crc >> 1) ^ (CRC32C_POLYNOMIAL & (crc & 1).wrapping_neg());
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
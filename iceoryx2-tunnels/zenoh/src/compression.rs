@@ -0,0 +1,158 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Publish-subscribe payloads on zenoh are prefixed with a flag byte that indicates whether
+//! and with which codec the remaining bytes are compressed.
+
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+
+use std::borrow::Cow;
+#[cfg(feature = "zstd")]
+use std::io::Read;
+
+/// The payload size in bytes above which payloads are compressed when compression is
+/// enabled via [`TunnelConfig::compression`](crate::TunnelConfig::compression).
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 64;
+
+/// The maximum size in bytes a payload of a service with a dynamic payload type, e.g. a
+/// slice, may decompress to, since iceoryx2 does not bound the size of such payloads.
+const MAX_DYNAMIC_PAYLOAD_SIZE: usize = 256 * 1024 * 1024;
+
+/// Flags a payload that is forwarded as-is.
+const UNCOMPRESSED: u8 = 0;
+/// Flags a payload that is compressed with LZ4, prepended by its uncompressed size.
+const LZ4: u8 = 1;
+/// Flags a payload that is compressed with Zstandard.
+#[cfg(feature = "zstd")]
+const ZSTD: u8 = 2;
+
+/// The codec the publish-subscribe payloads of a connection are compressed with.
+///
+/// The receiving tunnel detects the codec from the payload, payloads compressed with a codec
+/// that the receiving tunnel was built without are dropped.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompressionCodec {
+    /// The payloads are propagated as-is.
+    None,
+    /// The LZ4 block format, prepended by the uncompressed size. Fast with a moderate
+    /// compression ratio.
+    #[default]
+    Lz4,
+    /// The Zstandard format. Slower than LZ4 with a higher compression ratio, which grows
+    /// with the level.
+    #[cfg(feature = "zstd")]
+    Zstd {
+        /// The compression level, ranging from 1 to 22.
+        level: i32,
+    },
+}
+
+/// The compression applied to the connections of a tunnel with
+/// [`Tunnel::compress_all_connections()`](crate::Tunnel::compress_all_connections).
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct CompressionConfig {
+    /// The codec the payloads are compressed with.
+    pub codec: CompressionCodec,
+    /// The payload size in bytes above which payloads are compressed.
+    pub min_size_bytes: usize,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            codec: CompressionCodec::default(),
+            min_size_bytes: DEFAULT_COMPRESSION_THRESHOLD,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum DecompressionError {
+    MissingFlag,
+    UnknownFlag,
+    CorruptPayload,
+    PayloadTooLarge,
+}
+
+/// Returns the maximum size in bytes a payload of the provided type may decompress to.
+pub(crate) fn max_decompressed_size(payload_type: &TypeDetail) -> usize {
+    match payload_type.variant {
+        TypeVariant::FixedSize => payload_type.size,
+        TypeVariant::Dynamic => MAX_DYNAMIC_PAYLOAD_SIZE,
+    }
+}
+
+/// Encodes a payload for zenoh, compressing it with the provided codec if it exceeds the
+/// provided threshold. Payloads are never compressed if no threshold is provided.
+pub(crate) fn compress(bytes: &[u8], codec: CompressionCodec, threshold: Option<usize>) -> Vec<u8> {
+    match (codec, threshold) {
+        (CompressionCodec::Lz4, Some(threshold)) if bytes.len() > threshold => {
+            encode(LZ4, &lz4_flex::compress_prepend_size(bytes))
+        }
+        #[cfg(feature = "zstd")]
+        (CompressionCodec::Zstd { level }, Some(threshold)) if bytes.len() > threshold => {
+            match zstd::bulk::compress(bytes, level) {
+                Ok(compressed) => encode(ZSTD, &compressed),
+                Err(_) => encode(UNCOMPRESSED, bytes),
+            }
+        }
+        _ => encode(UNCOMPRESSED, bytes),
+    }
+}
+
+fn encode(flag: u8, payload: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(payload.len() + 1);
+    encoded.push(flag);
+    encoded.extend_from_slice(payload);
+    encoded
+}
+
+/// Decodes a payload received from zenoh. Payloads that would decompress to more than
+/// `max_size` bytes are rejected without decompressing them.
+pub(crate) fn decompress(
+    bytes: &[u8],
+    max_size: usize,
+) -> Result<Cow<'_, [u8]>, DecompressionError> {
+    match bytes.split_first() {
+        Some((&UNCOMPRESSED, payload)) => Ok(Cow::Borrowed(payload)),
+        Some((&LZ4, payload)) => {
+            // The size is prepended by the remote host and must not be trusted
+            let (size, payload) = lz4_flex::block::uncompressed_size(payload)
+                .map_err(|_e| DecompressionError::CorruptPayload)?;
+            if size > max_size {
+                return Err(DecompressionError::PayloadTooLarge);
+            }
+            lz4_flex::decompress(payload, size)
+                .map(Cow::Owned)
+                .map_err(|_e| DecompressionError::CorruptPayload)
+        }
+        #[cfg(feature = "zstd")]
+        Some((&ZSTD, payload)) => {
+            // The frame is decoded incrementally to stop once it exceeds the maximum size
+            let decoder = zstd::stream::read::Decoder::new(payload)
+                .map_err(|_e| DecompressionError::CorruptPayload)?;
+            let mut decompressed = Vec::new();
+            decoder
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut decompressed)
+                .map_err(|_e| DecompressionError::CorruptPayload)?;
+            if decompressed.len() > max_size {
+                return Err(DecompressionError::PayloadTooLarge);
+            }
+            Ok(Cow::Owned(decompressed))
+        }
+        Some(_) => Err(DecompressionError::UnknownFlag),
+        None => Err(DecompressionError::MissingFlag),
+    }
+}
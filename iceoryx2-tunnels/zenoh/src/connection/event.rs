@@ -10,14 +10,24 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use super::z_is_keep_alive;
+use super::z_put_all;
 use super::Connection;
+use super::ConnectionHealth;
+use super::ConnectionStatistics;
+use super::ConnectionValidation;
+use super::FailureTracker;
+use super::KeepAlive;
 use super::PropagationError;
+use super::SaturationTracker;
+use super::TunneledConnection;
 use crate::iox_create_event_service;
 use crate::iox_create_listener;
 use crate::iox_create_notifier;
 use crate::z_announce_service;
 use crate::z_create_listener;
 use crate::z_create_notifier;
+use crate::PublisherOptions;
 
 use iceoryx2::node::Node as IceoryxNode;
 use iceoryx2::port::listener::Listener as IceoryxListener;
@@ -25,16 +35,120 @@ use iceoryx2::port::notifier::Notifier as IceoryxNotifier;
 use iceoryx2::prelude::EventId;
 use iceoryx2::service::port_factory::event::PortFactory as IceoryxEventService;
 use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::error;
 use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
 
+use zenoh::bytes::ZBytes;
 use zenoh::handlers::FifoChannelHandler;
 use zenoh::pubsub::Publisher as ZenohPublisher;
 use zenoh::pubsub::Subscriber as ZenohSubscriber;
+use zenoh::sample::Locality;
 use zenoh::sample::Sample;
 use zenoh::Session as ZenohSession;
 use zenoh::Wait;
 
 use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// The maximum number of propagation timestamps retained to determine the event frequency.
+const EVENT_HISTORY_CAPACITY: usize = 1024;
+
+/// The heap bytes allocated for the event history of a connection at full capacity.
+pub(crate) const EVENT_HISTORY_BYTES: usize =
+    EVENT_HISTORY_CAPACITY * core::mem::size_of::<(Instant, usize)>();
+
+/// The size of the number of event ids that prefixes a batch of events on zenoh.
+const EVENT_BATCH_LENGTH_BYTES: usize = core::mem::size_of::<u32>();
+
+/// Determines when the local events of a connection that are collected in a batch are
+/// propagated to remote hosts as a single zenoh payload.
+///
+/// The batch is propagated once it holds `batch_size` distinct events or `batch_timeout` has
+/// elapsed since its first event, whichever comes first. As batches are only checked while
+/// propagating, the events of a batch may be delayed by up to one propagation interval
+/// beyond the timeout.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventBatching {
+    /// The number of distinct events after which a batch is propagated.
+    pub batch_size: usize,
+    /// The maximum duration the first event of a batch waits to be propagated.
+    pub batch_timeout: Duration,
+}
+
+/// The local events collected to be propagated as one batch.
+#[derive(Debug, Default)]
+struct PendingEventBatch {
+    ids: Vec<usize>,
+    started_at: Option<Instant>,
+}
+
+impl PendingEventBatch {
+    fn push(&mut self, id: usize) {
+        if !self.ids.contains(&id) {
+            self.ids.push(id);
+        }
+        self.started_at.get_or_insert_with(Instant::now);
+    }
+
+    fn is_due(&self, batching: &EventBatching) -> bool {
+        self.ids.len() >= batching.batch_size
+            || self
+                .started_at
+                .is_some_and(|started_at| started_at.elapsed() >= batching.batch_timeout)
+    }
+
+    /// Returns the duration until the batch is due because of its timeout, `None` if the
+    /// batch is empty.
+    fn due_in(&self, batching: &EventBatching) -> Option<Duration> {
+        self.started_at
+            .map(|started_at| batching.batch_timeout.saturating_sub(started_at.elapsed()))
+    }
+
+    fn take(&mut self) -> Vec<usize> {
+        self.started_at = None;
+        core::mem::take(&mut self.ids)
+    }
+}
+
+/// Encodes a batch of event ids as one zenoh payload, prefixed with the number of ids.
+fn encode_event_batch(ids: &[usize]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(EVENT_BATCH_LENGTH_BYTES + core::mem::size_of_val(ids));
+    payload.extend_from_slice(&(ids.len() as u32).to_ne_bytes());
+    for id in ids {
+        payload.extend_from_slice(&id.to_ne_bytes());
+    }
+
+    payload
+}
+
+/// Decodes the event ids of a zenoh payload, which is either a single event id or a batch
+/// of event ids. Returns `None` if the payload is neither.
+fn decode_event_ids(payload: &[u8]) -> Option<Vec<usize>> {
+    const ID_BYTES: usize = core::mem::size_of::<usize>();
+
+    if let Ok(id) = <[u8; ID_BYTES]>::try_from(payload) {
+        return Some(vec![usize::from_ne_bytes(id)]);
+    }
+
+    if payload.len() < EVENT_BATCH_LENGTH_BYTES {
+        return None;
+    }
+    let (length, ids) = payload.split_at(EVENT_BATCH_LENGTH_BYTES);
+    let length = u32::from_ne_bytes(length.try_into().ok()?) as usize;
+    if length == 0 || ids.len() != length * ID_BYTES {
+        return None;
+    }
+
+    ids.chunks_exact(ID_BYTES)
+        .map(|id| id.try_into().ok().map(usize::from_ne_bytes))
+        .collect()
+}
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum CreationError {
@@ -46,6 +160,13 @@ pub(crate) struct OutboundEventConnection<'a, ServiceType: iceoryx2::service::Se
     iox_service_config: IceoryxServiceConfig,
     iox_listener: IceoryxListener<ServiceType>,
     z_notifier: ZenohPublisher<'a>,
+    z_additional_notifiers: Vec<ZenohPublisher<'a>>,
+    statistics: Arc<ConnectionStatistics>,
+    batching: Option<EventBatching>,
+    pending_batch: Mutex<PendingEventBatch>,
+    keep_alive: KeepAlive,
+    receive_timeout: Option<Duration>,
+    disabled: bool,
 }
 
 impl<ServiceType: iceoryx2::service::Service> OutboundEventConnection<'_, ServiceType> {
@@ -55,49 +176,160 @@ impl<ServiceType: iceoryx2::service::Service> OutboundEventConnection<'_, Servic
         iox_service_config: &IceoryxServiceConfig,
         iox_event_service: &IceoryxEventService<ServiceType>,
         z_session: &ZenohSession,
+        z_key: &str,
+        z_allowed_destination: Locality,
+        statistics: &Arc<ConnectionStatistics>,
     ) -> Result<Self, CreationError> {
         let iox_listener = iox_create_listener(iox_event_service, iox_service_config)
             .map_err(|_e| CreationError::Error)?;
         let z_notifier =
-            z_create_notifier(z_session, iox_service_config).map_err(|_e| CreationError::Error)?;
+            z_create_notifier(z_session, z_key, iox_service_config, z_allowed_destination)
+                .map_err(|_e| CreationError::Error)?;
 
         Ok(Self {
             iox_service_config: iox_service_config.clone(),
             iox_listener,
             z_notifier,
+            z_additional_notifiers: Vec::new(),
+            statistics: statistics.clone(),
+            batching: None,
+            pending_batch: Mutex::new(PendingEventBatch::default()),
+            keep_alive: KeepAlive::default(),
+            receive_timeout: None,
+            disabled: false,
         })
     }
+
+    /// Propagates the pending batch of local events to remote hosts if it is due, or right
+    /// away if batching was disabled in the meantime, and returns how many events were
+    /// propagated.
+    fn propagate_pending_batch(&self) -> Result<usize, PropagationError> {
+        let mut pending_batch = self.pending_batch.lock().unwrap();
+        let is_due = match &self.batching {
+            Some(batching) => pending_batch.is_due(batching),
+            None => true,
+        };
+        if pending_batch.ids.is_empty() || !is_due {
+            return Ok(0);
+        }
+        let ids = pending_batch.take();
+        drop(pending_batch);
+
+        self.z_notify(encode_event_batch(&ids))?;
+        for id in &ids {
+            info!(
+                "PROPAGATED(iceoryx->zenoh): Event({}) {} [{}]",
+                id,
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+            self.statistics.record_iceoryx_to_zenoh();
+        }
+
+        Ok(ids.len())
+    }
+
+    /// Returns the number of local events collected in the pending batch.
+    fn pending_events(&self) -> usize {
+        self.pending_batch.lock().unwrap().ids.len()
+    }
+
+    /// Returns the duration until the pending batch is due, `None` if no batch is pending.
+    fn pending_batch_due_in(&self) -> Option<Duration> {
+        let batching = self.batching.as_ref()?;
+        self.pending_batch.lock().unwrap().due_in(batching)
+    }
+
+    /// Publishes an event payload to remote hosts on every zenoh session of the connection,
+    /// even if publishing fails on some of them.
+    fn z_notify(&self, payload: Vec<u8>) -> Result<(), PropagationError> {
+        let z_payload = ZBytes::from(payload);
+        z_put_all(
+            core::iter::once(&self.z_notifier).chain(&self.z_additional_notifiers),
+            |z_notifier| z_notifier.put(z_payload.clone()).wait(),
+        )
+        .map_err(|e| {
+            error!("Failed to propagate event to zenoh: {}", e);
+            PropagationError::ZenohPutFailed
+        })?;
+        self.keep_alive.record_published();
+
+        Ok(())
+    }
+
+    /// Publishes a keep-alive to remote hosts if no event was published within the
+    /// keep-alive interval.
+    fn publish_keep_alive(&self) -> Result<(), PropagationError> {
+        match self
+            .keep_alive
+            .publish_if_idle(core::iter::once(&self.z_notifier).chain(&self.z_additional_notifiers))
+        {
+            Ok(true) => {
+                info!(
+                    "KEEP-ALIVE(iceoryx->zenoh): Event {} [{}]",
+                    self.iox_service_config.service_id().as_str(),
+                    self.iox_service_config.name()
+                );
+                Ok(())
+            }
+            Ok(false) => Ok(()),
+            Err(e) => {
+                error!("Failed to publish keep-alive to zenoh: {}", e);
+                Err(PropagationError::ZenohPutFailed)
+            }
+        }
+    }
 }
 
 impl<ServiceType: iceoryx2::service::Service> Connection
     for OutboundEventConnection<'_, ServiceType>
 {
     /// Propagate local events received on the service to remote hosts.
-    fn propagate(&self) -> Result<(), PropagationError> {
-        // Propagate all notified ids once
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        if self.disabled {
+            return Ok(0);
+        }
+
+        // Propagate all notified ids once, or collect them in the pending batch
         let mut notified_ids: HashSet<usize> = HashSet::new();
-        while let Ok(sample) = self.iox_listener.try_wait_one() {
-            match sample {
-                Some(event_id) => {
-                    if !notified_ids.contains(&event_id.as_value()) {
-                        self.z_notifier
-                            .put(event_id.as_value().to_ne_bytes())
-                            .wait()
-                            .map_err(|_| PropagationError::Error)?;
+        let start = Instant::now();
+        loop {
+            if let Some(receive_timeout) = self.receive_timeout {
+                if start.elapsed() >= receive_timeout {
+                    warn!(
+                        "TIMEOUT(iceoryx->zenoh): Event {} [{}] after propagating {} events",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name(),
+                        notified_ids.len()
+                    );
+                    return Err(PropagationError::ReceiveTimeout);
+                }
+            }
+
+            match self.iox_listener.try_wait_one() {
+                Ok(Some(event_id)) => {
+                    if self.batching.is_some() {
+                        self.pending_batch.lock().unwrap().push(event_id.as_value());
+                    } else if !notified_ids.contains(&event_id.as_value()) {
+                        self.z_notify(event_id.as_value().to_ne_bytes().to_vec())?;
                         info!(
                             "PROPAGATED(iceoryx->zenoh): Event({}) {} [{}]",
                             event_id.as_value(),
                             self.iox_service_config.service_id().as_str(),
                             self.iox_service_config.name()
                         );
+                        self.statistics.record_iceoryx_to_zenoh();
                         notified_ids.insert(event_id.as_value());
                     }
                 }
-                None => break,
+                Ok(None) | Err(_) => break,
             }
         }
 
-        Ok(())
+        let batched = self.propagate_pending_batch()?;
+        self.publish_keep_alive()?;
+
+        Ok(notified_ids.len() + batched)
     }
 }
 
@@ -106,6 +338,10 @@ pub(crate) struct InboundEventConnection<ServiceType: iceoryx2::service::Service
     iox_service_config: IceoryxServiceConfig,
     iox_notifier: IceoryxNotifier<ServiceType>,
     z_listener: ZenohSubscriber<FifoChannelHandler<Sample>>,
+    z_additional_listeners: Vec<ZenohSubscriber<FifoChannelHandler<Sample>>>,
+    saturation: SaturationTracker,
+    statistics: Arc<ConnectionStatistics>,
+    disabled: bool,
 }
 
 impl<ServiceType: iceoryx2::service::Service> InboundEventConnection<ServiceType> {
@@ -115,41 +351,65 @@ impl<ServiceType: iceoryx2::service::Service> InboundEventConnection<ServiceType
         iox_service_config: &IceoryxServiceConfig,
         iox_event_service: &IceoryxEventService<ServiceType>,
         z_session: &ZenohSession,
+        z_key: &str,
+        statistics: &Arc<ConnectionStatistics>,
     ) -> Result<Self, CreationError> {
         let iox_notifier = iox_create_notifier(iox_event_service, iox_service_config)
             .map_err(|_e| CreationError::Error)?;
-        let z_listener =
-            z_create_listener(z_session, iox_service_config).map_err(|_e| CreationError::Error)?;
+        let z_listener = z_create_listener(z_session, z_key, iox_service_config)
+            .map_err(|_e| CreationError::Error)?;
 
         Ok(Self {
             iox_service_config: iox_service_config.clone(),
             iox_notifier,
             z_listener,
+            z_additional_listeners: Vec::new(),
+            saturation: SaturationTracker::default(),
+            statistics: statistics.clone(),
+            disabled: false,
         })
     }
+
+    /// Returns the zenoh listeners of all zenoh sessions of the connection.
+    fn z_listeners(&self) -> impl Iterator<Item = &ZenohSubscriber<FifoChannelHandler<Sample>>> {
+        core::iter::once(&self.z_listener).chain(&self.z_additional_listeners)
+    }
 }
 
 impl<ServiceType: iceoryx2::service::Service> Connection for InboundEventConnection<ServiceType> {
     /// Propagate remote events received on the service to remote hosts.
-    fn propagate(&self) -> Result<(), PropagationError> {
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        if self.disabled {
+            return Ok(0);
+        }
+
         // Collect all notified ids
         let mut received_ids: HashSet<usize> = HashSet::new();
-        while let Ok(Some(sample)) = self.z_listener.try_recv() {
-            let payload = sample.payload();
-            if payload.len() == std::mem::size_of::<usize>() {
-                let id: usize =
-                    unsafe { payload.to_bytes().as_ptr().cast::<usize>().read_unaligned() };
-                received_ids.insert(id);
-            } else {
-                // Error, invalid event id. Skip.
+        if self.z_listeners().any(|z_listener| z_listener.is_full()) {
+            self.saturation.record_saturation();
+        } else {
+            self.saturation.clear_saturation();
+        }
+        for z_listener in self.z_listeners() {
+            while let Ok(Some(sample)) = z_listener.try_recv() {
+                if z_is_keep_alive(&sample) {
+                    continue;
+                }
+                match decode_event_ids(&sample.payload().to_bytes()) {
+                    Some(ids) => received_ids.extend(ids),
+                    None => {
+                        // Error, invalid event id. Skip.
+                    }
+                }
             }
         }
 
         // Propagate notifications received - once per event id
-        for id in received_ids {
+        for id in &received_ids {
             self.iox_notifier
-                .__internal_notify(EventId::new(id), true)
-                .map_err(|_| PropagationError::Error)?;
+                .__internal_notify(EventId::new(*id), true)
+                .map_err(|_| PropagationError::IceoryxSendFailed)?;
+            self.statistics.record_zenoh_to_iceoryx();
             info!(
                 "PROPAGATED(iceoryx<-zenoh): Event({}) {} [{}]",
                 id,
@@ -158,51 +418,310 @@ impl<ServiceType: iceoryx2::service::Service> Connection for InboundEventConnect
             );
         }
 
-        Ok(())
+        Ok(received_ids.len())
     }
 }
 
 /// Couples the outbound and inbound connection for events
 /// from particular iceoryx2 service.
+///
+/// Only the [`EventId`] of a notification is forwarded: iceoryx2 notifications do not carry
+/// user data, payloads are exchanged via publish-subscribe services instead.
 pub(crate) struct BidirectionalEventConnection<'a, ServiceType: iceoryx2::service::Service> {
     outbound_connection: OutboundEventConnection<'a, ServiceType>,
     inbound_connection: InboundEventConnection<ServiceType>,
+    iox_service_config: IceoryxServiceConfig,
+    failures: FailureTracker,
+    statistics: Arc<ConnectionStatistics>,
+    event_history: Mutex<VecDeque<(Instant, usize)>>,
 }
 
 impl<ServiceType: iceoryx2::service::Service> BidirectionalEventConnection<'_, ServiceType> {
     /// Create a bi-directional connection to propagate events for a particular iceoryx2 service
-    /// to and from remote iceoryx2 instances via Zenoh.
+    /// to and from remote iceoryx2 instances via Zenoh. Local events are only published to the
+    /// zenoh instances of the allowed destination.
     pub fn create(
         iox_node: &IceoryxNode<ServiceType>,
         z_session: &ZenohSession,
         iox_service_config: &IceoryxServiceConfig,
+        z_key: &str,
+        z_allowed_destination: Locality,
     ) -> Result<Self, CreationError> {
         let iox_event_service =
             iox_create_event_service::<ServiceType>(iox_node, iox_service_config)
                 .map_err(|_e| CreationError::Error)?;
 
-        let inbound_connection =
-            InboundEventConnection::create(iox_service_config, &iox_event_service, z_session)?;
-        let outbound_connection =
-            OutboundEventConnection::create(iox_service_config, &iox_event_service, z_session)?;
+        let statistics = Arc::new(ConnectionStatistics::default());
+        let inbound_connection = InboundEventConnection::create(
+            iox_service_config,
+            &iox_event_service,
+            z_session,
+            z_key,
+            &statistics,
+        )?;
+        let outbound_connection = OutboundEventConnection::create(
+            iox_service_config,
+            &iox_event_service,
+            z_session,
+            z_key,
+            z_allowed_destination,
+            &statistics,
+        )?;
 
         z_announce_service(z_session, iox_service_config).map_err(|_e| CreationError::Error)?;
 
         Ok(Self {
             outbound_connection,
             inbound_connection,
+            iox_service_config: iox_service_config.clone(),
+            failures: FailureTracker::default(),
+            statistics,
+            event_history: Mutex::new(VecDeque::with_capacity(EVENT_HISTORY_CAPACITY)),
         })
     }
+
+    /// Returns the configuration of the iceoryx2 service of this connection.
+    pub fn service_config(&self) -> &IceoryxServiceConfig {
+        &self.iox_service_config
+    }
+
+    /// Disables or re-enables propagating local events to remote hosts.
+    pub fn set_iceoryx_to_zenoh_disabled(&mut self, disabled: bool) {
+        self.outbound_connection.disabled = disabled;
+    }
+
+    /// Disables or re-enables propagating remote events to the local host.
+    pub fn set_zenoh_to_iceoryx_disabled(&mut self, disabled: bool) {
+        self.inbound_connection.disabled = disabled;
+    }
+
+    /// Returns `true` if the zenoh listeners receiving the remote events are still connected
+    /// to their zenoh sessions.
+    pub fn is_z_listener_alive(&self) -> bool {
+        self.inbound_connection
+            .z_listeners()
+            .all(|z_listener| !z_listener.is_disconnected())
+    }
+
+    /// Returns the quality of service of the zenoh notifier propagating the local events.
+    pub fn publisher_options(&self) -> PublisherOptions {
+        let z_notifier = &self.outbound_connection.z_notifier;
+        PublisherOptions {
+            reliability: z_notifier.reliability(),
+            congestion_control: z_notifier.congestion_control(),
+            priority: z_notifier.priority(),
+        }
+    }
+
+    /// Collects the local events in batches that are propagated to remote hosts as a single
+    /// zenoh payload, `None` propagates every event on its own.
+    pub fn set_event_batching(&mut self, batching: Option<EventBatching>) {
+        self.outbound_connection.batching = batching;
+    }
+
+    /// Limits the duration spent receiving local events per propagation. `None` removes the
+    /// limit.
+    pub fn set_iceoryx_receive_timeout(&mut self, timeout: Option<Duration>) {
+        self.outbound_connection.receive_timeout = timeout;
+    }
+
+    /// Publishes keep-alives to remote hosts whenever no local event was propagated within the
+    /// provided interval. `None` disables the keep-alives.
+    pub fn set_keep_alive_interval(&mut self, interval: Option<Duration>) {
+        self.outbound_connection.keep_alive.set_interval(interval);
+    }
+
+    /// Additionally propagates the events of this connection over another zenoh session, e.g.
+    /// to reach the hosts behind a second zenoh router.
+    pub fn attach_zenoh_session(
+        &mut self,
+        z_session: &ZenohSession,
+        z_allowed_destination: Locality,
+    ) -> Result<(), CreationError> {
+        let z_key = self.z_key_expression();
+        let z_notifier = z_create_notifier(
+            z_session,
+            &z_key,
+            &self.iox_service_config,
+            z_allowed_destination,
+        )
+        .map_err(|_e| CreationError::Error)?;
+        let z_listener = z_create_listener(z_session, &z_key, &self.iox_service_config)
+            .map_err(|_e| CreationError::Error)?;
+        self.outbound_connection
+            .z_additional_notifiers
+            .push(z_notifier);
+        self.inbound_connection
+            .z_additional_listeners
+            .push(z_listener);
+
+        z_announce_service(z_session, &self.iox_service_config).map_err(|_e| CreationError::Error)
+    }
+
+    /// Returns the zenoh key expression events of this connection are published on.
+    pub fn z_key_expression(&self) -> String {
+        self.outbound_connection.z_notifier.key_expr().to_string()
+    }
+
+    /// Propagates local events to remote hosts without propagating remote events to the local
+    /// host and returns how many were propagated.
+    pub fn flush(&self) -> Result<usize, PropagationError> {
+        let outbound = self
+            .outbound_connection
+            .propagate()
+            .inspect_err(|e| self.statistics.record_error(*e))?;
+        self.record_events(outbound);
+
+        Ok(outbound)
+    }
+
+    /// Returns the number of local events waiting in the pending batch to be propagated to
+    /// remote hosts.
+    pub fn pending_events(&self) -> usize {
+        self.outbound_connection.pending_events()
+    }
+
+    /// Returns the duration until the pending batch of local events is due to be propagated
+    /// to remote hosts, `None` if no batch is pending.
+    pub fn pending_batch_due_in(&self) -> Option<Duration> {
+        self.outbound_connection.pending_batch_due_in()
+    }
+
+    /// Returns the propagation failures tracked for this connection.
+    pub fn failures(&self) -> &FailureTracker {
+        &self.failures
+    }
+
+    /// Returns the liveness of this connection according to its most recent propagations.
+    pub fn health(&self) -> ConnectionHealth {
+        self.failures.health()
+    }
+
+    /// Validates that the zenoh ports of this connection are still usable and that its
+    /// propagations do not fail excessively. The iceoryx2 listener and notifier do not expose
+    /// their state and are not validated.
+    pub fn validate(&self, z_session: &ZenohSession) -> ConnectionValidation {
+        if z_session.is_closed() {
+            return ConnectionValidation::Failed {
+                reason: String::from("zenoh notifier is closed"),
+            };
+        }
+        if self
+            .inbound_connection
+            .z_listeners()
+            .any(|z_listener| z_listener.is_disconnected())
+        {
+            return ConnectionValidation::Failed {
+                reason: String::from("zenoh listener is closed"),
+            };
+        }
+
+        ConnectionValidation::of_propagations(&self.failures, &self.statistics)
+    }
+
+    /// Returns the forwarding statistics tracked for this connection.
+    pub fn statistics(&self) -> &ConnectionStatistics {
+        &self.statistics
+    }
+
+    /// Returns `true` if the most recent propagation found the receive buffer for remote
+    /// events at capacity.
+    pub fn is_saturated(&self) -> bool {
+        self.inbound_connection.saturation.is_saturated()
+    }
+
+    /// Returns the rate of events per second that were propagated successfully in either
+    /// direction within the provided window, ending now.
+    ///
+    /// Only the most recent propagations are retained, thus very large windows may
+    /// under-report the frequency for busy connections.
+    pub fn event_frequency(&self, window: Duration) -> f64 {
+        if window.is_zero() {
+            return 0.0;
+        }
+
+        let now = Instant::now();
+        let event_history = self.event_history.lock().unwrap();
+        let events: usize = event_history
+            .iter()
+            .rev()
+            .take_while(|(timestamp, _)| now.duration_since(*timestamp) <= window)
+            .map(|(_, count)| count)
+            .sum();
+
+        events as f64 / window.as_secs_f64()
+    }
+
+    /// Returns the approximate number of heap bytes allocated for the histories of the
+    /// connection.
+    pub fn history_bytes(&self) -> usize {
+        let event_history_bytes = self.event_history.lock().unwrap().capacity()
+            * core::mem::size_of::<(Instant, usize)>();
+
+        self.statistics.history_bytes() + event_history_bytes
+    }
+
+    fn record_events(&self, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let mut event_history = self.event_history.lock().unwrap();
+        if event_history.len() == EVENT_HISTORY_CAPACITY {
+            event_history.pop_front();
+        }
+        event_history.push_back((Instant::now(), count));
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> TunneledConnection
+    for BidirectionalEventConnection<'_, ServiceType>
+{
+    fn service_config(&self) -> &IceoryxServiceConfig {
+        &self.iox_service_config
+    }
+
+    fn failures(&self) -> &FailureTracker {
+        &self.failures
+    }
+
+    fn statistics(&self) -> &ConnectionStatistics {
+        &self.statistics
+    }
 }
 
 impl<ServiceType: iceoryx2::service::Service> Connection
     for BidirectionalEventConnection<'_, ServiceType>
 {
     /// Propagate local events to remote host and remote events to the local host.
-    fn propagate(&self) -> Result<(), PropagationError> {
-        self.outbound_connection.propagate()?;
-        self.inbound_connection.propagate()?;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                service_id = self.iox_service_config.service_id().as_str(),
+                messaging_pattern = ?self.iox_service_config.messaging_pattern(),
+                messages_forwarded = tracing::field::Empty,
+            )
+        )
+    )]
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        let outbound = self
+            .outbound_connection
+            .propagate()
+            .inspect_err(|e| self.statistics.record_error(*e))?;
+        self.record_events(outbound);
+        let inbound = self
+            .inbound_connection
+            .propagate()
+            .inspect_err(|e| self.statistics.record_error(*e))?;
+        self.record_events(inbound);
+        self.statistics.record_ready();
 
-        Ok(())
+        let forwarded = outbound + inbound;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("messages_forwarded", forwarded);
+
+        Ok(forwarded)
     }
 }
@@ -12,13 +12,66 @@
 
 mod event;
 mod publish_subscribe;
+mod request_response;
 
 pub use event::*;
 pub use publish_subscribe::*;
+pub use request_response::*;
+
+use core::sync::atomic::Ordering;
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use zenoh::bytes::ZBytes;
+use zenoh::pubsub::Publisher as ZenohPublisher;
+use zenoh::sample::Sample;
+use zenoh::Wait;
+
+/// The number of most recent propagations whose timestamps are kept to determine the jitter.
+const PROPAGATION_HISTORY_CAPACITY: usize = 1024;
+
+/// The heap bytes allocated for the propagation history of a connection at full capacity.
+pub(crate) const PROPAGATION_HISTORY_BYTES: usize =
+    PROPAGATION_HISTORY_CAPACITY * core::mem::size_of::<Instant>();
+
+/// The number of most recent latencies kept to determine the latency percentiles.
+#[cfg(feature = "latency-tracking")]
+const LATENCY_HISTORY_CAPACITY: usize = 1024;
+
+/// The share of failed propagations above which a validated connection is degraded.
+const MAX_VALID_ERROR_RATE: f64 = 0.1;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum PropagationError {
-    Error,
+    /// Receiving the local payloads of a connection from iceoryx failed.
+    IceoryxReceiveFailed,
+    /// Loaning the memory for a remote payload from iceoryx failed.
+    IceoryxLoanFailed,
+    /// Delivering a remote payload to iceoryx failed, i.e. sending a sample, request or
+    /// response or notifying the listeners.
+    IceoryxSendFailed,
+    /// Publishing a local payload or keep-alive to zenoh failed.
+    ZenohPutFailed,
+    /// Sending a local request or response over zenoh failed.
+    ZenohQueryFailed,
+    /// A remote payload could not be decoded, e.g. because its compression is corrupt.
+    MalformedRemotePayload,
+    /// Receiving the local payloads of a connection exceeded the configured timeout.
+    ReceiveTimeout,
+    /// The local payloads of a connection could not be propagated due to backpressure, see
+    /// [`BackpressurePolicy`].
+    Backpressure,
 }
 
 impl core::fmt::Display for PropagationError {
@@ -29,6 +82,788 @@ impl core::fmt::Display for PropagationError {
 
 impl core::error::Error for PropagationError {}
 
+impl PropagationError {
+    /// Returns `true` if the error indicates a busy rather than a failing connection, i.e.
+    /// it is not counted towards the consecutive failures of the connection.
+    pub(crate) fn is_busy(&self) -> bool {
+        matches!(
+            self,
+            PropagationError::ReceiveTimeout | PropagationError::Backpressure
+        )
+    }
+}
+
+/// The liveness of a connection as determined by its most recent propagations.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ConnectionHealth {
+    /// The last propagation over the connection succeeded.
+    Healthy,
+    /// The last propagation over the connection failed, but the connection is still being
+    /// propagated.
+    Degraded { last_error: String },
+    /// The connection was disabled after failing repeatedly and is no longer propagated.
+    Dead,
+}
+
+/// The outcome of validating a connection.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) enum ConnectionValidation {
+    /// The ports of the connection are usable and its propagations succeed.
+    Healthy,
+    /// The ports of the connection are usable, but its propagations fail.
+    Degraded { reason: String },
+    /// The connection can no longer propagate.
+    Failed { reason: String },
+}
+
+impl ConnectionValidation {
+    /// Validates the recorded propagations of a connection, i.e. whether it was disabled, its
+    /// last propagation failed or its error rate exceeds [`MAX_VALID_ERROR_RATE`].
+    pub fn of_propagations(failures: &FailureTracker, statistics: &ConnectionStatistics) -> Self {
+        match failures.health() {
+            ConnectionHealth::Healthy => (),
+            ConnectionHealth::Degraded { last_error } => {
+                return ConnectionValidation::Degraded {
+                    reason: format!("last propagation failed with {last_error}"),
+                };
+            }
+            ConnectionHealth::Dead => {
+                return ConnectionValidation::Failed {
+                    reason: String::from("propagation was disabled after repeated failures"),
+                };
+            }
+        }
+
+        let error_rate = statistics.error_rate();
+        if error_rate > MAX_VALID_ERROR_RATE {
+            return ConnectionValidation::Degraded {
+                reason: format!("error rate of {error_rate:.2} exceeds {MAX_VALID_ERROR_RATE:.2}"),
+            };
+        }
+
+        ConnectionValidation::Healthy
+    }
+}
+
+/// The direction in which a payload is propagated over a connection.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Direction {
+    /// From the local host to remote hosts.
+    IceoryxToZenoh,
+    /// From remote hosts to the local host.
+    ZenohToIceoryx,
+}
+
+/// The directions in which the payloads of a publish-subscribe connection are propagated.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionDirection {
+    /// Propagate local payloads to remote hosts and remote payloads to the local host.
+    #[default]
+    Bidirectional,
+    /// Only propagate local payloads to remote hosts, e.g. on a host that only produces.
+    IceoryxToZenoh,
+    /// Only propagate remote payloads to the local host, e.g. on a host that only consumes.
+    ZenohToIceoryx,
+}
+
+impl ConnectionDirection {
+    /// Returns `true` if local payloads are propagated to remote hosts.
+    pub fn includes_iceoryx_to_zenoh(&self) -> bool {
+        *self != ConnectionDirection::ZenohToIceoryx
+    }
+
+    /// Returns `true` if remote payloads are propagated to the local host.
+    pub fn includes_zenoh_to_iceoryx(&self) -> bool {
+        *self != ConnectionDirection::IceoryxToZenoh
+    }
+}
+
+/// Returns whether the callback shall stay installed.
+type TapCallback = Box<dyn Fn(Direction, &[u8]) -> bool + Send>;
+
+/// Callbacks that are notified about every payload propagated over a connection.
+#[derive(Default)]
+pub(crate) struct PayloadTaps {
+    next_id: IoxAtomicU64,
+    callbacks: Mutex<Vec<(u64, Arc<Mutex<TapCallback>>)>>,
+}
+
+impl PayloadTaps {
+    /// Installs a callback and returns the id to remove it again.
+    pub fn install(&self, callback: TapCallback) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.callbacks
+            .lock()
+            .unwrap()
+            .push((id, Arc::new(Mutex::new(callback))));
+        id
+    }
+
+    /// Removes the callback with the provided id.
+    pub fn remove(&self, id: u64) {
+        self.callbacks
+            .lock()
+            .unwrap()
+            .retain(|(installed_id, _)| *installed_id != id);
+    }
+
+    /// Returns `true` if no callback is installed.
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.lock().unwrap().is_empty()
+    }
+
+    /// Notifies all installed callbacks about a propagated payload and removes the callbacks
+    /// that no longer want to be notified.
+    pub fn notify(&self, direction: Direction, payload: &[u8]) {
+        // The callbacks are called without holding the lock, so that they can install or
+        // remove callbacks themselves
+        let callbacks = self.callbacks.lock().unwrap().clone();
+        let finished: Vec<u64> = callbacks
+            .iter()
+            .filter(|(_, callback)| !(callback.lock().unwrap())(direction, payload))
+            .map(|(id, _)| *id)
+            .collect();
+        if !finished.is_empty() {
+            self.callbacks
+                .lock()
+                .unwrap()
+                .retain(|(id, _)| !finished.contains(id));
+        }
+    }
+}
+
 pub trait Connection {
-    fn propagate(&self) -> Result<(), PropagationError>;
+    /// Propagates all pending payloads of the connection and returns how many were propagated.
+    fn propagate(&self) -> Result<usize, PropagationError>;
+}
+
+/// The connection of a tunneled service, independent of its messaging pattern, so that the
+/// connections of all messaging patterns can be visited alike.
+pub(crate) trait TunneledConnection: Connection {
+    /// Returns the static configuration of the service of the connection.
+    fn service_config(&self) -> &IceoryxServiceConfig;
+
+    /// Returns the propagation failures tracked for the connection.
+    fn failures(&self) -> &FailureTracker;
+
+    /// Returns the traffic forwarded over the connection.
+    fn statistics(&self) -> &ConnectionStatistics;
+}
+
+/// Tracks the consecutive propagation failures of a connection and whether it was
+/// disabled as a consequence.
+#[derive(Debug, Default)]
+pub(crate) struct FailureTracker {
+    consecutive_failures: IoxAtomicU32,
+    is_disabled: IoxAtomicBool,
+    last_error: Mutex<Option<PropagationError>>,
+}
+
+impl FailureTracker {
+    /// Records a failed propagation and returns the number of consecutive failures.
+    pub fn record_failure(&self, error: PropagationError) -> u32 {
+        *self.last_error.lock().unwrap() = Some(error);
+        self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Records a successful propagation, resetting the consecutive failures.
+    pub fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+    }
+
+    /// Disables propagation over the connection.
+    pub fn disable(&self) {
+        self.is_disabled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if propagation over the connection is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled.load(Ordering::Relaxed)
+    }
+
+    /// Returns the liveness of the connection according to the recorded propagations.
+    pub fn health(&self) -> ConnectionHealth {
+        if self.is_disabled() {
+            return ConnectionHealth::Dead;
+        }
+
+        if self.consecutive_failures.load(Ordering::Relaxed) == 0 {
+            return ConnectionHealth::Healthy;
+        }
+
+        match *self.last_error.lock().unwrap() {
+            Some(last_error) => ConnectionHealth::Degraded {
+                last_error: last_error.to_string(),
+            },
+            None => ConnectionHealth::Healthy,
+        }
+    }
+}
+
+/// Tracks whether a connection found one of its receive buffers at capacity, which indicates
+/// that the sender is faster than the tunnel, and how many payloads the connection dropped
+/// due to backpressure.
+#[derive(Debug, Default)]
+pub(crate) struct SaturationTracker {
+    saturations: IoxAtomicU64,
+    is_saturated: IoxAtomicBool,
+    drops: IoxAtomicU64,
+}
+
+impl SaturationTracker {
+    /// Records that a receive buffer of the connection was found at capacity by the current
+    /// propagation.
+    pub fn record_saturation(&self) {
+        self.saturations.fetch_add(1, Ordering::Relaxed);
+        self.is_saturated.store(true, Ordering::Relaxed);
+    }
+
+    /// Records that the receive buffers of the connection were not found at capacity by the
+    /// current propagation (yet).
+    pub fn clear_saturation(&self) {
+        self.is_saturated.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns the number of saturations recorded since the connection was created.
+    pub fn count(&self) -> u64 {
+        self.saturations.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the most recent propagation found a receive buffer at capacity.
+    pub fn is_saturated(&self) -> bool {
+        self.is_saturated.load(Ordering::Relaxed)
+    }
+
+    /// Records a payload that was dropped by the connection due to backpressure.
+    pub fn record_drop(&self) {
+        self.drops.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the number of payloads dropped by the connection due to backpressure.
+    pub fn drops(&self) -> u64 {
+        self.drops.load(Ordering::Relaxed)
+    }
+}
+
+/// The attachment identifying the keep-alives of a connection, which carry no payload and are
+/// not propagated to the local host.
+const KEEP_ALIVE_ATTACHMENT: &[u8] = b"iox2-keep-alive";
+
+/// Publishes keep-alives on the zenoh publishers of an idle connection, so that zenoh routers
+/// do not drop them during idle periods.
+#[derive(Debug)]
+pub(crate) struct KeepAlive {
+    interval: Option<Duration>,
+    last_published: Mutex<Instant>,
+}
+
+impl Default for KeepAlive {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            last_published: Mutex::new(Instant::now()),
+        }
+    }
+}
+
+impl KeepAlive {
+    /// Sets the maximum duration the connection may stay idle, `None` disables the
+    /// keep-alives.
+    pub fn set_interval(&mut self, interval: Option<Duration>) {
+        self.interval = interval;
+    }
+
+    /// Records that a payload was published, which postpones the next keep-alive.
+    pub fn record_published(&self) {
+        *self.last_published.lock().unwrap() = Instant::now();
+    }
+
+    /// Publishes a keep-alive on the provided zenoh publishers if nothing was published
+    /// within the interval, returns `true` if a keep-alive was published.
+    pub fn publish_if_idle<'p, 'a: 'p>(
+        &self,
+        z_publishers: impl Iterator<Item = &'p ZenohPublisher<'a>>,
+    ) -> Result<bool, zenoh::Error> {
+        let Some(interval) = self.interval else {
+            return Ok(false);
+        };
+        let mut last_published = self.last_published.lock().unwrap();
+        if last_published.elapsed() < interval {
+            return Ok(false);
+        }
+
+        z_put_all(z_publishers, |z_publisher| {
+            z_publisher
+                .put(ZBytes::new())
+                .attachment(ZBytes::from(KEEP_ALIVE_ATTACHMENT))
+                .wait()
+        })?;
+        *last_published = Instant::now();
+
+        Ok(true)
+    }
+}
+
+/// Publishes with each of the provided zenoh publishers, e.g. one per zenoh session. A failing
+/// publisher does not prevent publishing with the remaining ones.
+///
+/// # Returns
+///
+/// * `Ok(())` - If all publishers published
+/// * `Err(zenoh::Error)` - Naming the number of failed publishers and the first failure
+pub(crate) fn z_put_all<'p, 'a: 'p>(
+    z_publishers: impl Iterator<Item = &'p ZenohPublisher<'a>>,
+    mut put: impl FnMut(&ZenohPublisher<'a>) -> Result<(), zenoh::Error>,
+) -> Result<(), zenoh::Error> {
+    let mut num_publishers = 0;
+    let mut failures = Vec::new();
+    for z_publisher in z_publishers {
+        num_publishers += 1;
+        if let Err(e) = put(z_publisher) {
+            failures.push(e);
+        }
+    }
+
+    match failures.first() {
+        None => Ok(()),
+        Some(e) => Err(format!(
+            "publishing failed on {} of {} zenoh sessions, first failure: {}",
+            failures.len(),
+            num_publishers,
+            e
+        )
+        .into()),
+    }
+}
+
+/// Returns `true` if the zenoh sample is a keep-alive of a remote connection.
+pub(crate) fn z_is_keep_alive(z_sample: &Sample) -> bool {
+    z_sample
+        .attachment()
+        .is_some_and(|z_attachment| *z_attachment.to_bytes() == *KEEP_ALIVE_ATTACHMENT)
+}
+
+/// Limits the rate at which payloads are propagated over a connection with a token bucket
+/// that holds up to one second worth of payloads.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    messages_per_second: f64,
+    bucket: Mutex<TokenBucket>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter with a full token bucket.
+    pub fn new(messages_per_second: u32) -> Self {
+        let messages_per_second = f64::from(messages_per_second);
+        Self {
+            messages_per_second,
+            bucket: Mutex::new(TokenBucket {
+                tokens: messages_per_second,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Takes a token from the bucket, returns `false` if the bucket is empty and the payload
+    /// must be dropped.
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        self.refill(&mut bucket);
+
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+
+    /// Returns the maximum rate of the limiter.
+    pub fn messages_per_second(&self) -> f64 {
+        self.messages_per_second
+    }
+
+    /// Returns `true` if the bucket is empty, i.e. the next payload would be dropped.
+    pub fn is_exhausted(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        self.refill(&mut bucket);
+
+        bucket.tokens < 1.0
+    }
+
+    fn refill(&self, bucket: &mut TokenBucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens =
+            (bucket.tokens + elapsed * self.messages_per_second).min(self.messages_per_second);
+        bucket.last_refill = now;
+    }
+}
+
+/// The payload size ranges counted by a [`SizeHistogram`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SizeBucket {
+    /// Payloads smaller than 64 bytes.
+    Below64B,
+    /// Payloads of at least 64 bytes and smaller than 512 bytes.
+    Below512B,
+    /// Payloads of at least 512 bytes and smaller than 4 KiB.
+    Below4KiB,
+    /// Payloads of at least 4 KiB and smaller than 64 KiB.
+    Below64KiB,
+    /// Payloads of 64 KiB or more.
+    From64KiB,
+}
+
+impl SizeBucket {
+    /// All buckets in ascending order of payload size.
+    pub const ALL: [SizeBucket; 5] = [
+        SizeBucket::Below64B,
+        SizeBucket::Below512B,
+        SizeBucket::Below4KiB,
+        SizeBucket::Below64KiB,
+        SizeBucket::From64KiB,
+    ];
+
+    /// Returns the bucket a payload of the provided size is counted in.
+    pub fn of(bytes: usize) -> Self {
+        match bytes {
+            0..64 => SizeBucket::Below64B,
+            64..512 => SizeBucket::Below512B,
+            512..4096 => SizeBucket::Below4KiB,
+            4096..65536 => SizeBucket::Below64KiB,
+            _ => SizeBucket::From64KiB,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The latency percentiles of the payloads received from remote hosts over a connection, see
+/// [`Tunnel::latency_report()`](crate::Tunnel::latency_report).
+#[cfg(feature = "latency-tracking")]
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct LatencyStats {
+    /// The median latency in microseconds.
+    pub p50_us: u64,
+    /// The 95th percentile of the latencies in microseconds.
+    pub p95_us: u64,
+    /// The 99th percentile of the latencies in microseconds.
+    pub p99_us: u64,
+    /// The maximum latency in microseconds.
+    pub max_us: u64,
+}
+
+/// Counts the forwarded payloads per [`SizeBucket`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct SizeHistogram {
+    counts: [u64; SizeBucket::ALL.len()],
+}
+
+impl SizeHistogram {
+    /// Returns the number of payloads counted in the provided bucket.
+    pub fn count(&self, bucket: SizeBucket) -> u64 {
+        self.counts[bucket.index()]
+    }
+
+    /// Returns the number of payloads counted in all buckets.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Adds the counts of another histogram to this histogram.
+    pub(crate) fn merge(&mut self, other: &SizeHistogram) {
+        for (count, other_count) in self.counts.iter_mut().zip(other.counts) {
+            *count += other_count;
+        }
+    }
+}
+
+/// Tracks the payloads forwarded over a connection in each direction and the propagation
+/// errors.
+#[derive(Debug)]
+pub(crate) struct ConnectionStatistics {
+    created_at: SystemTime,
+    forwarded_iceoryx_to_zenoh: IoxAtomicU64,
+    forwarded_zenoh_to_iceoryx: IoxAtomicU64,
+    bytes_iceoryx_to_zenoh: IoxAtomicU64,
+    bytes_zenoh_to_iceoryx: IoxAtomicU64,
+    size_histogram: [IoxAtomicU64; SizeBucket::ALL.len()],
+    errors: IoxAtomicU64,
+    corrupted: IoxAtomicU64,
+    #[cfg(feature = "latency-tracking")]
+    latencies: Mutex<VecDeque<Duration>>,
+    last_error: Mutex<Option<PropagationError>>,
+    last_received_message_id: Mutex<Option<u64>>,
+    last_active: Mutex<Instant>,
+    propagation_history: Mutex<VecDeque<Instant>>,
+    discovered_at: Mutex<Instant>,
+    connection_latency: OnceLock<Duration>,
+}
+
+impl Default for ConnectionStatistics {
+    fn default() -> Self {
+        Self {
+            created_at: SystemTime::now(),
+            forwarded_iceoryx_to_zenoh: IoxAtomicU64::new(0),
+            forwarded_zenoh_to_iceoryx: IoxAtomicU64::new(0),
+            bytes_iceoryx_to_zenoh: IoxAtomicU64::new(0),
+            bytes_zenoh_to_iceoryx: IoxAtomicU64::new(0),
+            size_histogram: core::array::from_fn(|_| IoxAtomicU64::new(0)),
+            errors: IoxAtomicU64::new(0),
+            corrupted: IoxAtomicU64::new(0),
+            #[cfg(feature = "latency-tracking")]
+            latencies: Mutex::new(VecDeque::with_capacity(LATENCY_HISTORY_CAPACITY)),
+            last_error: Mutex::new(None),
+            last_received_message_id: Mutex::new(None),
+            last_active: Mutex::new(Instant::now()),
+            propagation_history: Mutex::new(VecDeque::with_capacity(PROPAGATION_HISTORY_CAPACITY)),
+            discovered_at: Mutex::new(Instant::now()),
+            connection_latency: OnceLock::new(),
+        }
+    }
+}
+
+impl ConnectionStatistics {
+    /// Records a message, i.e. a payload or an event, forwarded from the local host to remote
+    /// hosts.
+    pub fn record_iceoryx_to_zenoh(&self) {
+        self.forwarded_iceoryx_to_zenoh
+            .fetch_add(1, Ordering::Relaxed);
+        self.record_forwarded();
+    }
+
+    /// Records the size of a payload forwarded from the local host to remote hosts, in addition
+    /// to the message recorded with [`ConnectionStatistics::record_iceoryx_to_zenoh()`].
+    pub fn record_iceoryx_to_zenoh_bytes(&self, bytes: usize) {
+        self.bytes_iceoryx_to_zenoh
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+        self.size_histogram[SizeBucket::of(bytes).index()].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a message, i.e. a payload or an event, forwarded from remote hosts to the local
+    /// host.
+    pub fn record_zenoh_to_iceoryx(&self) {
+        self.forwarded_zenoh_to_iceoryx
+            .fetch_add(1, Ordering::Relaxed);
+        self.record_forwarded();
+    }
+
+    /// Records the size of a payload forwarded from remote hosts to the local host, in addition
+    /// to the message recorded with [`ConnectionStatistics::record_zenoh_to_iceoryx()`].
+    pub fn record_zenoh_to_iceoryx_bytes(&self, bytes: usize) {
+        self.bytes_zenoh_to_iceoryx
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+        self.size_histogram[SizeBucket::of(bytes).index()].fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_forwarded(&self) {
+        let now = Instant::now();
+        *self.last_active.lock().unwrap() = now;
+
+        let mut propagation_history = self.propagation_history.lock().unwrap();
+        if propagation_history.len() == PROPAGATION_HISTORY_CAPACITY {
+            propagation_history.pop_front();
+        }
+        propagation_history.push_back(now);
+    }
+
+    /// Records the point in time the service of the connection was discovered, defaults to the
+    /// creation of the statistics.
+    pub fn record_discovered_at(&self, discovered_at: Instant) {
+        *self.discovered_at.lock().unwrap() = discovered_at;
+    }
+
+    /// Records a successful propagation, which determines the connection latency the first time.
+    pub fn record_ready(&self) {
+        self.connection_latency
+            .get_or_init(|| self.discovered_at.lock().unwrap().elapsed());
+    }
+
+    /// Records a failed propagation.
+    pub fn record_error(&self, error: PropagationError) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+        *self.last_error.lock().unwrap() = Some(error);
+    }
+
+    /// Returns the point in time the connection was created.
+    pub fn created_at(&self) -> SystemTime {
+        self.created_at
+    }
+
+    /// Returns the point in time a payload was last forwarded in either direction, or the
+    /// point in time the connection was created if nothing was forwarded yet.
+    pub fn last_active(&self) -> Instant {
+        *self.last_active.lock().unwrap()
+    }
+
+    /// Returns the standard deviation of the intervals between the payloads forwarded within
+    /// the provided window, or `None` if less than two payloads were forwarded within it.
+    pub fn propagation_jitter(&self, window: Duration) -> Option<Duration> {
+        let now = Instant::now();
+        let propagation_history = self.propagation_history.lock().unwrap();
+        let timestamps: Vec<Instant> = propagation_history
+            .iter()
+            .rev()
+            .take_while(|timestamp| now.duration_since(**timestamp) <= window)
+            .copied()
+            .collect();
+        drop(propagation_history);
+
+        if timestamps.len() < 2 {
+            return None;
+        }
+
+        let intervals: Vec<f64> = timestamps
+            .windows(2)
+            .map(|pair| pair[0].duration_since(pair[1]).as_secs_f64())
+            .collect();
+        let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
+        let variance = intervals
+            .iter()
+            .map(|interval| (interval - mean).powi(2))
+            .sum::<f64>()
+            / intervals.len() as f64;
+
+        Some(Duration::from_secs_f64(variance.sqrt()))
+    }
+
+    /// Returns the approximate number of heap bytes allocated for the propagation history.
+    pub fn history_bytes(&self) -> usize {
+        self.propagation_history.lock().unwrap().capacity() * core::mem::size_of::<Instant>()
+    }
+
+    /// Returns the number of payloads forwarded from the local host to remote hosts.
+    pub fn forwarded_iceoryx_to_zenoh(&self) -> u64 {
+        self.forwarded_iceoryx_to_zenoh.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of payloads forwarded from remote hosts to the local host.
+    pub fn forwarded_zenoh_to_iceoryx(&self) -> u64 {
+        self.forwarded_zenoh_to_iceoryx.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes forwarded from the local host to remote hosts.
+    pub fn bytes_iceoryx_to_zenoh(&self) -> u64 {
+        self.bytes_iceoryx_to_zenoh.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes forwarded from remote hosts to the local host.
+    pub fn bytes_zenoh_to_iceoryx(&self) -> u64 {
+        self.bytes_zenoh_to_iceoryx.load(Ordering::Relaxed)
+    }
+
+    /// Returns the sizes of the payloads forwarded in both directions.
+    pub fn size_histogram(&self) -> SizeHistogram {
+        SizeHistogram {
+            counts: core::array::from_fn(|i| self.size_histogram[i].load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Returns the duration from the discovery of the service until the first successful
+    /// propagation over the connection, or `None` if nothing was propagated successfully yet.
+    pub fn connection_latency(&self) -> Option<Duration> {
+        self.connection_latency.get().copied()
+    }
+
+    /// Returns the number of failed propagations.
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    /// Records a payload that was dropped since its checksum did not match.
+    pub fn record_corrupted(&self) {
+        self.corrupted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the number of payloads dropped since their checksum did not match.
+    pub fn corrupted(&self) -> u64 {
+        self.corrupted.load(Ordering::Relaxed)
+    }
+
+    /// Records the message id of the most recently received remote payload.
+    pub fn record_received_message_id(&self, message_id: u64) {
+        *self.last_received_message_id.lock().unwrap() = Some(message_id);
+    }
+
+    /// Returns the message id of the most recently received remote payload, if message ids
+    /// are tracked.
+    pub fn last_received_message_id(&self) -> Option<u64> {
+        *self.last_received_message_id.lock().unwrap()
+    }
+
+    /// Records the duration between the publication of a remote payload on zenoh and its
+    /// receipt.
+    #[cfg(feature = "latency-tracking")]
+    pub fn record_latency(&self, latency: Duration) {
+        let mut latencies = self.latencies.lock().unwrap();
+        if latencies.len() == LATENCY_HISTORY_CAPACITY {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+
+    /// Returns the percentiles of the most recent latencies, or `None` if no latency was
+    /// recorded yet.
+    #[cfg(feature = "latency-tracking")]
+    pub fn latency_stats(&self) -> Option<LatencyStats> {
+        let mut latencies: Vec<Duration> = self.latencies.lock().unwrap().iter().copied().collect();
+        if latencies.is_empty() {
+            return None;
+        }
+        latencies.sort_unstable();
+
+        // nearest-rank percentile
+        let percentile = |p: usize| {
+            let rank = (p * latencies.len()).div_ceil(100).max(1);
+            latencies[rank - 1].as_micros() as u64
+        };
+
+        Some(LatencyStats {
+            p50_us: percentile(50),
+            p95_us: percentile(95),
+            p99_us: percentile(99),
+            max_us: percentile(100),
+        })
+    }
+
+    /// Returns the share of failed propagations among the failed propagations and forwarded
+    /// payloads, `0.0` if there were none.
+    pub fn error_rate(&self) -> f64 {
+        let errors = self.errors();
+        let total = errors + self.forwarded_iceoryx_to_zenoh() + self.forwarded_zenoh_to_iceoryx();
+        if total == 0 {
+            return 0.0;
+        }
+
+        errors as f64 / total as f64
+    }
+
+    /// Returns the most recent propagation error, if any.
+    pub fn last_error(&self) -> Option<PropagationError> {
+        *self.last_error.lock().unwrap()
+    }
+
+    /// Resets the counts of the forwarded payloads and bytes, of the propagation errors and of
+    /// the corrupted payloads, along with the most recent propagation error.
+    pub fn reset_counters(&self) {
+        self.forwarded_iceoryx_to_zenoh.store(0, Ordering::Relaxed);
+        self.forwarded_zenoh_to_iceoryx.store(0, Ordering::Relaxed);
+        self.bytes_iceoryx_to_zenoh.store(0, Ordering::Relaxed);
+        self.bytes_zenoh_to_iceoryx.store(0, Ordering::Relaxed);
+        for count in &self.size_histogram {
+            count.store(0, Ordering::Relaxed);
+        }
+        self.errors.store(0, Ordering::Relaxed);
+        self.corrupted.store(0, Ordering::Relaxed);
+        *self.last_error.lock().unwrap() = None;
+    }
 }
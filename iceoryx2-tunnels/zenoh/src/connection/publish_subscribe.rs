@@ -10,34 +10,135 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use super::z_is_keep_alive;
+use super::z_put_all;
 use super::Connection;
+use super::ConnectionDirection;
+use super::ConnectionHealth;
+use super::ConnectionStatistics;
+use super::ConnectionValidation;
+use super::Direction;
+use super::FailureTracker;
+use super::KeepAlive;
+use super::PayloadTaps;
 use super::PropagationError;
+use super::RateLimiter;
+use super::SaturationTracker;
+use super::TunneledConnection;
+#[cfg(feature = "checksum")]
+use crate::checksum;
+#[cfg(feature = "checksum")]
+use crate::checksum::ChecksumAlgorithm;
+#[cfg(feature = "compression")]
+use crate::compression;
 use crate::iox_create_publish_subscribe_service;
 use crate::iox_create_publisher;
 use crate::iox_create_subscriber;
+use crate::keys;
 use crate::z_announce_service;
 use crate::z_create_publisher;
 use crate::z_create_subscriber;
+use crate::IceoryxServiceLimits;
+use crate::PublisherOptions;
+use crate::ServiceAttachment;
+use crate::ZenohPublisherConfig;
+use crate::Z_SUBSCRIBER_BUFFER_SIZE;
 
 use iceoryx2::node::Node as IceoryxNode;
 use iceoryx2::node::NodeId as IceoryxNodeId;
 use iceoryx2::port::publisher::Publisher as IceoryxPublisher;
 use iceoryx2::port::subscriber::Subscriber as IceoryxSubscriber;
+use iceoryx2::port::update_connections::UpdateConnections;
 use iceoryx2::service::builder::CustomHeaderMarker;
 use iceoryx2::service::builder::CustomPayloadMarker;
 use iceoryx2::service::port_factory::publish_subscribe::PortFactory as IceoryxPublishSubscribeService;
 use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
 use iceoryx2_bb_log::error;
 use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use zenoh::bytes::ZBytes;
 use zenoh::handlers::FifoChannelHandler;
 use zenoh::pubsub::Publisher as ZenohPublisher;
 use zenoh::pubsub::Subscriber as ZenohSubscriber;
+use zenoh::qos::CongestionControl;
+use zenoh::query::ConsolidationMode;
+use zenoh::query::Queryable as ZenohQueryable;
+use zenoh::query::Reply;
+use zenoh::sample::Locality;
 use zenoh::sample::Sample;
+#[cfg(feature = "latency-tracking")]
+use zenoh::time::Timestamp;
+#[cfg(feature = "latency-tracking")]
+use zenoh::time::TimestampId;
 use zenoh::Session as ZenohSession;
 use zenoh::Wait;
 
+use core::ops::Range;
+use core::sync::atomic::Ordering;
+
+#[cfg(feature = "compression")]
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::io::Read;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+#[cfg(feature = "latency-tracking")]
+use std::time::SystemTime;
+#[cfg(feature = "latency-tracking")]
+use std::time::UNIX_EPOCH;
+
+/// The size of the message id that prefixes the payloads on zenoh when tracking message ids.
+const MESSAGE_ID_SIZE: usize = core::mem::size_of::<u64>();
+
+/// Determines how the local payloads of a publish-subscribe connection are handled when they
+/// arrive faster than they may be propagated to remote hosts, i.e. while the rate limit set
+/// with `Tunnel::set_rate_limit()` is exhausted.
+///
+/// Dropping keeps the propagation unaffected at the cost of losing payloads. Blocking
+/// preserves the payloads by deferring them to later propagations for up to the timeout.
+/// Returning an error preserves the payloads and leaves the decision to the caller.
+///
+/// [`BackpressurePolicy::DropNewest`] and [`BackpressurePolicy::Block`] also determine the
+/// congestion control of the zenoh publishers of the connections, so that payloads are
+/// dropped or the publisher blocks when the zenoh network is congested.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum BackpressurePolicy {
+    /// Keep the local payloads pending and propagate them once the rate limit permits, in
+    /// the order they arrived. When as many payloads are pending as the subscriber buffer of
+    /// the service holds, the oldest pending payload is dropped. Requires a copy of every
+    /// pending payload.
+    DropOldest,
+    /// Drop the local payloads that arrive while the rate limit is exhausted.
+    #[default]
+    DropNewest,
+    /// Keep the local payloads queued in the subscriber of the connection and propagate them
+    /// in later propagations once the rate limit permits. Fails the propagation with
+    /// [`PropagationError::Backpressure`] when the rate limit is exhausted for longer than
+    /// `timeout`. Does not stall the propagation of the other connections.
+    Block { timeout: Duration },
+    /// Fail the propagation with [`PropagationError::Backpressure`] right away. The local
+    /// payloads remain queued in the subscriber of the connection.
+    ReturnError,
+}
+
+impl BackpressurePolicy {
+    /// The congestion control of the zenoh publishers corresponding to the policy, if any.
+    pub(crate) fn z_congestion_control(&self) -> Option<CongestionControl> {
+        match self {
+            BackpressurePolicy::DropNewest => Some(CongestionControl::Drop),
+            BackpressurePolicy::Block { .. } => Some(CongestionControl::Block),
+            BackpressurePolicy::DropOldest | BackpressurePolicy::ReturnError => None,
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum CreationError {
     Error,
@@ -51,17 +152,147 @@ impl core::fmt::Display for CreationError {
 
 impl core::error::Error for CreationError {}
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpliceError {
+    Error,
+}
+
+impl core::fmt::Display for SpliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "SpliceError::{self:?}")
+    }
+}
+
+impl core::error::Error for SpliceError {}
+
+/// Mirrors the history of an iceoryx2 service to replay it to the remote tunnels subscribing
+/// to the service later on.
+///
+/// iceoryx2 keeps the last `history_size()` payloads of a service in its publishers, but only
+/// delivers them to a subscriber once, when the subscriber connects during
+/// `Publisher::update_connections()` or the next `send()`. Since the history of a publisher
+/// cannot be requested again by a subscriber that is connected already, the connection keeps
+/// a copy of the most recent payloads it propagated instead and serves them to the remote
+/// tunnels querying the history when they subscribe, see [`z_query_history()`].
+struct HistoryReplay {
+    _z_queryable: ZenohQueryable<()>,
+    history: Arc<Mutex<VecDeque<ZBytes>>>,
+    history_size: usize,
+}
+
+impl HistoryReplay {
+    fn create(
+        z_session: &ZenohSession,
+        iox_service_config: &IceoryxServiceConfig,
+        z_attachment: &ZBytes,
+    ) -> Result<Self, CreationError> {
+        let history = Arc::new(Mutex::new(VecDeque::<ZBytes>::new()));
+        let z_key = keys::publish_subscribe_history(iox_service_config.service_id());
+
+        let replayed_history = history.clone();
+        let z_reply_key = z_key.clone();
+        let z_attachment = z_attachment.clone();
+        let iox_service_id = iox_service_config.service_id().as_str().to_string();
+        let iox_service_name = iox_service_config.name().to_string();
+        let z_queryable = z_session
+            .declare_queryable(z_key)
+            .callback(move |z_query| {
+                let history = replayed_history.lock().unwrap();
+                for z_payload in history.iter() {
+                    if let Err(e) = z_query
+                        .reply(z_reply_key.clone(), z_payload.clone())
+                        .attachment(z_attachment.clone())
+                        .wait()
+                    {
+                        error!("Failed to replay payload to zenoh: {}", e);
+                        return;
+                    }
+                }
+                info!(
+                    "REPLAYED(iceoryx->zenoh): PublishSubscribe {} [{}] history of {} payloads",
+                    iox_service_id,
+                    iox_service_name,
+                    history.len()
+                );
+            })
+            .allowed_origin(Locality::Remote)
+            .wait()
+            .map_err(|_e| CreationError::Error)?;
+
+        Ok(Self {
+            _z_queryable: z_queryable,
+            history,
+            history_size: iox_service_config.publish_subscribe().history_size(),
+        })
+    }
+
+    /// Records a propagated payload, dropping the oldest one once the history is full.
+    fn record(&self, z_payload: &ZBytes) {
+        let mut history = self.history.lock().unwrap();
+        if history.len() >= self.history_size {
+            history.pop_front();
+        }
+        history.push_back(z_payload.clone());
+    }
+}
+
+/// Queries the history of the service from the remote tunnels once, so that only the
+/// subscribing tunnel receives it, see [`HistoryReplay`].
+fn z_query_history(
+    z_session: &ZenohSession,
+    iox_service_config: &IceoryxServiceConfig,
+) -> Result<FifoChannelHandler<Reply>, CreationError> {
+    z_session
+        .get(keys::publish_subscribe_history(
+            iox_service_config.service_id(),
+        ))
+        // every reply is a payload of the history
+        .consolidation(ConsolidationMode::None)
+        .allowed_destination(Locality::Remote)
+        .wait()
+        .map_err(|_e| CreationError::Error)
+}
+
 /// A connection for propagating `iceoryx2` publish-subscribe payloads to remote hosts.
 pub(crate) struct OutboundPublishSubscribeConnection<'a, ServiceType: iceoryx2::service::Service> {
     iox_node_id: IceoryxNodeId,
     iox_service_config: IceoryxServiceConfig,
     iox_subscriber: IceoryxSubscriber<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
     z_publisher: ZenohPublisher<'a>,
+    z_additional_publishers: Vec<ZenohPublisher<'a>>,
+    z_attachment: ZBytes,
+    saturation: SaturationTracker,
+    max_queue_depth: IoxAtomicUsize,
+    statistics: Arc<ConnectionStatistics>,
+    taps: Arc<PayloadTaps>,
+    rate_limiter: Option<RateLimiter>,
+    #[cfg(feature = "compression")]
+    compression_codec: compression::CompressionCodec,
+    #[cfg(feature = "compression")]
+    compression_threshold: Option<usize>,
+    #[cfg(feature = "latency-tracking")]
+    z_timestamp_id: TimestampId,
+    receive_timeout: Option<Duration>,
+    backpressure_policy: BackpressurePolicy,
+    pending_payloads: Mutex<VecDeque<(Instant, Vec<u8>)>>,
+    blocked_since: Mutex<Option<Instant>>,
+    latency_budget: Option<Duration>,
+    expired: IoxAtomicU64,
+    keep_alive: KeepAlive,
+    history_replay: Option<HistoryReplay>,
+    #[cfg(feature = "checksum")]
+    checksum_verification: Option<ChecksumAlgorithm>,
+    message_id_tracking: bool,
+    next_message_id: IoxAtomicU64,
+    disabled: bool,
 }
 
-impl<ServiceType: iceoryx2::service::Service> OutboundPublishSubscribeConnection<'_, ServiceType> {
+impl<'a, ServiceType: iceoryx2::service::Service>
+    OutboundPublishSubscribeConnection<'a, ServiceType>
+{
     // Creates an outbound connection to remote hosts for publish-subscribe payloads for a
-    // particular service.
+    // particular service via the provided zenoh publisher.
+    #[cfg_attr(not(feature = "latency-tracking"), allow(unused_variables))]
     pub fn create(
         iox_node_id: &IceoryxNodeId,
         iox_service_config: &IceoryxServiceConfig,
@@ -71,30 +302,286 @@ impl<ServiceType: iceoryx2::service::Service> OutboundPublishSubscribeConnection
             CustomHeaderMarker,
         >,
         z_session: &ZenohSession,
+        z_publisher: ZenohPublisher<'a>,
+        statistics: &Arc<ConnectionStatistics>,
+        taps: &Arc<PayloadTaps>,
     ) -> Result<Self, CreationError> {
         let iox_subscriber = iox_create_subscriber::<ServiceType>(iox_service, iox_service_config)
             .map_err(|_e| CreationError::Error)?;
 
-        let z_publisher =
-            z_create_publisher(z_session, iox_service_config).map_err(|_e| CreationError::Error)?;
-
         Ok(Self {
             iox_node_id: *iox_node_id,
             iox_service_config: iox_service_config.clone(),
             iox_subscriber,
             z_publisher,
+            z_additional_publishers: Vec::new(),
+            z_attachment: ZBytes::from(ServiceAttachment::new(iox_service_config).to_json()),
+            saturation: SaturationTracker::default(),
+            max_queue_depth: IoxAtomicUsize::new(0),
+            statistics: statistics.clone(),
+            taps: taps.clone(),
+            rate_limiter: None,
+            #[cfg(feature = "compression")]
+            compression_codec: compression::CompressionCodec::default(),
+            #[cfg(feature = "compression")]
+            compression_threshold: None,
+            #[cfg(feature = "latency-tracking")]
+            z_timestamp_id: z_session.zid().into(),
+            receive_timeout: None,
+            backpressure_policy: BackpressurePolicy::default(),
+            pending_payloads: Mutex::new(VecDeque::new()),
+            blocked_since: Mutex::new(None),
+            latency_budget: None,
+            expired: IoxAtomicU64::new(0),
+            keep_alive: KeepAlive::default(),
+            history_replay: None,
+            #[cfg(feature = "checksum")]
+            checksum_verification: None,
+            message_id_tracking: false,
+            next_message_id: IoxAtomicU64::new(0),
+            disabled: false,
         })
     }
+
+    /// Returns the id of the next propagated payload if message ids are tracked.
+    fn next_message_id(&self) -> Option<u64> {
+        self.message_id_tracking
+            .then(|| self.next_message_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns `true` if a local payload received at the provided instant exceeds the latency
+    /// budget and must be dropped.
+    fn has_expired(&self, received_at: Instant) -> bool {
+        let Some(latency_budget) = self.latency_budget else {
+            return false;
+        };
+        if received_at.elapsed() <= latency_budget {
+            return false;
+        }
+
+        self.expired.fetch_add(1, Ordering::Relaxed);
+        info!(
+            "DROPPED(iceoryx->zenoh): PublishSubscribe {} [{}] exceeds the latency budget of {:?}",
+            self.iox_service_config.service_id().as_str(),
+            self.iox_service_config.name(),
+            latency_budget
+        );
+        true
+    }
+
+    /// Encodes a local payload in the wire format of the connection, i.e. compressed and
+    /// framed by its message id and checksum as configured.
+    #[cfg_attr(feature = "checksum", allow(clippy::let_and_return))]
+    fn encode_payload(&self, bytes: &[u8]) -> ZBytes {
+        // TODO(optimization): Is it possible to create the ZBytes struct without copy?
+        #[cfg(feature = "compression")]
+        let z_payload = ZBytes::from(compression::compress(
+            bytes,
+            self.compression_codec,
+            self.compression_threshold,
+        ));
+        #[cfg(not(feature = "compression"))]
+        let z_payload = ZBytes::from(bytes);
+        let z_payload = z_prepend_message_id(z_payload, self.next_message_id());
+        #[cfg(feature = "checksum")]
+        let z_payload = z_append_checksum(z_payload, self.checksum_verification);
+
+        z_payload
+    }
+
+    /// Propagates a local payload received at the provided instant to remote hosts. Returns
+    /// `false` if the payload was dropped since it exceeded the latency budget.
+    fn propagate_payload(
+        &self,
+        bytes: &[u8],
+        received_at: Instant,
+    ) -> Result<bool, PropagationError> {
+        let z_payload = self.encode_payload(bytes);
+        if self.has_expired(received_at) {
+            return Ok(false);
+        }
+        if let Err(e) = self.z_put(z_payload.clone()) {
+            error!("Failed to propagate payload to zenoh: {}", e);
+            return Err(PropagationError::ZenohPutFailed);
+        }
+        if let Some(history_replay) = &self.history_replay {
+            history_replay.record(&z_payload);
+        }
+
+        self.statistics.record_iceoryx_to_zenoh();
+        self.statistics.record_iceoryx_to_zenoh_bytes(bytes.len());
+        self.taps.notify(Direction::IceoryxToZenoh, bytes);
+        info!(
+            "PROPAGATED(iceoryx->zenoh): PublishSubscribe {} [{}]",
+            self.iox_service_config.service_id().as_str(),
+            self.iox_service_config.name()
+        );
+
+        Ok(true)
+    }
+
+    /// Propagates the pending local payloads in the order they arrived until the rate limit
+    /// is exhausted.
+    fn propagate_pending_payloads(&self) -> Result<usize, PropagationError> {
+        let mut pending_payloads = self.pending_payloads.lock().unwrap();
+        let mut propagated = 0;
+        while !pending_payloads.is_empty() {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                if !rate_limiter.try_acquire() {
+                    break;
+                }
+            }
+
+            if let Some((received_at, bytes)) = pending_payloads.pop_front() {
+                if self.propagate_payload(&bytes, received_at)? {
+                    propagated += 1;
+                }
+            }
+        }
+
+        Ok(propagated)
+    }
+
+    /// Keeps a local payload pending until the rate limit permits propagating it. The oldest
+    /// pending payload is dropped when the subscriber buffer of the service is exceeded.
+    fn defer_payload(&self, bytes: &[u8], received_at: Instant) {
+        let mut pending_payloads = self.pending_payloads.lock().unwrap();
+        if pending_payloads.len() >= self.iox_subscriber.buffer_size().max(1) {
+            pending_payloads.pop_front();
+            self.saturation.record_drop();
+            info!(
+                "DROPPED(iceoryx->zenoh): PublishSubscribe {} [{}] oldest pending payload due to backpressure",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+        }
+        pending_payloads.push_back((received_at, bytes.to_vec()));
+    }
+
+    /// Applies the backpressure policy before receiving the next local payload while the
+    /// rate limit is exhausted. Returns whether the next local payload shall be received, the
+    /// remaining local payloads stay queued in the subscriber otherwise.
+    fn await_rate_limit(&self) -> Result<bool, PropagationError> {
+        let Some(rate_limiter) = &self.rate_limiter else {
+            return Ok(true);
+        };
+        if !matches!(
+            self.backpressure_policy,
+            BackpressurePolicy::Block { .. } | BackpressurePolicy::ReturnError
+        ) {
+            return Ok(true);
+        }
+        if !rate_limiter.is_exhausted() {
+            *self.blocked_since.lock().unwrap() = None;
+            return Ok(true);
+        }
+
+        let has_samples = self.iox_subscriber.has_samples().map_err(|e| {
+            error!("Failed to check for local payloads: {}", e);
+            PropagationError::IceoryxReceiveFailed
+        })?;
+        if !has_samples {
+            return Ok(true);
+        }
+
+        if let BackpressurePolicy::Block { timeout } = self.backpressure_policy {
+            let mut blocked_since = self.blocked_since.lock().unwrap();
+            let blocked_since = *blocked_since.get_or_insert_with(Instant::now);
+            if blocked_since.elapsed() < timeout {
+                // Defer the local payloads to the next propagation instead of stalling the tunnel
+                return Ok(false);
+            }
+        }
+
+        warn!(
+            "BACKPRESSURE(iceoryx->zenoh): PublishSubscribe {} [{}] exceeds the rate limit",
+            self.iox_service_config.service_id().as_str(),
+            self.iox_service_config.name()
+        );
+        Err(PropagationError::Backpressure)
+    }
+
+    /// Publishes a keep-alive to remote hosts if no payload was published within the
+    /// keep-alive interval.
+    fn publish_keep_alive(&self) -> Result<(), PropagationError> {
+        match self.keep_alive.publish_if_idle(
+            core::iter::once(&self.z_publisher).chain(&self.z_additional_publishers),
+        ) {
+            Ok(true) => {
+                info!(
+                    "KEEP-ALIVE(iceoryx->zenoh): PublishSubscribe {} [{}]",
+                    self.iox_service_config.service_id().as_str(),
+                    self.iox_service_config.name()
+                );
+                Ok(())
+            }
+            Ok(false) => Ok(()),
+            Err(e) => {
+                error!("Failed to publish keep-alive to zenoh: {}", e);
+                Err(PropagationError::ZenohPutFailed)
+            }
+        }
+    }
+
+    /// Publishes a payload to remote hosts on every zenoh session of the connection, even if
+    /// publishing fails on some of them.
+    fn z_put(&self, z_payload: ZBytes) -> Result<(), zenoh::Error> {
+        z_put_all(
+            core::iter::once(&self.z_publisher).chain(&self.z_additional_publishers),
+            |z_publisher| {
+                let z_put = z_publisher
+                    .put(z_payload.clone())
+                    .attachment(self.z_attachment.clone());
+                #[cfg(feature = "latency-tracking")]
+                let z_put = z_put.timestamp(z_timestamp_now(self.z_timestamp_id));
+                z_put.wait()
+            },
+        )?;
+        self.keep_alive.record_published();
+
+        Ok(())
+    }
 }
 
 impl<ServiceType: iceoryx2::service::Service> Connection
     for OutboundPublishSubscribeConnection<'_, ServiceType>
 {
     /// Propagate local payloads received on the service to remote hosts.
-    fn propagate(&self) -> Result<(), PropagationError> {
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        if self.disabled {
+            return Ok(0);
+        }
+
+        self.saturation.clear_saturation();
+        let mut propagated = self.propagate_pending_payloads()?;
+        let mut received = 0;
+        let start = Instant::now();
         loop {
+            if let Some(receive_timeout) = self.receive_timeout {
+                if start.elapsed() >= receive_timeout {
+                    warn!(
+                        "TIMEOUT(iceoryx->zenoh): PublishSubscribe {} [{}] after propagating {} payloads",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name(),
+                        propagated
+                    );
+                    return Err(PropagationError::ReceiveTimeout);
+                }
+            }
+            if !self.await_rate_limit()? {
+                break;
+            }
+
             match unsafe { self.iox_subscriber.receive_custom_payload() } {
                 Ok(Some(sample)) => {
+                    let received_at = Instant::now();
+                    received += 1;
+                    self.max_queue_depth.fetch_max(received, Ordering::Relaxed);
+                    if received == self.iox_subscriber.buffer_size() {
+                        // The buffer was full, older samples may have been dropped.
+                        self.saturation.record_saturation();
+                    }
+
                     if sample.header().node_id() == self.iox_node_id {
                         // Ignore samples published by the gateway itself to prevent loopback.
                         continue;
@@ -104,36 +591,74 @@ impl<ServiceType: iceoryx2::service::Service> Connection
                     let len = sample.len();
                     let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
 
-                    // TODO(optimization): Is it possible to create the ZBytes struct without copy?
-                    let z_payload = ZBytes::from(bytes);
-                    if let Err(e) = self.z_publisher.put(z_payload).wait() {
-                        error!("Failed to propagate payload to zenoh: {}", e);
-                        return Err(PropagationError::Error);
+                    let is_drop_oldest = self.backpressure_policy == BackpressurePolicy::DropOldest;
+                    if is_drop_oldest && !self.pending_payloads.lock().unwrap().is_empty() {
+                        // Pending payloads are propagated first to preserve the order
+                        self.defer_payload(bytes, received_at);
+                        propagated += self.propagate_pending_payloads()?;
+                        continue;
                     }
 
-                    info!(
-                        "PROPAGATED(iceoryx->zenoh): PublishSubscribe {} [{}]",
-                        self.iox_service_config.service_id().as_str(),
-                        self.iox_service_config.name()
-                    );
+                    if let Some(rate_limiter) = &self.rate_limiter {
+                        if !rate_limiter.try_acquire() {
+                            if is_drop_oldest {
+                                self.defer_payload(bytes, received_at);
+                                continue;
+                            }
+
+                            self.saturation.record_drop();
+                            info!(
+                                "DROPPED(iceoryx->zenoh): PublishSubscribe {} [{}] exceeds the rate limit",
+                                self.iox_service_config.service_id().as_str(),
+                                self.iox_service_config.name()
+                            );
+                            continue;
+                        }
+                    }
+
+                    if self.propagate_payload(bytes, received_at)? {
+                        propagated += 1;
+                    }
                 }
                 Ok(None) => break, // No more samples available
                 Err(e) => {
                     error!("Failed to receive custom payload from iceoryx: {}", e);
-                    return Err(PropagationError::Error);
+                    return Err(PropagationError::IceoryxReceiveFailed);
                 }
             }
         }
 
-        Ok(())
+        self.publish_keep_alive()?;
+
+        Ok(propagated)
     }
 }
 
+/// The reasons a remote payload is dropped before it is decoded.
+enum DroppedPayload {
+    /// The checksum of the payload does not match.
+    ChecksumMismatch,
+    /// The payload is too short to be prefixed by its message id.
+    MissingMessageId,
+}
+
 /// A connection for propagating `iceoryx2` publish-subscribe payloads from remote hosts.
 pub(crate) struct InboundPublishSubscribeConnection<ServiceType: iceoryx2::service::Service> {
     iox_service_config: IceoryxServiceConfig,
     iox_publisher: IceoryxPublisher<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
     z_subscriber: ZenohSubscriber<FifoChannelHandler<Sample>>,
+    z_additional_subscribers: Vec<ZenohSubscriber<FifoChannelHandler<Sample>>>,
+    z_history_replies: Option<FifoChannelHandler<Reply>>,
+    saturation: SaturationTracker,
+    statistics: Arc<ConnectionStatistics>,
+    taps: Arc<PayloadTaps>,
+    #[cfg(feature = "checksum")]
+    checksum_verification: Option<ChecksumAlgorithm>,
+    message_id_tracking: bool,
+    // The raw attachments of the remote services payloads were received from, so that the
+    // origin of the payloads is only parsed and logged once
+    known_origins: Mutex<HashSet<Vec<u8>>>,
+    disabled: bool,
 }
 
 impl<ServiceType: iceoryx2::service::Service> InboundPublishSubscribeConnection<ServiceType> {
@@ -147,27 +672,184 @@ impl<ServiceType: iceoryx2::service::Service> InboundPublishSubscribeConnection<
             CustomHeaderMarker,
         >,
         z_session: &ZenohSession,
+        z_key: &str,
+        statistics: &Arc<ConnectionStatistics>,
+        taps: &Arc<PayloadTaps>,
     ) -> Result<Self, CreationError> {
         let iox_publisher =
             iox_create_publisher::<ServiceType>(iox_publish_subscribe_service, iox_service_config)
                 .map_err(|_e| CreationError::Error)?;
-        let z_subscriber = z_create_subscriber(z_session, iox_service_config)
+        let z_subscriber = z_create_subscriber(z_session, z_key, iox_service_config)
             .map_err(|_e| CreationError::Error)?;
 
         Ok(Self {
             iox_service_config: iox_service_config.clone(),
             iox_publisher,
             z_subscriber,
+            z_additional_subscribers: Vec::new(),
+            z_history_replies: None,
+            saturation: SaturationTracker::default(),
+            statistics: statistics.clone(),
+            taps: taps.clone(),
+            #[cfg(feature = "checksum")]
+            checksum_verification: None,
+            message_id_tracking: false,
+            known_origins: Mutex::new(HashSet::new()),
+            disabled: false,
+        })
+    }
+
+    /// Logs the origin of the first payload received from each remote service.
+    fn log_new_origin(&self, z_attachment: &ZBytes) {
+        let z_attachment = z_attachment.to_bytes();
+        let mut known_origins = self.known_origins.lock().unwrap();
+        if known_origins.contains(z_attachment.as_ref()) {
+            return;
+        }
+
+        match ServiceAttachment::from_json(&z_attachment) {
+            Some(origin) => info!(
+                "RECEIVED(zenoh): PublishSubscribe {} [{}] from {} [{}]",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name(),
+                origin.service_id,
+                origin.service_name
+            ),
+            None => warn!(
+                "RECEIVED(zenoh): PublishSubscribe {} [{}] with an attachment not identifying its origin",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            ),
+        }
+        known_origins.insert(z_attachment.into_owned());
+    }
+
+    /// Returns the zenoh subscribers of all zenoh sessions of the connection.
+    fn z_subscribers(&self) -> impl Iterator<Item = &ZenohSubscriber<FifoChannelHandler<Sample>>> {
+        core::iter::once(&self.z_subscriber).chain(&self.z_additional_subscribers)
+    }
+
+    /// Locates the encoded payload within a remote payload in the wire format of the
+    /// connection, i.e. behind its message id and before its checksum as configured.
+    ///
+    /// Returns the message id of the payload if message ids are tracked, along with the range
+    /// of the encoded payload.
+    fn unframe_payload(
+        &self,
+        z_payload: &ZBytes,
+    ) -> Result<(Option<u64>, Range<usize>), DroppedPayload> {
+        #[cfg(feature = "checksum")]
+        let checksum_len = match self.checksum_verification {
+            Some(algorithm) => {
+                if !checksum::verify(algorithm, &z_payload.to_bytes()) {
+                    return Err(DroppedPayload::ChecksumMismatch);
+                }
+                algorithm.size()
+            }
+            None => 0,
+        };
+        #[cfg(not(feature = "checksum"))]
+        let checksum_len = 0;
+
+        let message_id_len = if self.message_id_tracking {
+            MESSAGE_ID_SIZE
+        } else {
+            0
+        };
+        if z_payload.len() < message_id_len + checksum_len {
+            return Err(DroppedPayload::MissingMessageId);
+        }
+        let message_id = if self.message_id_tracking {
+            let mut message_id = [0u8; MESSAGE_ID_SIZE];
+            z_payload
+                .reader()
+                .read_exact(&mut message_id)
+                .ok()
+                .map(|()| u64::from_le_bytes(message_id))
+        } else {
+            None
+        };
+
+        Ok((message_id, message_id_len..z_payload.len() - checksum_len))
+    }
+
+    /// Decompresses an encoded remote payload, rejecting payloads exceeding the payload size
+    /// of the service.
+    #[cfg(feature = "compression")]
+    fn decompress_payload<'p>(&self, bytes: &'p [u8]) -> Result<Cow<'p, [u8]>, PropagationError> {
+        let iox_message_type_details = self
+            .iox_service_config
+            .publish_subscribe()
+            .message_type_details();
+        compression::decompress(
+            bytes,
+            compression::max_decompressed_size(&iox_message_type_details.payload),
+        )
+        .map_err(|e| {
+            error!(
+                "Failed to decompress payload ({}): {:?}",
+                self.iox_service_config.name(),
+                e
+            );
+            PropagationError::MalformedRemotePayload
         })
     }
+
+    /// Decodes a remote payload in the wire format of the connection into the payload that
+    /// is published on iceoryx, without recording it in the statistics.
+    fn decode_payload(&self, z_payload: &ZBytes) -> Result<Vec<u8>, PropagationError> {
+        let (_, payload_range) = self
+            .unframe_payload(z_payload)
+            .map_err(|_e| PropagationError::MalformedRemotePayload)?;
+        let z_payload_bytes = z_payload.to_bytes();
+        #[cfg(feature = "compression")]
+        let payload = self
+            .decompress_payload(&z_payload_bytes[payload_range])?
+            .into_owned();
+        #[cfg(not(feature = "compression"))]
+        let payload = z_payload_bytes[payload_range].to_vec();
+
+        Ok(payload)
+    }
 }
 
 impl<ServiceType: iceoryx2::service::Service> Connection
     for InboundPublishSubscribeConnection<ServiceType>
 {
     /// Propagate remote publish-subscribe payloads received on the service to the local host.
-    fn propagate(&self) -> Result<(), PropagationError> {
-        for z_sample in self.z_subscriber.drain() {
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        if self.disabled {
+            return Ok(0);
+        }
+
+        let mut propagated = 0;
+        if self
+            .z_subscribers()
+            .any(|z_subscriber| z_subscriber.is_full())
+        {
+            self.saturation.record_saturation();
+        } else {
+            self.saturation.clear_saturation();
+        }
+
+        // The replayed history precedes the payloads received since subscribing
+        let z_history = self
+            .z_history_replies
+            .iter()
+            .flat_map(|z_replies| z_replies.drain())
+            .filter_map(|z_reply| z_reply.into_result().ok());
+        for z_sample in z_history.chain(
+            self.z_subscribers()
+                .flat_map(|z_subscriber| z_subscriber.drain()),
+        ) {
+            if z_is_keep_alive(&z_sample) {
+                continue;
+            }
+
+            if let Some(z_attachment) = z_sample.attachment() {
+                self.log_new_origin(z_attachment);
+            }
+
             let iox_message_type_details = self
                 .iox_service_config
                 .publish_subscribe()
@@ -178,15 +860,69 @@ impl<ServiceType: iceoryx2::service::Service> Connection
             // TODO(correctness): verify size and alignment
             let z_payload = z_sample.payload();
 
-            let number_of_elements = z_payload.len() / iox_payload_size;
+            let payload_range = match self.unframe_payload(z_payload) {
+                Ok((message_id, payload_range)) => {
+                    if let Some(message_id) = message_id {
+                        self.statistics.record_received_message_id(message_id);
+                    }
+                    payload_range
+                }
+                Err(DroppedPayload::ChecksumMismatch) => {
+                    warn!(
+                        "Dropped payload with mismatching checksum ({})",
+                        self.iox_service_config.name()
+                    );
+                    self.statistics.record_corrupted();
+                    continue;
+                }
+                Err(DroppedPayload::MissingMessageId) => {
+                    warn!(
+                        "Dropped payload lacking its message id ({})",
+                        self.iox_service_config.name()
+                    );
+                    continue;
+                }
+            };
+
+            #[cfg(feature = "compression")]
+            let z_payload_bytes = z_payload.to_bytes();
+            #[cfg(feature = "compression")]
+            let payload = self.decompress_payload(&z_payload_bytes[payload_range])?;
+            #[cfg(feature = "compression")]
+            let (payload_len, payload_slices, payload_offset) =
+                (payload.len(), core::iter::once(&*payload), 0);
+            #[cfg(not(feature = "compression"))]
+            let (payload_len, payload_slices, payload_offset) =
+                (payload_range.len(), z_payload.slices(), payload_range.start);
+
+            let number_of_elements = payload_len / iox_payload_size;
             unsafe {
                 match self.iox_publisher.loan_custom_payload(number_of_elements) {
                     Ok(mut iox_sample) => {
-                        core::ptr::copy_nonoverlapping(
-                            z_payload.to_bytes().as_ptr(),
-                            iox_sample.payload_mut().as_mut_ptr() as *mut u8,
-                            z_payload.len(),
-                        );
+                        // Write the payload slices directly into the uninitialized loan,
+                        // `ZBytes::to_bytes()` would copy fragmented payloads into an
+                        // intermediate buffer first.
+                        let iox_payload = iox_sample.payload_mut().as_mut_ptr() as *mut u8;
+                        let mut offset = 0;
+                        let mut skipped = 0;
+                        for payload_slice in payload_slices {
+                            // the message id is not part of the payload
+                            let skip = (payload_offset - skipped).min(payload_slice.len());
+                            skipped += skip;
+                            let payload_slice = &payload_slice[skip..];
+                            // the checksum is not part of the payload
+                            let len = payload_slice.len().min(payload_len - offset);
+                            core::ptr::copy_nonoverlapping(
+                                payload_slice.as_ptr(),
+                                iox_payload.add(offset),
+                                len,
+                            );
+                            offset += len;
+                        }
+                        // The taps are notified once the sample was published, which makes
+                        // its payload inaccessible
+                        let tapped_payload = (!self.taps.is_empty())
+                            .then(|| core::slice::from_raw_parts(iox_payload, offset).to_vec());
                         let iox_sample = iox_sample.assume_init();
                         if let Err(e) = iox_sample.send() {
                             error!(
@@ -194,13 +930,26 @@ impl<ServiceType: iceoryx2::service::Service> Connection
                                 self.iox_service_config.name(),
                                 e
                             );
-                            return Err(PropagationError::Error);
+                            return Err(PropagationError::IceoryxSendFailed);
+                        }
+                        if let Some(tapped_payload) = tapped_payload {
+                            self.taps.notify(Direction::ZenohToIceoryx, &tapped_payload);
+                        }
+                        self.statistics.record_zenoh_to_iceoryx();
+                        self.statistics.record_zenoh_to_iceoryx_bytes(payload_len);
+                        #[cfg(feature = "latency-tracking")]
+                        if let Some(z_timestamp) = z_sample.timestamp() {
+                            let published_at = z_timestamp.get_time().to_system_time();
+                            if let Ok(latency) = SystemTime::now().duration_since(published_at) {
+                                self.statistics.record_latency(latency);
+                            }
                         }
                         info!(
                             "PROPAGATED(iceoryx<-zenoh): PublishSubscribe {} [{}]",
                             self.iox_service_config.service_id().as_str(),
                             self.iox_service_config.name()
                         );
+                        propagated += 1;
                     }
                     Err(e) => {
                         error!(
@@ -208,69 +957,670 @@ impl<ServiceType: iceoryx2::service::Service> Connection
                             self.iox_service_config.name(),
                             e
                         );
-                        return Err(PropagationError::Error);
+                        return Err(PropagationError::IceoryxLoanFailed);
                     }
                 }
             }
         }
 
-        Ok(())
+        Ok(propagated)
     }
 }
 
 /// Couples the outbound and inbound connection for publish-subscribe payloads
-/// from particular iceoryx2 service.
+/// from particular iceoryx2 service. Unidirectional connections lack the connection of the
+/// other direction.
 pub(crate) struct BidirectionalPublishSubscribeConnection<
     'a,
     ServiceType: iceoryx2::service::Service,
 > {
-    outbound_connection: OutboundPublishSubscribeConnection<'a, ServiceType>,
-    inbound_connection: InboundPublishSubscribeConnection<ServiceType>,
+    outbound_connection: Option<OutboundPublishSubscribeConnection<'a, ServiceType>>,
+    inbound_connection: Option<InboundPublishSubscribeConnection<ServiceType>>,
+    iox_service_config: IceoryxServiceConfig,
+    z_session: ZenohSession,
+    z_key: String,
+    z_publisher_options: PublisherOptions,
+    failures: FailureTracker,
+    statistics: Arc<ConnectionStatistics>,
+    taps: Arc<PayloadTaps>,
 }
 
 impl<ServiceType: iceoryx2::service::Service>
     BidirectionalPublishSubscribeConnection<'_, ServiceType>
 {
     /// Create a bi-directional connection to propagate payloads for a particular iceoryx2 service
-    /// to and from remote iceoryx2 instances via Zenoh.
+    /// to and from remote iceoryx2 instances via Zenoh, or a unidirectional connection that
+    /// only propagates in the provided direction. Local payloads are only published to the
+    /// zenoh instances of the allowed destination.
     pub fn create(
         iox_node: &IceoryxNode<ServiceType>,
         z_session: &ZenohSession,
         iox_service_config: &IceoryxServiceConfig,
+        z_key: &str,
+        direction: ConnectionDirection,
+        z_publisher_config: ZenohPublisherConfig,
+        iox_service_limits: IceoryxServiceLimits,
     ) -> Result<Self, CreationError> {
-        let iox_publish_subscribe_service =
-            iox_create_publish_subscribe_service::<ServiceType>(iox_node, iox_service_config)
-                .map_err(|_e| CreationError::Error)?;
-
-        let outbound_connection = OutboundPublishSubscribeConnection::create(
-            iox_node.id(),
-            iox_service_config,
-            &iox_publish_subscribe_service,
-            z_session,
-        )?;
-        let inbound_connection = InboundPublishSubscribeConnection::create(
+        let iox_publish_subscribe_service = iox_create_publish_subscribe_service::<ServiceType>(
+            iox_node,
             iox_service_config,
-            &iox_publish_subscribe_service,
-            z_session,
-        )?;
+            iox_service_limits,
+        )
+        .map_err(|_e| CreationError::Error)?;
+
+        let statistics = Arc::new(ConnectionStatistics::default());
+        let taps = Arc::new(PayloadTaps::default());
+        let outbound_connection = if direction.includes_iceoryx_to_zenoh() {
+            let z_publisher =
+                z_create_publisher(z_session, z_key, iox_service_config, z_publisher_config)
+                    .map_err(|_e| CreationError::Error)?;
+            Some(OutboundPublishSubscribeConnection::create(
+                iox_node.id(),
+                iox_service_config,
+                &iox_publish_subscribe_service,
+                z_session,
+                z_publisher,
+                &statistics,
+                &taps,
+            )?)
+        } else {
+            None
+        };
+        let inbound_connection = if direction.includes_zenoh_to_iceoryx() {
+            Some(InboundPublishSubscribeConnection::create(
+                iox_service_config,
+                &iox_publish_subscribe_service,
+                z_session,
+                z_key,
+                &statistics,
+                &taps,
+            )?)
+        } else {
+            None
+        };
 
         z_announce_service(z_session, iox_service_config).map_err(|_e| CreationError::Error)?;
 
         Ok(Self {
             outbound_connection,
             inbound_connection,
+            iox_service_config: iox_service_config.clone(),
+            z_session: z_session.clone(),
+            z_key: z_key.to_string(),
+            z_publisher_options: z_publisher_config.options,
+            failures: FailureTracker::default(),
+            statistics,
+            taps,
         })
     }
+
+    /// Returns the configuration of the iceoryx2 service of this connection.
+    pub fn service_config(&self) -> &IceoryxServiceConfig {
+        &self.iox_service_config
+    }
+
+    /// Disables or re-enables propagating local payloads to remote hosts.
+    pub fn set_iceoryx_to_zenoh_disabled(&mut self, disabled: bool) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.disabled = disabled;
+        }
+    }
+
+    /// Disables or re-enables propagating remote payloads to the local host.
+    pub fn set_zenoh_to_iceoryx_disabled(&mut self, disabled: bool) {
+        if let Some(inbound_connection) = &mut self.inbound_connection {
+            inbound_connection.disabled = disabled;
+        }
+    }
+
+    /// Limits the rate at which local payloads are propagated to remote hosts, payloads
+    /// exceeding the limit are dropped. `None` removes the limit.
+    pub fn set_rate_limit(&mut self, messages_per_second: Option<u32>) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.rate_limiter = messages_per_second.map(RateLimiter::new);
+        }
+    }
+
+    /// Compresses local payloads larger than the provided size in bytes before propagating
+    /// them to remote hosts, smaller payloads are propagated as-is.
+    #[cfg(feature = "compression")]
+    pub fn set_compression_threshold(&mut self, min_size_bytes: usize) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.compression_threshold = Some(min_size_bytes);
+        }
+    }
+
+    /// Sets the codec local payloads are compressed with once they exceed the compression
+    /// threshold, see [`BidirectionalPublishSubscribeConnection::set_compression_threshold()`].
+    #[cfg(feature = "compression")]
+    pub fn set_compression_codec(&mut self, codec: compression::CompressionCodec) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.compression_codec = codec;
+        }
+    }
+
+    /// Additionally propagates the payloads of this connection over another zenoh session,
+    /// e.g. to reach the hosts behind a second zenoh router. Only the directions of the
+    /// connection are attached.
+    pub fn attach_zenoh_session(
+        &mut self,
+        z_session: &ZenohSession,
+        z_allowed_destination: Locality,
+    ) -> Result<(), CreationError> {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            let z_publisher = z_create_publisher(
+                z_session,
+                &self.z_key,
+                &self.iox_service_config,
+                ZenohPublisherConfig {
+                    allowed_destination: z_allowed_destination,
+                    options: self.z_publisher_options,
+                },
+            )
+            .map_err(|_e| CreationError::Error)?;
+            outbound_connection
+                .z_additional_publishers
+                .push(z_publisher);
+        }
+        if let Some(inbound_connection) = &mut self.inbound_connection {
+            let z_subscriber =
+                z_create_subscriber(z_session, &self.z_key, &self.iox_service_config)
+                    .map_err(|_e| CreationError::Error)?;
+            inbound_connection
+                .z_additional_subscribers
+                .push(z_subscriber);
+        }
+
+        z_announce_service(z_session, &self.iox_service_config).map_err(|_e| CreationError::Error)
+    }
+
+    /// Appends a checksum computed with the provided algorithm to local payloads and drops the
+    /// remote payloads whose checksum does not match. `None` disables the checksums.
+    #[cfg(feature = "checksum")]
+    pub fn set_checksum_verification(&mut self, algorithm: Option<ChecksumAlgorithm>) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.checksum_verification = algorithm;
+        }
+        if let Some(inbound_connection) = &mut self.inbound_connection {
+            inbound_connection.checksum_verification = algorithm;
+        }
+    }
+
+    /// Prefixes local payloads with a sequence number incremented per payload and records
+    /// the sequence number of the remote payloads, e.g. to correlate a payload across hosts.
+    pub fn set_message_id_tracking(&mut self, enabled: bool) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.message_id_tracking = enabled;
+        }
+        if let Some(inbound_connection) = &mut self.inbound_connection {
+            inbound_connection.message_id_tracking = enabled;
+        }
+    }
+
+    /// Encodes a local payload in the wire format the connection publishes on zenoh, e.g. to
+    /// publish a payload as a remote host would. Returns `None` if the connection does not
+    /// propagate local payloads.
+    pub fn encode_payload(&self, bytes: &[u8]) -> Option<ZBytes> {
+        self.outbound_connection
+            .as_ref()
+            .map(|outbound_connection| outbound_connection.encode_payload(bytes))
+    }
+
+    /// Decodes a payload in the wire format of the connection into the payload that is
+    /// published on iceoryx, e.g. to inspect a payload received from zenoh. Returns `None` if
+    /// the connection does not propagate remote payloads.
+    pub fn decode_payload(&self, z_payload: &ZBytes) -> Option<Result<Vec<u8>, PropagationError>> {
+        self.inbound_connection
+            .as_ref()
+            .map(|inbound_connection| inbound_connection.decode_payload(z_payload))
+    }
+
+    /// Determines how local payloads are handled while the rate limit is exhausted.
+    pub fn set_backpressure_policy(&mut self, policy: BackpressurePolicy) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.backpressure_policy = policy;
+            *outbound_connection.blocked_since.get_mut().unwrap() = None;
+        }
+    }
+
+    /// Drops the local payloads that could not be propagated within the provided duration
+    /// after they were received. `None` removes the latency budget.
+    pub fn set_latency_budget(&mut self, budget: Option<Duration>) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.latency_budget = budget;
+        }
+    }
+
+    /// Returns the latency budget of the local payloads, if any.
+    pub fn latency_budget(&self) -> Option<Duration> {
+        self.outbound_connection
+            .as_ref()
+            .and_then(|outbound_connection| outbound_connection.latency_budget)
+    }
+
+    /// Returns the number of local payloads dropped since they exceeded the latency budget.
+    pub fn expired_payloads(&self) -> u64 {
+        self.outbound_connection
+            .as_ref()
+            .map_or(0, |outbound_connection| {
+                outbound_connection.expired.load(Ordering::Relaxed)
+            })
+    }
+
+    /// Returns the number of local payloads kept pending due to backpressure.
+    pub fn num_pending_payloads(&self) -> usize {
+        self.outbound_connection
+            .as_ref()
+            .map_or(0, |outbound_connection| {
+                outbound_connection.pending_payloads.lock().unwrap().len()
+            })
+    }
+
+    /// Serves the history of the service to remote tunnels subscribing to it later on and
+    /// queries the history from remote tunnels once, so that it is replayed to this connection
+    /// only. Services without history are not affected.
+    pub fn set_history_replay(&mut self, enabled: bool) -> Result<(), CreationError> {
+        let enabled = enabled && self.iox_service_config.publish_subscribe().history_size() > 0;
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            if outbound_connection.history_replay.is_some() != enabled {
+                outbound_connection.history_replay = if enabled {
+                    Some(HistoryReplay::create(
+                        &self.z_session,
+                        &self.iox_service_config,
+                        &outbound_connection.z_attachment,
+                    )?)
+                } else {
+                    None
+                };
+            }
+        }
+        if let Some(inbound_connection) = &mut self.inbound_connection {
+            if inbound_connection.z_history_replies.is_some() != enabled {
+                inbound_connection.z_history_replies = if enabled {
+                    Some(z_query_history(&self.z_session, &self.iox_service_config)?)
+                } else {
+                    None
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Publishes keep-alives to remote hosts whenever no local payload was propagated within
+    /// the provided interval. `None` disables the keep-alives.
+    pub fn set_keep_alive_interval(&mut self, interval: Option<Duration>) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.keep_alive.set_interval(interval);
+        }
+    }
+
+    /// Limits the duration spent receiving local payloads per propagation. `None` removes the
+    /// limit.
+    pub fn set_iceoryx_receive_timeout(&mut self, timeout: Option<Duration>) {
+        if let Some(outbound_connection) = &mut self.outbound_connection {
+            outbound_connection.receive_timeout = timeout;
+        }
+    }
+
+    /// Returns `true` if a rate limit is set and exhausted, i.e. the next local payload would
+    /// be dropped.
+    pub fn is_rate_limit_exceeded(&self) -> bool {
+        self.outbound_connection
+            .as_ref()
+            .and_then(|outbound_connection| outbound_connection.rate_limiter.as_ref())
+            .is_some_and(|rate_limiter| rate_limiter.is_exhausted())
+    }
+
+    /// Returns `true` if the zenoh subscribers receiving the remote payloads are still
+    /// connected to their zenoh sessions, `false` if one was dropped or remote payloads are
+    /// not received.
+    pub fn is_z_subscriber_alive(&self) -> bool {
+        self.inbound_connection
+            .as_ref()
+            .is_some_and(|inbound_connection| {
+                inbound_connection
+                    .z_subscribers()
+                    .all(|z_subscriber| !z_subscriber.is_disconnected())
+            })
+    }
+
+    /// Returns the rate limit of the local payloads in messages per second, if set.
+    pub fn rate_limit(&self) -> Option<f64> {
+        self.outbound_connection
+            .as_ref()
+            .and_then(|outbound_connection| outbound_connection.rate_limiter.as_ref())
+            .map(|rate_limiter| rate_limiter.messages_per_second())
+    }
+
+    /// Returns the quality of service of the zenoh publisher propagating the local payloads,
+    /// or the one it would be created with if local payloads are not propagated.
+    pub fn publisher_options(&self) -> PublisherOptions {
+        match &self.outbound_connection {
+            Some(outbound_connection) => PublisherOptions {
+                reliability: outbound_connection.z_publisher.reliability(),
+                congestion_control: outbound_connection.z_publisher.congestion_control(),
+                priority: outbound_connection.z_publisher.priority(),
+            },
+            None => self.z_publisher_options,
+        }
+    }
+
+    /// Returns the zenoh key expression payloads of this connection are published on.
+    pub fn z_key_expression(&self) -> String {
+        self.z_key.clone()
+    }
+
+    /// Returns `true` if local payloads are propagated to remote hosts over this connection.
+    pub fn is_iceoryx_to_zenoh(&self) -> bool {
+        self.outbound_connection.is_some()
+    }
+
+    /// Returns `true` if remote payloads are propagated to the local host over this
+    /// connection.
+    pub fn is_zenoh_to_iceoryx(&self) -> bool {
+        self.inbound_connection.is_some()
+    }
+
+    /// Returns the propagation failures tracked for this connection.
+    pub fn failures(&self) -> &FailureTracker {
+        &self.failures
+    }
+
+    /// Returns the liveness of this connection according to its most recent propagations.
+    pub fn health(&self) -> ConnectionHealth {
+        self.failures.health()
+    }
+
+    /// Validates that the iceoryx2 and zenoh ports of this connection are still usable and
+    /// that its propagations do not fail excessively.
+    pub fn validate(&self, z_session: &ZenohSession) -> ConnectionValidation {
+        if let Some(outbound_connection) = &self.outbound_connection {
+            if let Err(e) = outbound_connection.iox_subscriber.update_connections() {
+                return ConnectionValidation::Failed {
+                    reason: format!("iceoryx2 subscriber is invalid: {e:?}"),
+                };
+            }
+            if z_session.is_closed() {
+                return ConnectionValidation::Failed {
+                    reason: String::from("zenoh publisher is closed"),
+                };
+            }
+        }
+
+        if let Some(inbound_connection) = &self.inbound_connection {
+            if let Err(e) = inbound_connection.iox_publisher.update_connections() {
+                return ConnectionValidation::Failed {
+                    reason: format!("iceoryx2 publisher is invalid: {e:?}"),
+                };
+            }
+            if inbound_connection
+                .z_subscribers()
+                .any(|z_subscriber| z_subscriber.is_disconnected())
+            {
+                return ConnectionValidation::Failed {
+                    reason: String::from("zenoh subscriber is closed"),
+                };
+            }
+        }
+
+        ConnectionValidation::of_propagations(&self.failures, &self.statistics)
+    }
+
+    /// Returns the forwarding statistics tracked for this connection.
+    pub fn statistics(&self) -> &ConnectionStatistics {
+        &self.statistics
+    }
+
+    /// Returns the approximate number of heap bytes of the remote payloads buffered by the
+    /// connection when its buffer is full.
+    pub fn payload_buffer_bytes(&self) -> usize {
+        if self.inbound_connection.is_none() {
+            return 0;
+        }
+
+        let iox_payload_size = self
+            .iox_service_config
+            .publish_subscribe()
+            .message_type_details()
+            .payload
+            .size;
+
+        Z_SUBSCRIBER_BUFFER_SIZE * iox_payload_size
+    }
+
+    /// Returns the approximate number of heap bytes allocated for the histories of the
+    /// connection.
+    pub fn history_bytes(&self) -> usize {
+        self.statistics.history_bytes()
+    }
+
+    /// Returns the callbacks notified about every payload propagated over this connection.
+    pub fn taps(&self) -> &Arc<PayloadTaps> {
+        &self.taps
+    }
+
+    /// Returns the number of local samples the iceoryx2 subscriber of the connection may have
+    /// missed because the publisher was faster than the tunnel.
+    ///
+    /// The subscriber does not report how many samples were overwritten, so one missed sample
+    /// is accumulated for every propagation that found its buffer at capacity.
+    pub fn missed_iceoryx_samples(&self) -> u64 {
+        self.outbound_connection
+            .as_ref()
+            .map_or(0, |outbound_connection| {
+                outbound_connection.saturation.count()
+            })
+    }
+
+    /// Returns the maximum number of unread samples found in the buffer of the iceoryx2
+    /// subscriber of the connection by a single propagation, e.g. to tell whether the buffer
+    /// size of the service is sufficient.
+    pub fn max_iceoryx_queue_depth(&self) -> usize {
+        self.outbound_connection
+            .as_ref()
+            .map_or(0, |outbound_connection| {
+                outbound_connection.max_queue_depth.load(Ordering::Relaxed)
+            })
+    }
+
+    /// Returns `true` if the most recent propagation found a receive buffer in either
+    /// direction at capacity.
+    pub fn is_saturated(&self) -> bool {
+        let outbound = self
+            .outbound_connection
+            .as_ref()
+            .is_some_and(|outbound_connection| outbound_connection.saturation.is_saturated());
+        let inbound = self
+            .inbound_connection
+            .as_ref()
+            .is_some_and(|inbound_connection| inbound_connection.saturation.is_saturated());
+
+        outbound || inbound
+    }
+
+    /// Returns the number of local payloads the connection dropped due to backpressure, i.e.
+    /// since they exceeded the rate limit or the pending payloads.
+    pub fn dropped_payloads(&self) -> u64 {
+        self.outbound_connection
+            .as_ref()
+            .map_or(0, |outbound_connection| {
+                outbound_connection.saturation.drops()
+            })
+    }
+
+    /// Forwards all pending local payloads of the `source` connection to remote hosts via the
+    /// zenoh publisher of the `sink` connection instead of the one of `source`, e.g. to wire
+    /// the output of one service to the zenoh topic of another service. The payloads are
+    /// propagated like the ones of `sink`, i.e. subject to its rate limit, latency budget and
+    /// taps. Nothing is forwarded while the propagation of local payloads is disabled for
+    /// either connection.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of forwarded payloads
+    /// * `Err(SpliceError)` - If either connection does not propagate local payloads to remote
+    ///   hosts or receiving or publishing a payload failed
+    pub fn splice(source: &Self, sink: &mut Self) -> Result<u64, SpliceError> {
+        let (Some(source_outbound_connection), Some(sink_outbound_connection)) =
+            (&source.outbound_connection, &sink.outbound_connection)
+        else {
+            return Err(SpliceError::Error);
+        };
+        if source_outbound_connection.disabled
+            || sink_outbound_connection.disabled
+            || source.failures.is_disabled()
+            || sink.failures.is_disabled()
+        {
+            return Ok(0);
+        }
+
+        let iox_subscriber = &source_outbound_connection.iox_subscriber;
+        let rate_limiter = &sink_outbound_connection.rate_limiter;
+        let mut forwarded = 0;
+        loop {
+            // The remaining payloads are forwarded once the rate limit permits it
+            if rate_limiter
+                .as_ref()
+                .is_some_and(|rate_limiter| rate_limiter.is_exhausted())
+            {
+                break;
+            }
+
+            match unsafe { iox_subscriber.receive_custom_payload() } {
+                Ok(Some(sample)) => {
+                    if sample.header().node_id() == source_outbound_connection.iox_node_id {
+                        // Ignore samples published by the gateway itself to prevent loopback.
+                        continue;
+                    }
+
+                    let received_at = Instant::now();
+                    let ptr = sample.payload().as_ptr() as *const u8;
+                    let len = sample.len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    if let Some(rate_limiter) = rate_limiter {
+                        if !rate_limiter.try_acquire() {
+                            sink_outbound_connection.saturation.record_drop();
+                            continue;
+                        }
+                    }
+
+                    let is_propagated = sink_outbound_connection
+                        .propagate_payload(bytes, received_at)
+                        .map_err(|_e| SpliceError::Error)?;
+                    if !is_propagated {
+                        continue;
+                    }
+
+                    info!(
+                        "SPLICED(iceoryx->zenoh): PublishSubscribe {} [{}] -> {} [{}]",
+                        source.iox_service_config.service_id().as_str(),
+                        source.iox_service_config.name(),
+                        sink.iox_service_config.service_id().as_str(),
+                        sink.iox_service_config.name()
+                    );
+                    forwarded += 1;
+                }
+                Ok(None) => break, // No more samples available
+                Err(e) => {
+                    error!("Failed to receive custom payload from iceoryx: {}", e);
+                    return Err(SpliceError::Error);
+                }
+            }
+        }
+
+        Ok(forwarded)
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> TunneledConnection
+    for BidirectionalPublishSubscribeConnection<'_, ServiceType>
+{
+    fn service_config(&self) -> &IceoryxServiceConfig {
+        &self.iox_service_config
+    }
+
+    fn failures(&self) -> &FailureTracker {
+        &self.failures
+    }
+
+    fn statistics(&self) -> &ConnectionStatistics {
+        &self.statistics
+    }
 }
 
 impl<ServiceType: iceoryx2::service::Service> Connection
     for BidirectionalPublishSubscribeConnection<'_, ServiceType>
 {
     /// Propagate local payloads to remote host and remote payloads to the local host.
-    fn propagate(&self) -> Result<(), PropagationError> {
-        self.outbound_connection.propagate()?;
-        self.inbound_connection.propagate()?;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                service_id = self.iox_service_config.service_id().as_str(),
+                messaging_pattern = ?self.iox_service_config.messaging_pattern(),
+                messages_forwarded = tracing::field::Empty,
+            )
+        )
+    )]
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        let outbound = match &self.outbound_connection {
+            Some(outbound_connection) => outbound_connection
+                .propagate()
+                .inspect_err(|e| self.statistics.record_error(*e))?,
+            None => 0,
+        };
+        let inbound = match &self.inbound_connection {
+            Some(inbound_connection) => inbound_connection
+                .propagate()
+                .inspect_err(|e| self.statistics.record_error(*e))?,
+            None => 0,
+        };
+        self.statistics.record_ready();
 
-        Ok(())
+        let forwarded = outbound + inbound;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("messages_forwarded", forwarded);
+
+        Ok(forwarded)
+    }
+}
+
+/// Appends the checksum computed with the provided algorithm to a payload for zenoh, the
+/// payload is forwarded as-is if no algorithm is provided.
+#[cfg(feature = "checksum")]
+fn z_append_checksum(z_payload: ZBytes, algorithm: Option<ChecksumAlgorithm>) -> ZBytes {
+    match algorithm {
+        Some(algorithm) => ZBytes::from(checksum::append(
+            algorithm,
+            z_payload.to_bytes().into_owned(),
+        )),
+        None => z_payload,
+    }
+}
+
+/// Prefixes a payload for zenoh with the provided message id, the payload is forwarded as-is
+/// if no message id is provided.
+fn z_prepend_message_id(z_payload: ZBytes, message_id: Option<u64>) -> ZBytes {
+    match message_id {
+        Some(message_id) => {
+            let mut bytes = Vec::with_capacity(MESSAGE_ID_SIZE + z_payload.len());
+            bytes.extend_from_slice(&message_id.to_le_bytes());
+            bytes.extend_from_slice(&z_payload.to_bytes());
+            ZBytes::from(bytes)
+        }
+        None => z_payload,
     }
 }
+
+/// Returns a timestamp of the current system time to publish along with a payload, so that
+/// the receiving tunnel can determine the latency.
+#[cfg(feature = "latency-tracking")]
+fn z_timestamp_now(z_timestamp_id: TimestampId) -> Timestamp {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    Timestamp::new(now.into(), z_timestamp_id)
+}
@@ -0,0 +1,725 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Request-response services are mapped onto zenoh queries:
+//!
+//! * Requests of local clients are received by an iceoryx2 server of the tunnel and sent as
+//!   zenoh queries to the queryables of remote tunnels. Every reply to a query is sent as a
+//!   response to the local client. Once the query is finalized, the active request is
+//!   dropped, signalling the local client that no more responses follow.
+//! * Queries of remote tunnels are received by a zenoh queryable and sent as requests by an
+//!   iceoryx2 client of the tunnel to the local servers. Every response is sent as a reply to
+//!   the query. Once no local server is connected to the request anymore, the query is
+//!   dropped, finalizing it for the remote tunnel.
+//!
+//! The request and client ids of iceoryx2 never leave the host. Requests and responses are
+//! correlated locally by the active request and pending response they belong to, and across
+//! hosts by zenoh, which routes the replies to the query they belong to.
+
+use super::Connection;
+use super::ConnectionHealth;
+use super::ConnectionStatistics;
+use super::ConnectionValidation;
+use super::FailureTracker;
+use super::PropagationError;
+use super::TunneledConnection;
+use crate::iox_create_client;
+use crate::iox_create_request_response_service;
+use crate::iox_create_server;
+use crate::z_announce_service;
+use crate::z_create_querier;
+use crate::z_create_queryable;
+
+use iceoryx2::active_request::ActiveRequest;
+use iceoryx2::node::Node as IceoryxNode;
+use iceoryx2::pending_response::PendingResponse;
+use iceoryx2::port::client::Client as IceoryxClient;
+use iceoryx2::port::port_identifiers::UniqueClientId;
+use iceoryx2::port::server::Server as IceoryxServer;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::request_response::PortFactory as IceoryxRequestResponseService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::error;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+
+use zenoh::bytes::ZBytes;
+use zenoh::handlers::FifoChannelHandler;
+use zenoh::query::Querier as ZenohQuerier;
+use zenoh::query::Query;
+use zenoh::query::Queryable as ZenohQueryable;
+use zenoh::query::Reply;
+use zenoh::Session as ZenohSession;
+use zenoh::Wait;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+type IceoryxRequestResponseServiceType<ServiceType> = IceoryxRequestResponseService<
+    ServiceType,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+type IceoryxServerType<ServiceType> = IceoryxServer<
+    ServiceType,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+type IceoryxClientType<ServiceType> = IceoryxClient<
+    ServiceType,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// Returns the number of elements of the given size that a remote payload consists of, `None`
+/// if the payload does not consist of whole elements and cannot be loaned for.
+fn number_of_elements(payload_len: usize, payload_size: usize) -> Option<usize> {
+    if payload_size == 0 {
+        return (payload_len == 0).then_some(1);
+    }
+
+    (payload_len % payload_size == 0).then_some(payload_len / payload_size)
+}
+
+/// A request of a local client that awaits the replies of remote servers.
+///
+/// Only [`Send`] for thread-safe service types since the active request shares the state of
+/// the server.
+struct PendingRemoteReplies<ServiceType: iceoryx2::service::Service> {
+    iox_active_request: ActiveRequest<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    z_replies: FifoChannelHandler<Reply>,
+}
+
+/// A request of a remote client that awaits the responses of local servers.
+///
+/// Only [`Send`] for thread-safe service types since the pending response shares the state of
+/// the client.
+struct PendingLocalResponses<ServiceType: iceoryx2::service::Service> {
+    iox_pending_response: PendingResponse<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    z_query: Query,
+}
+
+/// A connection for propagating requests of local `iceoryx2` clients to remote hosts and
+/// the replies back to the clients.
+pub(crate) struct OutboundRequestResponseConnection<'a, ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_server: IceoryxServerType<ServiceType>,
+    iox_client_id: UniqueClientId,
+    z_querier: ZenohQuerier<'a>,
+    pending_requests: Mutex<Vec<PendingRemoteReplies<ServiceType>>>,
+    statistics: Arc<ConnectionStatistics>,
+    receive_timeout: Option<Duration>,
+    disabled: bool,
+}
+
+impl<ServiceType: iceoryx2::service::Service> OutboundRequestResponseConnection<'_, ServiceType> {
+    // Creates an outbound connection to remote hosts for requests for a particular service.
+    // Requests of the client with the provided id are ignored to prevent loopback.
+    pub fn create(
+        iox_client_id: UniqueClientId,
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxRequestResponseServiceType<ServiceType>,
+        z_session: &ZenohSession,
+        z_key: &str,
+        statistics: &Arc<ConnectionStatistics>,
+    ) -> Result<Self, CreationError> {
+        let iox_server = iox_create_server::<ServiceType>(iox_service, iox_service_config)
+            .map_err(|_e| CreationError::Error)?;
+        let z_querier = z_create_querier(z_session, z_key, iox_service_config)
+            .map_err(|_e| CreationError::Error)?;
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_server,
+            iox_client_id,
+            z_querier,
+            pending_requests: Mutex::new(Vec::new()),
+            statistics: statistics.clone(),
+            receive_timeout: None,
+            disabled: false,
+        })
+    }
+
+    /// Sends the replies received for a pending request as responses to the local client.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((usize, bool))` - The number of propagated replies and whether the query is
+    ///   finalized, i.e. no more replies follow
+    /// * `Err(PropagationError)` - If a response could not be sent
+    fn propagate_replies(
+        &self,
+        pending_request: &PendingRemoteReplies<ServiceType>,
+    ) -> Result<(usize, bool), PropagationError> {
+        let iox_payload_size = self
+            .iox_service_config
+            .request_response()
+            .response_message_type_details()
+            .payload
+            .size;
+
+        let mut propagated = 0;
+        loop {
+            let z_reply = match pending_request.z_replies.try_recv() {
+                Ok(Some(z_reply)) => z_reply,
+                Ok(None) => return Ok((propagated, false)),
+                // The channel is closed once the query is finalized
+                Err(_) => return Ok((propagated, true)),
+            };
+            let Ok(z_sample) = z_reply.result() else {
+                // Error replies of remote hosts are not propagated. Skip.
+                continue;
+            };
+
+            let z_payload = z_sample.payload();
+            let Some(number_of_elements) = number_of_elements(z_payload.len(), iox_payload_size)
+            else {
+                warn!(
+                    "Malformed reply of {} bytes ({}) is no multiple of the response size {}. Skip.",
+                    z_payload.len(),
+                    self.iox_service_config.name(),
+                    iox_payload_size
+                );
+                continue;
+            };
+            unsafe {
+                let mut iox_response = pending_request
+                    .iox_active_request
+                    .loan_custom_payload(number_of_elements)
+                    .map_err(|e| {
+                        error!(
+                            "Failed to loan response ({}): {}",
+                            self.iox_service_config.name(),
+                            e
+                        );
+                        PropagationError::IceoryxLoanFailed
+                    })?;
+                let iox_payload = iox_response.payload_mut().as_mut_ptr() as *mut u8;
+                let mut offset = 0;
+                for z_payload_slice in z_payload.slices() {
+                    core::ptr::copy_nonoverlapping(
+                        z_payload_slice.as_ptr(),
+                        iox_payload.add(offset),
+                        z_payload_slice.len(),
+                    );
+                    offset += z_payload_slice.len();
+                }
+                if let Err(e) = iox_response.assume_init().send() {
+                    error!(
+                        "Failed to send response ({}): {}",
+                        self.iox_service_config.name(),
+                        e
+                    );
+                    return Err(PropagationError::IceoryxSendFailed);
+                }
+            }
+
+            self.statistics.record_zenoh_to_iceoryx();
+            self.statistics
+                .record_zenoh_to_iceoryx_bytes(z_payload.len());
+            info!(
+                "PROPAGATED(iceoryx<-zenoh): Response {} [{}]",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+            propagated += 1;
+        }
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Connection
+    for OutboundRequestResponseConnection<'_, ServiceType>
+{
+    /// Propagate requests of local clients to remote hosts and their replies to the clients.
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        if self.disabled {
+            return Ok(0);
+        }
+
+        let mut propagated = 0;
+        let mut pending_requests = self.pending_requests.lock().unwrap();
+        let mut is_timed_out = false;
+        let start = Instant::now();
+        loop {
+            if let Some(receive_timeout) = self.receive_timeout {
+                if start.elapsed() >= receive_timeout {
+                    warn!(
+                        "TIMEOUT(iceoryx->zenoh): Request {} [{}] after propagating {} requests",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name(),
+                        propagated
+                    );
+                    // The replies of the pending requests are still propagated
+                    is_timed_out = true;
+                    break;
+                }
+            }
+
+            match unsafe { self.iox_server.receive_custom_payload() } {
+                Ok(Some(iox_active_request)) => {
+                    if iox_active_request.origin() == self.iox_client_id {
+                        // Ignore requests sent by the gateway itself to prevent loopback.
+                        continue;
+                    }
+
+                    let ptr = iox_active_request.payload().as_ptr() as *const u8;
+                    let len = iox_active_request.payload().len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    let z_replies = match self.z_querier.get().payload(ZBytes::from(bytes)).wait() {
+                        Ok(z_replies) => z_replies,
+                        Err(e) => {
+                            error!("Failed to propagate request to zenoh: {}", e);
+                            return Err(PropagationError::ZenohQueryFailed);
+                        }
+                    };
+
+                    self.statistics.record_iceoryx_to_zenoh();
+                    self.statistics.record_iceoryx_to_zenoh_bytes(len);
+                    info!(
+                        "PROPAGATED(iceoryx->zenoh): Request {} [{}]",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name()
+                    );
+                    pending_requests.push(PendingRemoteReplies {
+                        iox_active_request,
+                        z_replies,
+                    });
+                    propagated += 1;
+                }
+                Ok(None) => break, // No more requests available
+                Err(e) => {
+                    error!("Failed to receive custom payload from iceoryx: {}", e);
+                    return Err(PropagationError::IceoryxReceiveFailed);
+                }
+            }
+        }
+
+        let mut index = 0;
+        while index < pending_requests.len() {
+            let (replies, is_finalized) = self.propagate_replies(&pending_requests[index])?;
+            propagated += replies;
+            if is_finalized {
+                // Dropping the active request signals the local client that no more
+                // responses follow.
+                pending_requests.swap_remove(index);
+            } else {
+                index += 1;
+            }
+        }
+
+        if is_timed_out {
+            return Err(PropagationError::ReceiveTimeout);
+        }
+
+        Ok(propagated)
+    }
+}
+
+/// A connection for propagating requests of remote clients to local `iceoryx2` servers and
+/// the responses back to the remote clients.
+pub(crate) struct InboundRequestResponseConnection<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_client: IceoryxClientType<ServiceType>,
+    z_queryable: ZenohQueryable<FifoChannelHandler<Query>>,
+    pending_requests: Mutex<Vec<PendingLocalResponses<ServiceType>>>,
+    statistics: Arc<ConnectionStatistics>,
+    disabled: bool,
+}
+
+impl<ServiceType: iceoryx2::service::Service> InboundRequestResponseConnection<ServiceType> {
+    // Creates an inbound connection from remote hosts for requests for a particular service.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxRequestResponseServiceType<ServiceType>,
+        z_session: &ZenohSession,
+        z_key: &str,
+        statistics: &Arc<ConnectionStatistics>,
+    ) -> Result<Self, CreationError> {
+        let iox_client = iox_create_client::<ServiceType>(iox_service, iox_service_config)
+            .map_err(|_e| CreationError::Error)?;
+        let z_queryable = z_create_queryable(z_session, z_key, iox_service_config)
+            .map_err(|_e| CreationError::Error)?;
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_client,
+            z_queryable,
+            pending_requests: Mutex::new(Vec::new()),
+            statistics: statistics.clone(),
+            disabled: false,
+        })
+    }
+
+    /// Sends the request of a remote client to the local servers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(PendingLocalResponses))` - The request awaiting the responses of the servers
+    /// * `Ok(None)` - If the request is malformed and was answered with an error reply
+    /// * `Err(PropagationError)` - If the request could not be sent
+    fn propagate_request(
+        &self,
+        z_query: Query,
+    ) -> Result<Option<PendingLocalResponses<ServiceType>>, PropagationError> {
+        let iox_payload_size = self
+            .iox_service_config
+            .request_response()
+            .request_message_type_details()
+            .payload
+            .size;
+
+        let z_payload = z_query.payload().cloned().unwrap_or_default();
+        let Some(number_of_elements) = number_of_elements(z_payload.len(), iox_payload_size) else {
+            warn!(
+                "Malformed request of {} bytes ({}) is no multiple of the request size {}. Skip.",
+                z_payload.len(),
+                self.iox_service_config.name(),
+                iox_payload_size
+            );
+            if let Err(e) = z_query.reply_err(ZBytes::from("malformed request")).wait() {
+                warn!("Failed to reply to malformed request: {}", e);
+            }
+            return Ok(None);
+        };
+        let iox_pending_response = unsafe {
+            let mut iox_request = self
+                .iox_client
+                .loan_custom_payload(number_of_elements)
+                .map_err(|e| {
+                    error!(
+                        "Failed to loan request ({}): {}",
+                        self.iox_service_config.name(),
+                        e
+                    );
+                    PropagationError::IceoryxLoanFailed
+                })?;
+            let iox_payload = iox_request.payload_mut().as_mut_ptr() as *mut u8;
+            let mut offset = 0;
+            for z_payload_slice in z_payload.slices() {
+                core::ptr::copy_nonoverlapping(
+                    z_payload_slice.as_ptr(),
+                    iox_payload.add(offset),
+                    z_payload_slice.len(),
+                );
+                offset += z_payload_slice.len();
+            }
+            iox_request.assume_init().send().map_err(|e| {
+                error!(
+                    "Failed to send request ({}): {}",
+                    self.iox_service_config.name(),
+                    e
+                );
+                PropagationError::IceoryxSendFailed
+            })?
+        };
+
+        self.statistics.record_zenoh_to_iceoryx();
+        self.statistics
+            .record_zenoh_to_iceoryx_bytes(z_payload.len());
+        info!(
+            "PROPAGATED(iceoryx<-zenoh): Request {} [{}]",
+            self.iox_service_config.service_id().as_str(),
+            self.iox_service_config.name()
+        );
+
+        Ok(Some(PendingLocalResponses {
+            iox_pending_response,
+            z_query,
+        }))
+    }
+
+    /// Sends the responses received for a pending request as replies to the remote client.
+    fn propagate_responses(
+        &self,
+        pending_request: &PendingLocalResponses<ServiceType>,
+    ) -> Result<usize, PropagationError> {
+        let mut propagated = 0;
+        loop {
+            match unsafe {
+                pending_request
+                    .iox_pending_response
+                    .receive_custom_payload()
+            } {
+                Ok(Some(iox_response)) => {
+                    let ptr = iox_response.payload().as_ptr() as *const u8;
+                    let len = iox_response.payload().len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    let z_query = &pending_request.z_query;
+                    if let Err(e) = z_query
+                        .reply(z_query.key_expr().clone(), ZBytes::from(bytes))
+                        .wait()
+                    {
+                        error!("Failed to propagate response to zenoh: {}", e);
+                        return Err(PropagationError::ZenohQueryFailed);
+                    }
+
+                    self.statistics.record_iceoryx_to_zenoh();
+                    self.statistics.record_iceoryx_to_zenoh_bytes(len);
+                    info!(
+                        "PROPAGATED(iceoryx->zenoh): Response {} [{}]",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name()
+                    );
+                    propagated += 1;
+                }
+                Ok(None) => break, // No more responses available
+                Err(e) => {
+                    error!("Failed to receive custom payload from iceoryx: {}", e);
+                    return Err(PropagationError::IceoryxReceiveFailed);
+                }
+            }
+        }
+
+        Ok(propagated)
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Connection
+    for InboundRequestResponseConnection<ServiceType>
+{
+    /// Propagate requests of remote clients to the local host and the responses to the
+    /// remote clients.
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        if self.disabled {
+            return Ok(0);
+        }
+
+        let mut propagated = 0;
+        let mut pending_requests = self.pending_requests.lock().unwrap();
+        while let Ok(Some(z_query)) = self.z_queryable.try_recv() {
+            if let Some(pending_request) = self.propagate_request(z_query)? {
+                pending_requests.push(pending_request);
+                propagated += 1;
+            }
+        }
+
+        let mut index = 0;
+        while index < pending_requests.len() {
+            // Determined before receiving, responses sent before the servers disconnect
+            // are received in any case.
+            let is_connected = pending_requests[index].iox_pending_response.is_connected();
+            propagated += self.propagate_responses(&pending_requests[index])?;
+            if is_connected {
+                index += 1;
+            } else {
+                // Dropping the query finalizes it, signalling the remote client that no more
+                // replies follow.
+                pending_requests.swap_remove(index);
+            }
+        }
+
+        Ok(propagated)
+    }
+}
+
+/// Couples the outbound and inbound connection for requests and responses
+/// from particular iceoryx2 service.
+pub(crate) struct BidirectionalRequestResponseConnection<
+    'a,
+    ServiceType: iceoryx2::service::Service,
+> {
+    outbound_connection: OutboundRequestResponseConnection<'a, ServiceType>,
+    inbound_connection: InboundRequestResponseConnection<ServiceType>,
+    iox_service_config: IceoryxServiceConfig,
+    failures: FailureTracker,
+    statistics: Arc<ConnectionStatistics>,
+}
+
+impl<ServiceType: iceoryx2::service::Service>
+    BidirectionalRequestResponseConnection<'_, ServiceType>
+{
+    /// Create a bi-directional connection to propagate requests and responses for a particular
+    /// iceoryx2 service to and from remote iceoryx2 instances via Zenoh.
+    pub fn create(
+        iox_node: &IceoryxNode<ServiceType>,
+        z_session: &ZenohSession,
+        iox_service_config: &IceoryxServiceConfig,
+        z_key: &str,
+    ) -> Result<Self, CreationError> {
+        let iox_request_response_service =
+            iox_create_request_response_service::<ServiceType>(iox_node, iox_service_config)
+                .map_err(|_e| CreationError::Error)?;
+
+        let statistics = Arc::new(ConnectionStatistics::default());
+        let inbound_connection = InboundRequestResponseConnection::create(
+            iox_service_config,
+            &iox_request_response_service,
+            z_session,
+            z_key,
+            &statistics,
+        )?;
+        let outbound_connection = OutboundRequestResponseConnection::create(
+            inbound_connection.iox_client.id(),
+            iox_service_config,
+            &iox_request_response_service,
+            z_session,
+            z_key,
+            &statistics,
+        )?;
+
+        z_announce_service(z_session, iox_service_config).map_err(|_e| CreationError::Error)?;
+
+        Ok(Self {
+            outbound_connection,
+            inbound_connection,
+            iox_service_config: iox_service_config.clone(),
+            failures: FailureTracker::default(),
+            statistics,
+        })
+    }
+
+    /// Returns the configuration of the iceoryx2 service of this connection.
+    pub fn service_config(&self) -> &IceoryxServiceConfig {
+        &self.iox_service_config
+    }
+
+    /// Disables or re-enables propagating requests of local clients to remote hosts.
+    pub fn set_iceoryx_to_zenoh_disabled(&mut self, disabled: bool) {
+        self.outbound_connection.disabled = disabled;
+    }
+
+    /// Disables or re-enables propagating requests of remote clients to the local host.
+    pub fn set_zenoh_to_iceoryx_disabled(&mut self, disabled: bool) {
+        self.inbound_connection.disabled = disabled;
+    }
+
+    /// Limits the duration spent receiving requests of local clients per propagation. `None`
+    /// removes the limit.
+    pub fn set_iceoryx_receive_timeout(&mut self, timeout: Option<Duration>) {
+        self.outbound_connection.receive_timeout = timeout;
+    }
+
+    /// Returns the zenoh key expression requests of this connection are queried on.
+    pub fn z_key_expression(&self) -> String {
+        self.outbound_connection.z_querier.key_expr().to_string()
+    }
+
+    /// Returns the propagation failures tracked for this connection.
+    pub fn failures(&self) -> &FailureTracker {
+        &self.failures
+    }
+
+    /// Returns the liveness of this connection according to its most recent propagations.
+    pub fn health(&self) -> ConnectionHealth {
+        self.failures.health()
+    }
+
+    /// Validates that the zenoh ports of this connection are still usable and that its
+    /// propagations do not fail excessively. The iceoryx2 server and client do not expose
+    /// their state and are not validated.
+    pub fn validate(&self, z_session: &ZenohSession) -> ConnectionValidation {
+        if z_session.is_closed() {
+            return ConnectionValidation::Failed {
+                reason: String::from("zenoh querier is closed"),
+            };
+        }
+        if self.inbound_connection.z_queryable.is_disconnected() {
+            return ConnectionValidation::Failed {
+                reason: String::from("zenoh queryable is closed"),
+            };
+        }
+
+        ConnectionValidation::of_propagations(&self.failures, &self.statistics)
+    }
+
+    /// Returns the forwarding statistics tracked for this connection.
+    pub fn statistics(&self) -> &ConnectionStatistics {
+        &self.statistics
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> TunneledConnection
+    for BidirectionalRequestResponseConnection<'_, ServiceType>
+{
+    fn service_config(&self) -> &IceoryxServiceConfig {
+        &self.iox_service_config
+    }
+
+    fn failures(&self) -> &FailureTracker {
+        &self.failures
+    }
+
+    fn statistics(&self) -> &ConnectionStatistics {
+        &self.statistics
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Connection
+    for BidirectionalRequestResponseConnection<'_, ServiceType>
+{
+    /// Propagate local requests to remote hosts and remote requests to the local host, along
+    /// with their responses.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                service_id = self.iox_service_config.service_id().as_str(),
+                messaging_pattern = ?self.iox_service_config.messaging_pattern(),
+                messages_forwarded = tracing::field::Empty,
+            )
+        )
+    )]
+    fn propagate(&self) -> Result<usize, PropagationError> {
+        let outbound = self
+            .outbound_connection
+            .propagate()
+            .inspect_err(|e| self.statistics.record_error(*e))?;
+        let inbound = self
+            .inbound_connection
+            .propagate()
+            .inspect_err(|e| self.statistics.record_error(*e))?;
+        self.statistics.record_ready();
+
+        let forwarded = outbound + inbound;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("messages_forwarded", forwarded);
+
+        Ok(forwarded)
+    }
+}
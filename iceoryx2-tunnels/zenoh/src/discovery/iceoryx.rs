@@ -16,21 +16,25 @@ use super::DiscoveryError;
 use iceoryx2::config::Config as IceoryxConfig;
 use iceoryx2::node::Node as IceoryxNode;
 use iceoryx2::port::subscriber::Subscriber as IceoryxSubscriber;
+use iceoryx2::prelude::CallbackProgression;
 use iceoryx2::prelude::ServiceName;
+use iceoryx2::service::messaging_pattern::MessagingPattern as MessagingPatternKind;
+use iceoryx2::service::service_id::ServiceId as IceoryxServiceId;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
 use iceoryx2_bb_log::info;
 use iceoryx2_services_discovery::service_discovery::Discovery as DiscoveryUpdate;
 use iceoryx2_services_discovery::service_discovery::Tracker as IceoryxServiceTracker;
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub enum CreationError {
-    Error,
-}
+use std::collections::HashMap;
+use std::collections::HashSet;
 
 pub(crate) struct IceoryxDiscovery<ServiceType: iceoryx2::service::Service> {
     iox_config: IceoryxConfig,
     iox_discovery_subscriber: Option<IceoryxSubscriber<ServiceType, DiscoveryUpdate, ()>>,
     iox_discovery_tracker: Option<IceoryxServiceTracker<ServiceType>>,
+    // The services announced by the discovery service and not removed since
+    iox_announced_services: HashMap<IceoryxServiceId, IceoryxServiceConfig>,
 }
 
 impl<ServiceType: iceoryx2::service::Service> IceoryxDiscovery<ServiceType> {
@@ -38,25 +42,18 @@ impl<ServiceType: iceoryx2::service::Service> IceoryxDiscovery<ServiceType> {
         iox_config: &IceoryxConfig,
         iox_node: &IceoryxNode<ServiceType>,
         iox_service_name: &Option<String>,
-    ) -> Result<Self, CreationError> {
+    ) -> Result<Self, Box<dyn core::error::Error + Send + Sync>> {
         let (iox_discovery_subscriber, iox_discovery_tracker) = match iox_service_name {
             Some(value) => {
-                let iox_service_name: ServiceName = value
-                    .as_str()
-                    .try_into()
-                    .map_err(|_e| CreationError::Error)?;
+                let iox_service_name: ServiceName = value.as_str().try_into()?;
 
                 info!("CONFIGURED Discovery updates from service {}", value);
                 let iox_service = iox_node
                     .service_builder(&iox_service_name)
                     .publish_subscribe::<DiscoveryUpdate>()
-                    .open_or_create()
-                    .map_err(|_e| CreationError::Error)?;
+                    .open_or_create()?;
 
-                let iox_subscriber = iox_service
-                    .subscriber_builder()
-                    .create()
-                    .map_err(|_e| CreationError::Error)?;
+                let iox_subscriber = iox_service.subscriber_builder().create()?;
 
                 (Some(iox_subscriber), None)
             }
@@ -70,7 +67,109 @@ impl<ServiceType: iceoryx2::service::Service> IceoryxDiscovery<ServiceType> {
             iox_config: iox_config.clone(),
             iox_discovery_subscriber,
             iox_discovery_tracker,
+            iox_announced_services: HashMap::new(),
+        })
+    }
+
+    /// Queries all local services and returns the configurations of the services with the
+    /// provided messaging pattern, without tracking them as discovered.
+    pub fn services_matching_pattern(
+        &self,
+        pattern: MessagingPatternKind,
+    ) -> Result<Vec<IceoryxServiceConfig>, DiscoveryError> {
+        let mut iox_service_configs = Vec::new();
+        ServiceType::list(&self.iox_config, |iox_service_details| {
+            if kind_of(iox_service_details.static_details.messaging_pattern()) == pattern {
+                iox_service_configs.push(iox_service_details.static_details);
+            }
+            CallbackProgression::Continue
+        })
+        .map_err(|e| DiscoveryError::IceoryxDiscoveryFailed {
+            reason: format!("{e:?}"),
+        })?;
+
+        Ok(iox_service_configs)
+    }
+
+    /// Queries all local services and returns the configurations of the services that can be
+    /// tunneled, without tracking them as discovered.
+    pub fn list_services(&self) -> Result<Vec<IceoryxServiceConfig>, DiscoveryError> {
+        let mut iox_service_configs = Vec::new();
+        ServiceType::list(&self.iox_config, |iox_service_details| {
+            if is_supported(iox_service_details.static_details.messaging_pattern()) {
+                iox_service_configs.push(iox_service_details.static_details);
+            }
+            CallbackProgression::Continue
         })
+        .map_err(|e| DiscoveryError::IceoryxDiscoveryFailed {
+            reason: format!("{e:?}"),
+        })?;
+
+        Ok(iox_service_configs)
+    }
+
+    /// Notifies the provided callback about every supported service in the cache of the
+    /// discovery, without querying for changes. The cache is updated with
+    /// [`IceoryxDiscovery::refresh()`] or [`Discovery::discover()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Invoked with the configuration of every cached service
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of services the callback was invoked for
+    pub fn discover_non_blocking<F: FnMut(&IceoryxServiceConfig)>(&self, mut callback: F) -> usize {
+        let mut notified = 0;
+        let mut notify = |iox_service_details: &IceoryxServiceConfig| {
+            if is_supported(iox_service_details.messaging_pattern()) {
+                callback(iox_service_details);
+                notified += 1;
+            }
+        };
+
+        if let Some(iox_discovery_tracker) = &self.iox_discovery_tracker {
+            for iox_service_details in iox_discovery_tracker.get_all() {
+                notify(&iox_service_details.static_details);
+            }
+        } else {
+            for iox_service_details in self.iox_announced_services.values() {
+                notify(iox_service_details);
+            }
+        }
+
+        notified
+    }
+
+    /// Updates the cache of the discovery with the services added or removed since the
+    /// previous update, without notifying about them.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the cache was updated
+    /// * `Err(DiscoveryError)` - If the services could not be queried
+    pub fn refresh(&mut self) -> Result<(), DiscoveryError> {
+        self.discover(&mut |_| {})
+    }
+}
+
+/// Returns `true` if services with the provided messaging pattern can be tunneled.
+fn is_supported(messaging_pattern: &MessagingPattern) -> bool {
+    matches!(
+        messaging_pattern,
+        MessagingPattern::PublishSubscribe(_)
+            | MessagingPattern::Event(_)
+            | MessagingPattern::RequestResponse(_)
+    )
+}
+
+/// Returns the kind of a messaging pattern, regardless of its configuration.
+fn kind_of(messaging_pattern: &MessagingPattern) -> MessagingPatternKind {
+    match messaging_pattern {
+        MessagingPattern::PublishSubscribe(_) => MessagingPatternKind::PublishSubscribe,
+        MessagingPattern::Event(_) => MessagingPatternKind::Event,
+        MessagingPattern::RequestResponse(_) => MessagingPatternKind::RequestResponse,
+        MessagingPattern::Blackboard(_) => MessagingPatternKind::Blackboard,
     }
 }
 
@@ -87,9 +186,19 @@ impl<ServiceType: iceoryx2::service::Service> Discovery<ServiceType>
                 match iox_discovery_subscriber.receive() {
                     Ok(result) => match result {
                         Some(iox_sample) => {
+                            if let DiscoveryUpdate::Removed(iox_service_details) =
+                                iox_sample.payload()
+                            {
+                                self.iox_announced_services
+                                    .remove(iox_service_details.service_id());
+                            }
                             if let DiscoveryUpdate::Added(iox_service_details) =
                                 iox_sample.payload()
                             {
+                                self.iox_announced_services.insert(
+                                    iox_service_details.service_id().clone(),
+                                    iox_service_details.clone(),
+                                );
                                 match iox_service_details.messaging_pattern() {
                                     MessagingPattern::PublishSubscribe(_) => {
                                         on_discovered(iox_service_details);
@@ -97,23 +206,30 @@ impl<ServiceType: iceoryx2::service::Service> Discovery<ServiceType>
                                     MessagingPattern::Event(_) => {
                                         on_discovered(iox_service_details);
                                     }
+                                    MessagingPattern::RequestResponse(_) => {
+                                        on_discovered(iox_service_details);
+                                    }
                                     _ => { /* Not supported. Nothing to do. */ }
                                 }
                             }
                         }
                         None => break,
                     },
-                    Err(_e) => {
-                        return Err(DiscoveryError::Error);
+                    Err(e) => {
+                        return Err(DiscoveryError::IceoryxDiscoveryFailed {
+                            reason: format!("{e:?}"),
+                        });
                     }
                 }
             }
         }
         // OR Discover via internal service tracker
         else if let Some(iox_discovery_tracker) = &mut self.iox_discovery_tracker {
-            let (added, _removed) = iox_discovery_tracker
-                .sync(&self.iox_config)
-                .map_err(|_e| DiscoveryError::Error)?;
+            let (added, _removed) = iox_discovery_tracker.sync(&self.iox_config).map_err(|e| {
+                DiscoveryError::IceoryxDiscoveryFailed {
+                    reason: format!("{e:?}"),
+                }
+            })?;
 
             for iox_service_id in added {
                 if let Some(iox_service_details) = iox_discovery_tracker.get(&iox_service_id) {
@@ -126,6 +242,9 @@ impl<ServiceType: iceoryx2::service::Service> Discovery<ServiceType>
                         MessagingPattern::Event(_) => {
                             on_discovered(iox_service_details);
                         }
+                        MessagingPattern::RequestResponse(_) => {
+                            on_discovered(iox_service_details);
+                        }
                         _ => { /* Not supported. Nothing to do. */ }
                     }
                 }
@@ -138,4 +257,22 @@ impl<ServiceType: iceoryx2::service::Service> Discovery<ServiceType>
 
         Ok(())
     }
+
+    fn live_services(&self) -> Option<HashSet<IceoryxServiceId>> {
+        if self.iox_discovery_subscriber.is_some() {
+            Some(self.iox_announced_services.keys().cloned().collect())
+        } else {
+            self.iox_discovery_tracker
+                .as_ref()
+                .map(|iox_discovery_tracker| {
+                    iox_discovery_tracker
+                        .get_all()
+                        .iter()
+                        .map(|iox_service_details| {
+                            iox_service_details.static_details.service_id().clone()
+                        })
+                        .collect()
+                })
+        }
+    }
 }
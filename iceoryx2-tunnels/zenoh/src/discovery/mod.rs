@@ -10,30 +10,34 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2::service::service_id::ServiceId as IceoryxServiceId;
 use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
 
+use std::collections::HashSet;
+
 mod iceoryx;
 mod zenoh;
 
 pub(crate) use iceoryx::*;
 pub(crate) use zenoh::*;
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub enum DiscoveryError {
-    Error,
-}
+pub use zenoh::DiscoveryMode;
 
-impl core::fmt::Display for DiscoveryError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
-        core::write!(f, "DiscoveryError::{self:?}")
-    }
-}
+pub(crate) use crate::tunnel::DiscoveryError;
 
-impl core::error::Error for DiscoveryError {}
-
-pub(crate) trait Discovery<ServiceType: iceoryx2::service::Service> {
+/// A source of iceoryx2 services to be tunneled, e.g. a custom service registry provided to
+/// [`Tunnel::create_with_custom_discovery()`](crate::Tunnel::create_with_custom_discovery()).
+pub trait Discovery<ServiceType: iceoryx2::service::Service> {
+    /// Invokes the provided callback for every service discovered since the previous call.
     fn discover<OnDiscovered: FnMut(&IceoryxServiceConfig)>(
         &mut self,
         on_discovered: &mut OnDiscovered,
     ) -> Result<(), DiscoveryError>;
+
+    /// Returns the ids of all services that are alive as of the previous call to
+    /// [`Discovery::discover()`], or `None` if the source cannot tell which services
+    /// disappeared. Connections of services that are no longer alive are removed.
+    fn live_services(&self) -> Option<HashSet<IceoryxServiceId>> {
+        None
+    }
 }
@@ -16,44 +16,205 @@ use crate::discovery::Discovery;
 use crate::keys;
 
 use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::error;
 
 use zenoh::handlers::FifoChannelHandler;
+use zenoh::key_expr::KeyExpr;
 use zenoh::query::Querier as ZenohQuerier;
+use zenoh::query::Queryable as ZenohQueryable;
 use zenoh::query::Reply;
 use zenoh::sample::Locality;
+use zenoh::Config as ZenohConfig;
 use zenoh::Session as ZenohSession;
 use zenoh::Wait;
 
-pub enum CreationError {
-    Error,
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// Determines how the details of remote services are retrieved.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiscoveryMode {
+    /// Query the details announced by each remote service on its own, see
+    /// [`keys::discovery()`].
+    #[default]
+    PerService,
+    /// Query the registries of the remote tunnels, each replying with the details of all
+    /// services of its tunnel, see [`keys::service_registries()`]. As the registries are
+    /// served by the tunnels themselves, the services are discovered again as soon as the
+    /// tunnels are reachable, e.g. after a zenoh router restarted. Only tunnels in this mode
+    /// serve their registry.
+    Queryable,
 }
 
 /// Discovers remote `iceoryx2` services via Zenoh.
 ///
 /// TODO: Explain in detail
 pub(crate) struct ZenohDiscovery<'a, ServiceType: iceoryx2::service::Service> {
+    z_session: ZenohSession,
     z_querier: ZenohQuerier<'a>,
     z_query: FifoChannelHandler<Reply>,
+    // Replies to queries of remote tunnels, only declared in `DiscoveryMode::Queryable`
+    _z_registry: Option<ZenohQueryable<()>>,
+    registered_services: Arc<Mutex<String>>,
+    mode: DiscoveryMode,
+    scouting_endpoint: Option<String>,
     _phantom: core::marker::PhantomData<ServiceType>,
 }
 
 impl<ServiceType: iceoryx2::service::Service> ZenohDiscovery<'_, ServiceType> {
-    pub fn create(z_session: &ZenohSession) -> Result<Self, CreationError> {
-        let z_querier = z_session
-            .declare_querier(keys::discovery())
-            .allowed_destination(Locality::Remote)
-            .wait()
-            .map_err(|_e| CreationError::Error)?;
-
-        // Make query immediately - replies processed in first `discover()` call
-        let z_query = z_querier.get().wait().map_err(|_e| CreationError::Error)?;
+    /// Creates a discovery querying the service details announced on the provided zenoh
+    /// key expression, either by each service or by the registry of each tunnel depending on
+    /// the mode. The registry of the own tunnel is only served in [`DiscoveryMode::Queryable`].
+    pub fn create(
+        z_session: &ZenohSession,
+        z_key_expression: &str,
+        mode: DiscoveryMode,
+    ) -> Result<Self, zenoh::Error> {
+        let registered_services = Arc::new(Mutex::new(String::from("[]")));
+        let z_registry = match mode {
+            DiscoveryMode::PerService => None,
+            DiscoveryMode::Queryable => {
+                Some(declare_service_registry(z_session, &registered_services)?)
+            }
+        };
+        let (z_querier, z_query) = declare_discovery_query(z_session, z_key_expression)?;
 
         Ok(Self {
+            z_session: z_session.clone(),
             z_querier,
             z_query,
+            _z_registry: z_registry,
+            registered_services,
+            mode,
+            scouting_endpoint: None,
             _phantom: core::marker::PhantomData,
         })
     }
+
+    /// Creates a discovery on a dedicated zenoh session that only contacts the provided
+    /// endpoint, e.g. `tcp/10.0.0.1:7447`, instead of scouting for zenoh instances via
+    /// multicast or gossip.
+    pub fn with_scouting_endpoint(
+        endpoint: &str,
+        z_key_expression: &str,
+        mode: DiscoveryMode,
+    ) -> Result<Self, zenoh::Error> {
+        let mut z_config = ZenohConfig::default();
+        z_config.insert_json5("scouting/multicast/enabled", "false")?;
+        z_config.insert_json5("scouting/gossip/enabled", "false")?;
+        z_config.insert_json5(
+            "connect/endpoints",
+            &serde_json::json!([endpoint]).to_string(),
+        )?;
+        let z_session = zenoh::open(z_config).wait()?;
+
+        let mut discovery = Self::create(&z_session, z_key_expression, mode)?;
+        discovery.scouting_endpoint = Some(endpoint.to_string());
+
+        Ok(discovery)
+    }
+
+    /// Returns the endpoint the discovery is restricted to, if it was created with
+    /// [`ZenohDiscovery::with_scouting_endpoint()`].
+    pub fn scouting_endpoint(&self) -> Option<&str> {
+        self.scouting_endpoint.as_deref()
+    }
+
+    /// Queries the service details announced on the provided zenoh key expression from now
+    /// on, replacing the current key expression. Replies to the pending query on the previous
+    /// key expression are discarded.
+    pub fn set_discovery_key_expression(
+        &mut self,
+        z_key_expression: &str,
+    ) -> Result<(), DiscoveryError> {
+        KeyExpr::try_from(z_key_expression).map_err(|e| DiscoveryError::InvalidKeyExpression {
+            key_expr: z_key_expression.to_string(),
+            reason: e.to_string(),
+        })?;
+        let (z_querier, z_query) = declare_discovery_query(&self.z_session, z_key_expression)
+            .map_err(|e| DiscoveryError::ZenohDiscoveryFailed {
+                reason: e.to_string(),
+            })?;
+        self.z_querier = z_querier;
+        self.z_query = z_query;
+
+        Ok(())
+    }
+
+    /// Returns the zenoh key expression on which the service details are queried.
+    pub fn key_expression(&self) -> String {
+        self.z_querier.key_expr().to_string()
+    }
+
+    /// Returns how the details of remote services are retrieved.
+    pub fn mode(&self) -> DiscoveryMode {
+        self.mode
+    }
+
+    /// Returns the configurations of the remote services that replied to the pending query,
+    /// each service once, and queries them anew like [`Discovery::discover()`].
+    pub fn list_services(&mut self) -> Result<Vec<IceoryxServiceConfig>, DiscoveryError> {
+        let mut listed = HashSet::new();
+        let mut iox_service_configs = Vec::new();
+        self.discover(&mut |iox_service_config: &IceoryxServiceConfig| {
+            if listed.insert(iox_service_config.service_id().clone()) {
+                iox_service_configs.push(iox_service_config.clone());
+            }
+        })?;
+
+        Ok(iox_service_configs)
+    }
+
+    /// Replaces the services the registry of the tunnel replies with to remote tunnels.
+    pub fn set_registered_services<'b>(
+        &self,
+        iox_service_configs: impl Iterator<Item = &'b IceoryxServiceConfig>,
+    ) {
+        let iox_service_configs: Vec<&IceoryxServiceConfig> = iox_service_configs.collect();
+        match serde_json::to_string(&iox_service_configs) {
+            Ok(serialized) => *self.registered_services.lock().unwrap() = serialized,
+            Err(e) => error!("Failed to serialize the registered services: {}", e),
+        }
+    }
+}
+
+/// Declares the queryable replying with the details of all services of the tunnel as a JSON
+/// array on the registry key of the zenoh session. Local queries are answered as well, while
+/// the discovery only queries remote registries.
+fn declare_service_registry(
+    z_session: &ZenohSession,
+    registered_services: &Arc<Mutex<String>>,
+) -> Result<ZenohQueryable<()>, zenoh::Error> {
+    let z_key = keys::service_registry(&z_session.zid().to_string());
+    let registered_services = registered_services.clone();
+
+    z_session
+        .declare_queryable(z_key.clone())
+        .callback(move |query| {
+            let serialized = registered_services.lock().unwrap().clone();
+            if let Err(e) = query.reply(z_key.clone(), serialized).wait() {
+                error!("Failed to reply to query {}: {}", z_key, e);
+            }
+        })
+        .allowed_origin(Locality::Any)
+        .wait()
+}
+
+/// Declares a querier for service details on the provided key expression and makes the first
+/// query immediately, the replies are processed in the first `discover()` call.
+fn declare_discovery_query<'a>(
+    z_session: &ZenohSession,
+    z_key_expression: &str,
+) -> Result<(ZenohQuerier<'a>, FifoChannelHandler<Reply>), zenoh::Error> {
+    let z_querier = z_session
+        .declare_querier(z_key_expression.to_string())
+        .allowed_destination(Locality::Remote)
+        .wait()?;
+    let z_query = z_querier.get().wait()?;
+
+    Ok((z_querier, z_query))
 }
 
 impl<ServiceType: iceoryx2::service::Service> Discovery<ServiceType>
@@ -66,16 +227,30 @@ impl<ServiceType: iceoryx2::service::Service> Discovery<ServiceType>
         // Drain all replies from previous query
         for z_reply in self.z_query.drain() {
             match z_reply.result() {
-                Ok(z_sample) => {
-                    match serde_json::from_slice::<IceoryxServiceConfig>(
-                        &z_sample.payload().to_bytes(),
-                    ) {
-                        Ok(iox_service_details) => {
-                            on_discovered(&iox_service_details);
+                Ok(z_sample) => match self.mode {
+                    DiscoveryMode::PerService => {
+                        match serde_json::from_slice::<IceoryxServiceConfig>(
+                            &z_sample.payload().to_bytes(),
+                        ) {
+                            Ok(iox_service_details) => {
+                                on_discovered(&iox_service_details);
+                            }
+                            Err(_e) => { /* Ignore malformed announcements of other tunnels */ }
+                        }
+                    }
+                    DiscoveryMode::Queryable => {
+                        match serde_json::from_slice::<Vec<IceoryxServiceConfig>>(
+                            &z_sample.payload().to_bytes(),
+                        ) {
+                            Ok(iox_services_details) => {
+                                for iox_service_details in &iox_services_details {
+                                    on_discovered(iox_service_details);
+                                }
+                            }
+                            Err(_e) => { /* Ignore malformed registries of other tunnels */ }
                         }
-                        Err(_e) => todo!(),
                     }
-                }
+                },
                 Err(_e) => { /* Ignore and process other requests */ }
             }
         }
@@ -84,11 +259,13 @@ impl<ServiceType: iceoryx2::service::Service> Discovery<ServiceType>
         // NOTE: This results in all service details being resent - not optimal
         // TODO(optimization): A solution to request all quereyables once whilst still retrieving
         //                     querying new quereyables that appear
-        self.z_query = self
-            .z_querier
-            .get()
-            .wait()
-            .map_err(|_e| DiscoveryError::Error)?;
+        self.z_query =
+            self.z_querier
+                .get()
+                .wait()
+                .map_err(|e| DiscoveryError::ZenohDiscoveryFailed {
+                    reason: e.to_string(),
+                })?;
 
         Ok(())
     }
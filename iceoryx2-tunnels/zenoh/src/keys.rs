@@ -16,6 +16,23 @@ use iceoryx2::service::service_id::ServiceId;
 pub fn discovery() -> String {
     "iox2/services/*".into()
 }
+/// The zenoh key for discovering the service registries of all tunnels.
+pub fn service_registries() -> String {
+    "iox2/registries/*".into()
+}
+
+/// The zenoh key at which the details of all services of the tunnel with the given zenoh
+/// session id can be received.
+pub fn service_registry(z_session_id: &str) -> String {
+    format!("iox2/registries/{z_session_id}")
+}
+
+/// The zenoh liveliness key announcing the primary tunnel of the hot-standby pair with the
+/// given id.
+pub fn ha_primary(ha_pair_id: &str) -> String {
+    format!("iox2/ha/{ha_pair_id}/primary")
+}
+
 /// The zenoh key at which the service details for the given service id can be received.
 pub fn service_details(service_id: &ServiceId) -> String {
     format!("iox2/services/{}", service_id.as_str())
@@ -26,7 +43,21 @@ pub fn publish_subscribe(service_id: &ServiceId) -> String {
     format!("iox2/services/{}/publish_subscribe", service_id.as_str())
 }
 
+/// The zenoh key at which the history of the payloads for the given service id can be
+/// queried.
+pub fn publish_subscribe_history(service_id: &ServiceId) -> String {
+    format!(
+        "iox2/services/{}/publish_subscribe/history",
+        service_id.as_str()
+    )
+}
+
 /// The zenoh key at which events for the given service id can be received.
 pub fn event(service_id: &ServiceId) -> String {
     format!("iox2/services/{}/event", service_id.as_str())
 }
+
+/// The zenoh key at which requests for the given service id can be queried.
+pub fn request_response(service_id: &ServiceId) -> String {
+    format!("iox2/services/{}/request_response", service_id.as_str())
+}
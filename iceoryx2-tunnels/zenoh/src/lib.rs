@@ -10,30 +10,66 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod attachment;
+#[cfg(feature = "checksum")]
+mod checksum;
+#[cfg(feature = "compression")]
+mod compression;
 mod connection;
 mod discovery;
 pub mod keys;
+mod router;
 mod tunnel;
 
+pub use attachment::ServiceAttachment;
+#[cfg(feature = "checksum")]
+pub use checksum::ChecksumAlgorithm;
+#[cfg(feature = "compression")]
+pub use compression::CompressionCodec;
+#[cfg(feature = "compression")]
+pub use compression::CompressionConfig;
+#[cfg(feature = "compression")]
+pub use compression::DEFAULT_COMPRESSION_THRESHOLD;
+pub use connection::BackpressurePolicy;
+pub use connection::ConnectionDirection;
+pub use connection::ConnectionHealth;
+pub use connection::Direction;
+pub use connection::EventBatching;
+#[cfg(feature = "latency-tracking")]
+pub use connection::LatencyStats;
+pub use connection::PropagationError;
+pub use connection::SizeBucket;
+pub use connection::SizeHistogram;
+pub use connection::SpliceError;
 pub(crate) use connection::*;
+pub use discovery::Discovery;
+pub use discovery::DiscoveryMode;
+pub use router::*;
 pub use tunnel::*;
 
 use iceoryx2::node::Node as IceoryxNode;
+use iceoryx2::port::client::Client as IceoryxClient;
 use iceoryx2::port::listener::Listener as IceoryxListener;
 use iceoryx2::port::listener::ListenerCreateError;
 use iceoryx2::port::notifier::Notifier as IceoryxNotifier;
 use iceoryx2::port::notifier::NotifierCreateError;
 use iceoryx2::port::publisher::Publisher as IceoryxPublisher;
 use iceoryx2::port::publisher::PublisherCreateError;
+use iceoryx2::port::server::Server as IceoryxServer;
 use iceoryx2::port::subscriber::Subscriber as IceoryxSubscriber;
 use iceoryx2::port::subscriber::SubscriberCreateError;
 use iceoryx2::prelude::AllocationStrategy;
 use iceoryx2::service::builder::event::EventOpenOrCreateError;
 use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
+use iceoryx2::service::builder::request_response::RequestResponseOpenOrCreateError;
 use iceoryx2::service::builder::CustomHeaderMarker;
 use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::client::ClientCreateError;
 use iceoryx2::service::port_factory::event::PortFactory as IceoryxEventService;
 use iceoryx2::service::port_factory::publish_subscribe::PortFactory as IceoryxPublishSubscribeService;
+use iceoryx2::service::port_factory::request_response::PortFactory as IceoryxRequestResponseService;
+use iceoryx2::service::port_factory::server::ServerCreateError;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
 use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
 use iceoryx2_bb_log::error;
 use iceoryx2_bb_log::info;
@@ -42,16 +78,34 @@ use zenoh::handlers::FifoChannel;
 use zenoh::handlers::FifoChannelHandler;
 use zenoh::pubsub::Publisher as ZenohPublisher;
 use zenoh::pubsub::Subscriber as ZenohSubscriber;
+use zenoh::qos::CongestionControl;
+use zenoh::qos::Priority;
 use zenoh::qos::Reliability;
+use zenoh::query::Querier as ZenohQuerier;
+use zenoh::query::Query;
+use zenoh::query::Queryable as ZenohQueryable;
 use zenoh::sample::Locality;
 use zenoh::sample::Sample;
 use zenoh::Session as ZenohSession;
 use zenoh::Wait;
 
+/// The number of samples the zenoh subscribers of the connections buffer until they are
+/// propagated.
+pub(crate) const Z_SUBSCRIBER_BUFFER_SIZE: usize = 10;
+
+/// Overrides of the resource limits of the iceoryx2 services created by the tunnel.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct IceoryxServiceLimits {
+    pub(crate) max_publishers: Option<u64>,
+    pub(crate) max_subscribers: Option<u64>,
+}
+
 /// Creates an iceoryx2 publish-subscribe service matching the provided service configuration.
+/// The provided limits take precedence over the limits of the service configuration.
 pub(crate) fn iox_create_publish_subscribe_service<ServiceType: iceoryx2::service::Service>(
     iox_node: &IceoryxNode<ServiceType>,
     iox_service_config: &IceoryxServiceConfig,
+    iox_service_limits: IceoryxServiceLimits,
 ) -> Result<
     IceoryxPublishSubscribeService<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
     PublishSubscribeOpenOrCreateError,
@@ -72,8 +126,20 @@ pub(crate) fn iox_create_publish_subscribe_service<ServiceType: iceoryx2::servic
             )
             .enable_safe_overflow(iox_publish_subscribe_config.has_safe_overflow())
             .history_size(iox_publish_subscribe_config.history_size())
-            .max_publishers(iox_publish_subscribe_config.max_publishers())
-            .max_subscribers(iox_publish_subscribe_config.max_subscribers())
+            .max_publishers(
+                iox_service_limits
+                    .max_publishers
+                    .map_or(iox_publish_subscribe_config.max_publishers(), |max| {
+                        max as usize
+                    }),
+            )
+            .max_subscribers(
+                iox_service_limits
+                    .max_subscribers
+                    .map_or(iox_publish_subscribe_config.max_subscribers(), |max| {
+                        max as usize
+                    }),
+            )
             .subscriber_max_buffer_size(iox_publish_subscribe_config.subscriber_max_buffer_size())
             .subscriber_max_buffer_size(
                 iox_publish_subscribe_config.subscriber_max_borrowed_samples(),
@@ -97,6 +163,59 @@ pub(crate) fn iox_create_event_service<ServiceType: iceoryx2::service::Service>(
     Ok(iox_service)
 }
 
+/// Creates an iceoryx2 request-response service matching the provided service configuration.
+pub(crate) fn iox_create_request_response_service<ServiceType: iceoryx2::service::Service>(
+    iox_node: &IceoryxNode<ServiceType>,
+    iox_service_config: &IceoryxServiceConfig,
+) -> Result<
+    IceoryxRequestResponseService<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    RequestResponseOpenOrCreateError,
+> {
+    let iox_request_response_config = iox_service_config.request_response();
+    let iox_request_type_details = iox_request_response_config.request_message_type_details();
+    let iox_response_type_details = iox_request_response_config.response_message_type_details();
+    let iox_service = unsafe {
+        iox_node
+            .service_builder(iox_service_config.name())
+            .request_response::<[CustomPayloadMarker], [CustomPayloadMarker]>()
+            .request_user_header::<CustomHeaderMarker>()
+            .response_user_header::<CustomHeaderMarker>()
+            .__internal_set_request_header_type_details(&iox_request_type_details.user_header)
+            .__internal_set_request_payload_type_details(&iox_request_type_details.payload)
+            .__internal_set_response_header_type_details(&iox_response_type_details.user_header)
+            .__internal_set_response_payload_type_details(&iox_response_type_details.payload)
+            .enable_safe_overflow_for_requests(
+                iox_request_response_config.has_safe_overflow_for_requests(),
+            )
+            .enable_safe_overflow_for_responses(
+                iox_request_response_config.has_safe_overflow_for_responses(),
+            )
+            .enable_fire_and_forget_requests(
+                iox_request_response_config.does_support_fire_and_forget_requests(),
+            )
+            .max_active_requests_per_client(
+                iox_request_response_config.max_active_requests_per_client(),
+            )
+            .max_loaned_requests(iox_request_response_config.max_loaned_requests())
+            .max_response_buffer_size(iox_request_response_config.max_response_buffer_size())
+            .max_borrowed_responses_per_pending_response(
+                iox_request_response_config.max_borrowed_responses_per_pending_response(),
+            )
+            .max_servers(iox_request_response_config.max_servers())
+            .max_clients(iox_request_response_config.max_clients())
+            .max_nodes(iox_request_response_config.max_nodes())
+            .open_or_create()?
+    };
+
+    Ok(iox_service)
+}
+
 /// Creates an iceoryx publisher to the provided service.
 pub(crate) fn iox_create_publisher<ServiceType: iceoryx2::service::Service>(
     iox_publish_subscribe_service: &IceoryxPublishSubscribeService<
@@ -148,6 +267,74 @@ pub(crate) fn iox_create_subscriber<ServiceType: iceoryx2::service::Service>(
     Ok(iox_subscriber)
 }
 
+/// Creates an iceoryx server for the provided service.
+pub(crate) fn iox_create_server<ServiceType: iceoryx2::service::Service>(
+    iox_request_response_service: &IceoryxRequestResponseService<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    iox_service_config: &IceoryxServiceConfig,
+) -> Result<
+    IceoryxServer<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    ServerCreateError,
+> {
+    let iox_server = iox_request_response_service
+        .server_builder()
+        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+        .create()?;
+
+    info!(
+        "CREATED(iceoryx): Server {} [{}]",
+        iox_service_config.service_id().as_str(),
+        iox_service_config.name()
+    );
+
+    Ok(iox_server)
+}
+
+/// Creates an iceoryx client to the provided service.
+pub(crate) fn iox_create_client<ServiceType: iceoryx2::service::Service>(
+    iox_request_response_service: &IceoryxRequestResponseService<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    iox_service_config: &IceoryxServiceConfig,
+) -> Result<
+    IceoryxClient<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    ClientCreateError,
+> {
+    let iox_client = iox_request_response_service
+        .client_builder()
+        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+        .create()?;
+
+    info!(
+        "CREATED(iceoryx): Client {} [{}]",
+        iox_service_config.service_id().as_str(),
+        iox_service_config.name()
+    );
+
+    Ok(iox_client)
+}
+
 /// Creates an iceoryx notifier to the provided service.
 pub(crate) fn iox_create_notifier<ServiceType: iceoryx2::service::Service>(
     iox_event_service: &IceoryxEventService<ServiceType>,
@@ -180,16 +367,128 @@ pub(crate) fn iox_create_listener<ServiceType: iceoryx2::service::Service>(
     Ok(iox_listener)
 }
 
-/// Creates a Zenoh publisher to send payloads from iceoryx2 services to remote hosts.
+/// The quality of service of the zenoh publisher of a publish-subscribe connection, see
+/// [`iox_qos_to_zenoh_publisher_options()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublisherOptions {
+    /// Whether lost payloads are retransmitted.
+    pub reliability: Reliability,
+    /// Whether payloads are dropped or the publisher blocks when the transmission queue is
+    /// full.
+    pub congestion_control: CongestionControl,
+    /// The priority of the payloads relative to other zenoh traffic.
+    pub priority: Priority,
+}
+
+/// Determines the quality of service of the zenoh publishers propagating the payloads of
+/// publish-subscribe services to remote hosts, see [`TunnelConfig::qos_mapping`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum QosMapping {
+    /// Propagate the payloads of all services reliably with
+    /// [`CongestionControl::Block`] and [`Priority::Data`].
+    #[default]
+    Reliable,
+    /// Derive the quality of service from the iceoryx2 service with
+    /// [`iox_qos_to_zenoh_publisher_options()`]. The payloads of services with safe overflow,
+    /// the default of iceoryx2, are propagated best effort and may be lost.
+    FromService,
+}
+
+impl QosMapping {
+    /// Returns the options of the zenoh publisher propagating the payloads of the provided
+    /// service.
+    pub fn publisher_options(&self, iox_service_config: &IceoryxServiceConfig) -> PublisherOptions {
+        match self {
+            QosMapping::Reliable => PublisherOptions {
+                reliability: Reliability::Reliable,
+                congestion_control: CongestionControl::Block,
+                priority: Priority::Data,
+            },
+            QosMapping::FromService => iox_qos_to_zenoh_publisher_options(iox_service_config),
+        }
+    }
+}
+
+/// The settings of the zenoh publisher propagating the local payloads of a publish-subscribe
+/// service to remote hosts.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ZenohPublisherConfig {
+    pub(crate) allowed_destination: Locality,
+    pub(crate) options: PublisherOptions,
+}
+
+/// Converts the quality of service of an iceoryx2 publish-subscribe service to the nearest
+/// options of the zenoh publisher propagating its payloads to remote hosts. Only applied
+/// with [`QosMapping::FromService`].
+///
+/// | safe overflow | history size | subscriber buffer size | reliability  | congestion control | priority   |
+/// |---------------|--------------|------------------------|--------------|--------------------|------------|
+/// | enabled       | any          | any                    | `BestEffort` | `Drop`             | `Data`     |
+/// | disabled      | `0`          | `1`                    | `Reliable`   | `Block`            | `DataHigh` |
+/// | disabled      | any other    | any other              | `Reliable`   | `Block`            | `Data`     |
+///
+/// A service with safe overflow tolerates the loss of its oldest payloads, whereas a service
+/// without it must not lose any payload. A service without history whose subscribers only
+/// buffer the latest payload is assumed to be latency-sensitive. Services of other messaging
+/// patterns are mapped to the options of a service without safe overflow and with defaults
+/// otherwise.
+///
+/// # Arguments
+///
+/// * `config` - The static config of the iceoryx2 service
+///
+/// # Returns
+///
+/// * `PublisherOptions` - The options of the zenoh publisher
+pub fn iox_qos_to_zenoh_publisher_options(config: &IceoryxServiceConfig) -> PublisherOptions {
+    let MessagingPattern::PublishSubscribe(iox_publish_subscribe_config) =
+        config.messaging_pattern()
+    else {
+        return PublisherOptions {
+            reliability: Reliability::Reliable,
+            congestion_control: CongestionControl::Block,
+            priority: Priority::Data,
+        };
+    };
+
+    if iox_publish_subscribe_config.has_safe_overflow() {
+        return PublisherOptions {
+            reliability: Reliability::BestEffort,
+            congestion_control: CongestionControl::Drop,
+            priority: Priority::Data,
+        };
+    }
+
+    let is_latency_sensitive = iox_publish_subscribe_config.history_size() == 0
+        && iox_publish_subscribe_config.subscriber_max_buffer_size() == 1;
+
+    PublisherOptions {
+        reliability: Reliability::Reliable,
+        congestion_control: CongestionControl::Block,
+        priority: if is_latency_sensitive {
+            Priority::DataHigh
+        } else {
+            Priority::Data
+        },
+    }
+}
+
+/// Creates a Zenoh publisher to send payloads from iceoryx2 services to the zenoh instances of
+/// the allowed destination.
 pub(crate) fn z_create_publisher<'a>(
     z_session: &ZenohSession,
+    z_key: &str,
     iox_service_config: &IceoryxServiceConfig,
+    z_publisher_config: ZenohPublisherConfig,
 ) -> Result<ZenohPublisher<'a>, zenoh::Error> {
-    let z_key = keys::publish_subscribe(iox_service_config.service_id());
+    let options = z_publisher_config.options;
     let z_publisher = z_session
-        .declare_publisher(z_key.clone())
-        .allowed_destination(Locality::Remote)
-        .reliability(Reliability::Reliable)
+        .declare_publisher(z_key.to_owned())
+        .allowed_destination(z_publisher_config.allowed_destination)
+        .reliability(options.reliability)
+        .congestion_control(options.congestion_control)
+        .priority(options.priority)
         .wait()?;
     info!(
         "CREATED(zenoh): Publisher {} [{}]",
@@ -203,14 +502,13 @@ pub(crate) fn z_create_publisher<'a>(
 /// Creates a Zenoh subscriber to receive payloads from remote hosts for a particular iceoryx2 service.
 pub(crate) fn z_create_subscriber(
     z_session: &ZenohSession,
+    z_key: &str,
     iox_service_config: &IceoryxServiceConfig,
 ) -> Result<ZenohSubscriber<FifoChannelHandler<Sample>>, zenoh::Error> {
-    let z_key = keys::publish_subscribe(iox_service_config.service_id());
-
     // TODO(correctness): Make handler type and properties configurable
     let z_subscriber = z_session
-        .declare_subscriber(z_key.clone())
-        .with(FifoChannel::new(10))
+        .declare_subscriber(z_key.to_owned())
+        .with(FifoChannel::new(Z_SUBSCRIBER_BUFFER_SIZE))
         .allowed_origin(Locality::Remote)
         .wait()?;
     info!(
@@ -223,15 +521,17 @@ pub(crate) fn z_create_subscriber(
 }
 
 // TODO(correctness): Optimize for notifications
-/// Creates a Zenoh notifier to send notifications from iceoryx2 services to remote hosts.
+/// Creates a Zenoh notifier to send notifications from iceoryx2 services to the zenoh instances
+/// of the allowed destination.
 pub(crate) fn z_create_notifier<'a>(
     z_session: &ZenohSession,
+    z_key: &str,
     iox_service_config: &IceoryxServiceConfig,
+    z_allowed_destination: Locality,
 ) -> Result<ZenohPublisher<'a>, zenoh::Error> {
-    let z_key = keys::event(iox_service_config.service_id());
     let z_notifier = z_session
-        .declare_publisher(z_key.clone())
-        .allowed_destination(Locality::Remote)
+        .declare_publisher(z_key.to_owned())
+        .allowed_destination(z_allowed_destination)
         .reliability(Reliability::Reliable)
         .wait()?;
     info!(
@@ -247,14 +547,13 @@ pub(crate) fn z_create_notifier<'a>(
 /// Creates a Zenoh listener to receive notifications from remote hosts for a particular iceoryx2 service.
 pub(crate) fn z_create_listener(
     z_session: &ZenohSession,
+    z_key: &str,
     iox_service_config: &IceoryxServiceConfig,
 ) -> Result<ZenohSubscriber<FifoChannelHandler<Sample>>, zenoh::Error> {
-    let z_key = keys::event(iox_service_config.service_id());
-
     // TODO(correctness): Make handler type and properties configurable
     let z_listener = z_session
-        .declare_subscriber(z_key.clone())
-        .with(FifoChannel::new(10))
+        .declare_subscriber(z_key.to_owned())
+        .with(FifoChannel::new(Z_SUBSCRIBER_BUFFER_SIZE))
         .allowed_origin(Locality::Remote)
         .wait()?;
     info!(
@@ -266,6 +565,47 @@ pub(crate) fn z_create_listener(
     Ok(z_listener)
 }
 
+/// Creates a Zenoh querier to send requests from iceoryx2 clients to servers on remote hosts.
+pub(crate) fn z_create_querier<'a>(
+    z_session: &ZenohSession,
+    z_key: &str,
+    iox_service_config: &IceoryxServiceConfig,
+) -> Result<ZenohQuerier<'a>, zenoh::Error> {
+    let z_querier = z_session
+        .declare_querier(z_key.to_owned())
+        .allowed_destination(Locality::Remote)
+        .wait()?;
+    info!(
+        "CREATED(zenoh): Querier {} [{}]",
+        z_key,
+        iox_service_config.name()
+    );
+
+    Ok(z_querier)
+}
+
+/// Creates a Zenoh queryable to receive requests from clients on remote hosts for a particular
+/// iceoryx2 service.
+pub(crate) fn z_create_queryable(
+    z_session: &ZenohSession,
+    z_key: &str,
+    iox_service_config: &IceoryxServiceConfig,
+) -> Result<ZenohQueryable<FifoChannelHandler<Query>>, zenoh::Error> {
+    // TODO(correctness): Make handler type and properties configurable
+    let z_queryable = z_session
+        .declare_queryable(z_key.to_owned())
+        .with(FifoChannel::new(Z_SUBSCRIBER_BUFFER_SIZE))
+        .allowed_origin(Locality::Remote)
+        .wait()?;
+    info!(
+        "CREATED(zenoh): Queryable {} [{}]",
+        z_key,
+        iox_service_config.name()
+    );
+
+    Ok(z_queryable)
+}
+
 /// Announces an iceoryx service over Zenoh to make it discoverable by remote hosts.
 pub(crate) fn z_announce_service(
     z_session: &ZenohSession,
@@ -291,6 +631,15 @@ pub(crate) fn z_announce_service(
                 iox_service_config.name()
             );
         }
+        iceoryx2::service::static_config::messaging_pattern::MessagingPattern::RequestResponse(
+            _,
+        ) => {
+            info!(
+                "ANNOUNCING(zenoh): RequestResponse {} [{}]",
+                z_key,
+                iox_service_config.name()
+            );
+        }
         _ => {
             // Not Supported. Nothing to do.
         }
@@ -302,9 +651,10 @@ pub(crate) fn z_announce_service(
         .allowed_destination(Locality::Remote)
         .wait()?;
 
-    // Set up a queryable to respond to future hosts.
+    // Set up a queryable to respond to future hosts. Local queries are answered as well, e.g.
+    // to cross-check the announcements, while the discovery only queries remote hosts.
     z_session
-        .declare_queryable(z_key.clone())
+        .declare_queryable(z_key.to_owned())
         .callback(move |query| {
             if let Err(e) = query
                 .reply(z_key.clone(), iox_service_config_serialized.clone())
@@ -313,7 +663,7 @@ pub(crate) fn z_announce_service(
                 error!("Failed to reply to query {}: {}", z_key, e);
             }
         })
-        .allowed_origin(Locality::Remote)
+        .allowed_origin(Locality::Any)
         .background()
         .wait()?;
 
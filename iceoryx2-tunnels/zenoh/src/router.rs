@@ -0,0 +1,110 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::DiscoveryError;
+use crate::PropagationReport;
+use crate::Scope;
+use crate::Tunnel;
+
+/// Manages multiple named tunnels, e.g. one per remote host in a hub-and-spoke
+/// architecture, and dispatches discovery and propagation to each of them in sequence.
+pub struct TunnelRouter<'a, ServiceType: iceoryx2::service::Service> {
+    tunnels: Vec<(String, Tunnel<'a, ServiceType>)>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> Default for TunnelRouter<'_, ServiceType> {
+    fn default() -> Self {
+        Self {
+            tunnels: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Service: iceoryx2::service::Service> TunnelRouter<'a, Service> {
+    /// Creates a new router without any tunnels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tunnel to be managed by the router under the provided name.
+    ///
+    /// If a tunnel with the same name is already managed, it is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name identifying the tunnel in reports
+    /// * `tunnel` - The tunnel to be managed
+    pub fn add_tunnel(&mut self, name: &str, tunnel: Tunnel<'a, Service>) {
+        match self.tunnels.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => *existing = tunnel,
+            None => self.tunnels.push((name.to_string(), tunnel)),
+        }
+    }
+
+    /// Returns the tunnel managed under the provided name.
+    pub fn tunnel(&self, name: &str) -> Option<&Tunnel<'a, Service>> {
+        self.tunnels
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, tunnel)| tunnel)
+    }
+
+    /// Returns the number of tunnels managed by the router.
+    pub fn len(&self) -> usize {
+        self.tunnels.len()
+    }
+
+    /// Returns `true` if the router does not manage any tunnels.
+    pub fn is_empty(&self) -> bool {
+        self.tunnels.is_empty()
+    }
+
+    /// Runs discovery on all managed tunnels.
+    ///
+    /// Discovery is run on every tunnel, even if it fails for some of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the discovery scope
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If discovery was successful on all tunnels
+    /// * `Err(Vec<(String, DiscoveryError)>)` - The names of the tunnels on which discovery
+    ///   failed, along with the failure
+    pub fn discover_all(&mut self, scope: Scope) -> Result<(), Vec<(String, DiscoveryError)>> {
+        let failures: Vec<(String, DiscoveryError)> = self
+            .tunnels
+            .iter_mut()
+            .filter_map(|(name, tunnel)| tunnel.discover(scope).err().map(|e| (name.clone(), e)))
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+
+    /// Propagates payloads on all managed tunnels.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(String, PropagationReport)>` - The propagation report of each tunnel, in the
+    ///   order the tunnels were added
+    pub fn propagate_all(&self) -> Vec<(String, PropagationReport)> {
+        self.tunnels
+            .iter()
+            .map(|(name, tunnel)| (name.clone(), tunnel.propagate()))
+            .collect()
+    }
+}
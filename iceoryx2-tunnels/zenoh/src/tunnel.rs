@@ -11,282 +11,8771 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::discovery::Discovery;
+use crate::discovery::DiscoveryMode;
 use crate::discovery::IceoryxDiscovery;
 use crate::discovery::ZenohDiscovery;
+use crate::iox_create_publish_subscribe_service;
+use crate::iox_create_publisher;
+use crate::iox_create_subscriber;
+use crate::keys;
+use crate::BackpressurePolicy;
 use crate::BidirectionalEventConnection;
 use crate::BidirectionalPublishSubscribeConnection;
+use crate::BidirectionalRequestResponseConnection;
+#[cfg(feature = "checksum")]
+use crate::ChecksumAlgorithm;
+#[cfg(feature = "compression")]
+use crate::CompressionCodec;
+#[cfg(feature = "compression")]
+use crate::CompressionConfig;
 use crate::Connection;
+use crate::ConnectionDirection;
+use crate::ConnectionHealth;
+use crate::ConnectionStatistics;
+use crate::ConnectionValidation;
+use crate::Direction;
+use crate::EventBatching;
+use crate::FailureTracker;
+use crate::IceoryxServiceLimits;
+#[cfg(feature = "latency-tracking")]
+use crate::LatencyStats;
+use crate::PayloadTaps;
+use crate::PropagationError;
+use crate::QosMapping;
+use crate::SizeHistogram;
+use crate::SpliceError;
+use crate::TunneledConnection;
+use crate::ZenohPublisherConfig;
 
 use iceoryx2::config::Config as IceoryxConfig;
 use iceoryx2::node::Node as IceoryxNode;
 use iceoryx2::node::NodeBuilder;
+use iceoryx2::node::NodeCreationFailure;
+use iceoryx2::node::NodeState;
+use iceoryx2::port::publisher::Publisher as IceoryxPublisher;
+use iceoryx2::port::subscriber::Subscriber as IceoryxSubscriber;
+use iceoryx2::prelude::CallbackProgression;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::messaging_pattern::MessagingPattern as MessagingPatternKind;
 use iceoryx2::service::service_id::ServiceId as IceoryxServiceId;
+use iceoryx2::service::static_config::message_type_details::MessageTypeDetails;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
 use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
 use iceoryx2_bb_log::error;
 use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
+use zenoh::key_expr::KeyExpr;
+use zenoh::liveliness::LivelinessToken;
+use zenoh::qos::CongestionControl;
+use zenoh::qos::Priority;
+use zenoh::qos::Reliability;
+use zenoh::query::Queryable as ZenohQueryable;
+use zenoh::sample::Locality;
 use zenoh::Config as ZenohConfig;
 use zenoh::Session as ZenohSession;
 use zenoh::Wait;
 
+use core::convert::Infallible;
+use core::sync::atomic::Ordering;
+
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::io::IsTerminal;
+#[cfg(feature = "toml-config")]
+use std::path::Path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+/// A user-defined discovery source, see [`Tunnel::create_with_custom_discovery()`].
+type CustomDiscovery<'a> =
+    Box<dyn FnMut(&mut dyn FnMut(&IceoryxServiceConfig)) -> Result<(), DiscoveryError> + Send + 'a>;
+
+/// Maps an iceoryx2 service to the zenoh key expression on which its payloads, events or
+/// requests are exchanged, see [`TunnelConfig::key_expr_mapper`].
+pub type KeyExprMapper = dyn Fn(&IceoryxServiceConfig) -> String + Send + Sync;
+
+/// Called with the details of a service whose connection was created, see
+/// [`TunnelConfig::on_connection_created`].
+pub type ConnectionCreatedCallback = dyn Fn(&TunneledServiceInfo) + Send + Sync;
+
+/// Called with the id of a service whose connection was removed, see
+/// [`TunnelConfig::on_connection_removed`].
+pub type ConnectionRemovedCallback = dyn Fn(&IceoryxServiceId) + Send + Sync;
+
+/// The maximum duration to close the zenoh session of a tunnel created with
+/// [`Tunnel::create_transient()`] unless configured otherwise.
+const TRANSIENT_CLOSE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// The maximum duration [`TunnelHAController`] waits for the liveliness token of the primary
+/// tunnel.
+const HA_LIVELINESS_QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// The period between two queries of [`Tunnel::cross_check_with_zenoh()`].
+const CROSS_CHECK_RETRY_PERIOD: Duration = Duration::from_millis(50);
+
+/// The period after which [`Tunnel::test_message_roundtrip()`] sends the payload again when
+/// it was not received, e.g. while the zenoh entities are not matched yet.
+const ROUNDTRIP_RETRY_PERIOD: Duration = Duration::from_millis(50);
+
+/// The transitions between the lifecycle states of a connection as `(from, to, trigger)`,
+/// see [`Tunnel::export_connection_state_machine_diagram()`].
+const CONNECTION_STATE_TRANSITIONS: &[(&str, &str, &str)] = &[
+    ("[*]", "Creating", "service discovered"),
+    ("Creating", "Active", "connection created"),
+    (
+        "Creating",
+        "Creating",
+        "creation failed, retried on next discovery",
+    ),
+    ("Active", "Paused", "propagation disabled"),
+    ("Paused", "Active", "propagation enabled"),
+    ("Active", "Resetting", "reconnect() or force_rediscover()"),
+    ("Paused", "Resetting", "reconnect() or force_rediscover()"),
+    ("Resetting", "Creating", "service discovered anew"),
+    ("Active", "Closed", "service stale, evicted or removed"),
+    ("Paused", "Closed", "service stale, evicted or removed"),
+    ("Closed", "[*]", "resources released"),
+];
+
+/// The version of the [`TunnelCapabilities`] announced by the tunnel, incremented whenever
+/// the format of the propagated payloads changes.
+pub const TUNNEL_CAPABILITIES_VERSION: u32 = 1;
+
+/// Determines how the tunnel reacts to connections that repeatedly fail to propagate.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropagationPolicy {
+    /// Keep propagating over failing connections.
+    #[default]
+    Continue,
+    /// Disable propagation over a connection after the given number of consecutive failures.
+    /// Receive timeouts and backpressure indicate a busy connection and are not counted.
+    DisableAfter(u32),
+}
+
+/// Determines whether the tunnel reconnects to zenoh on its own when the zenoh session is
+/// lost, see [`Tunnel::propagate_with_reconnect()`], [`Tunnel::run()`] and
+/// [`Tunnel::propagate_in_thread()`].
+///
+/// [`Tunnel::propagate()`] does not reconnect since it cannot replace the session of a
+/// shared tunnel.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReconnectPolicy {
+    /// Reconnect only when [`Tunnel::reconnect()`] is called.
+    #[default]
+    Manual,
+    /// Reconnect when the zenoh session is lost, doubling the delay between two failed
+    /// attempts up to the maximum delay.
+    ExponentialBackoff {
+        /// The delay after the first failed attempt.
+        initial_delay: Duration,
+        /// The upper bound of the delay between two attempts.
+        max_delay: Duration,
+        /// The number of attempts before giving up.
+        max_attempts: u32,
+    },
+}
+
+/// Determines how the tunnel reacts to newly discovered services once
+/// [`TunnelConfig::max_connections`] is reached.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum MaxConnectionsPolicy {
+    /// Do not tunnel newly discovered services until connections are removed. The skipped
+    /// services are connected in the order they were discovered in once there is room, see
+    /// [`Tunnel::num_pending_connections()`].
+    #[default]
+    SkipNew,
+    /// Remove the connection that was active least recently to tunnel the newly discovered
+    /// service.
+    EvictLeastRecentlyUsed,
+}
+
+/// Determines how [`Tunnel::run()`] and [`Tunnel::run_until_cancelled()`] react to failed
+/// discoveries and propagations.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunErrorPolicy {
+    /// Log the failure and continue with the next tick.
+    #[default]
+    LogAndContinue,
+    /// Stop running and return the failure.
+    Abort,
+}
+
+/// Decides per connection whether it is propagated over by
+/// [`Tunnel::propagate_with_context()`], based on a context that is only known when
+/// propagating, e.g. the current network conditions.
+pub trait PropagationInterceptor<C> {
+    /// Returns `true` if the connection of the service shall be propagated over.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    /// * `context` - The context provided to [`Tunnel::propagate_with_context()`]
+    fn intercept(&self, id: &IceoryxServiceId, context: &C) -> bool;
+}
+
+impl<C, F: Fn(&IceoryxServiceId, &C) -> bool> PropagationInterceptor<C> for F {
+    fn intercept(&self, id: &IceoryxServiceId, context: &C) -> bool {
+        self(id, context)
+    }
+}
+
+/// The message types a service is expected to use, see [`SchemaRegistry`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum TypeDescriptor {
+    /// The message types of a publish-subscribe service.
+    PublishSubscribe(MessageTypeDetails),
+    /// The message types of the requests and responses of a request-response service.
+    RequestResponse {
+        request: MessageTypeDetails,
+        response: MessageTypeDetails,
+    },
+    /// Event services do not exchange typed messages.
+    Event,
+}
+
+impl TypeDescriptor {
+    /// Returns the message types of the provided service, or `None` if services with its
+    /// messaging pattern are not tunneled.
+    pub fn of(iox_service_config: &IceoryxServiceConfig) -> Option<Self> {
+        match iox_service_config.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(config) => Some(TypeDescriptor::PublishSubscribe(
+                config.message_type_details().clone(),
+            )),
+            MessagingPattern::RequestResponse(config) => Some(TypeDescriptor::RequestResponse {
+                request: config.request_message_type_details().clone(),
+                response: config.response_message_type_details().clone(),
+            }),
+            MessagingPattern::Event(_) => Some(TypeDescriptor::Event),
+            _ => None,
+        }
+    }
+}
+
+/// A central store of the message types the services of a system are expected to use, see
+/// [`Tunnel::enforce_schema_registry()`].
+pub trait SchemaRegistry {
+    /// Returns the message types expected for the service with the provided name, or `None`
+    /// if the service is unknown to the registry.
+    fn lookup(&self, service_name: &str) -> Option<TypeDescriptor>;
+}
+
+/// Determines how [`Tunnel::merge_from()`] handles the services that are tunneled by both
+/// tunnels.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum MergePolicy {
+    /// Keep the connection of the tunnel that is merged into.
+    #[default]
+    Skip,
+    /// Replace the connection of the tunnel that is merged into.
+    Replace,
+}
+
+/// The formats of the diagrams exported by
+/// [`Tunnel::export_connection_state_machine_diagram()`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DiagramFormat {
+    /// A [Mermaid](https://mermaid.js.org) state diagram.
+    Mermaid,
+    /// A [PlantUML](https://plantuml.com) state diagram.
+    PlantUml,
+}
+
+/// The delays between the discoveries of [`Tunnel::wait_for_service()`]. The delay starts at
+/// `initial_delay` and is multiplied by `multiplier` after each discovery, up to `max_delay`.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaitBackoff {
+    /// The delay after the first discovery.
+    pub initial_delay: Duration,
+    /// The factor by which the delay grows after each discovery.
+    pub multiplier: u32,
+    /// The upper bound of the delay between two discoveries.
+    pub max_delay: Duration,
+}
+
+impl Default for WaitBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(10),
+            multiplier: 2,
+            max_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Strongly-typed zenoh session properties that are applied on top of the zenoh configuration
+/// of the tunnel before the session is opened. Properties that are not set keep the value of
+/// the zenoh configuration.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZenohSessionOptions {
+    /// Enables or disables scouting for other zenoh instances via UDP multicast.
+    pub multicast_scouting: Option<bool>,
+    /// Enables or disables scouting for other zenoh instances via gossip.
+    pub gossip_scouting: Option<bool>,
+    /// Enables or disables the shared memory transport between zenoh instances on the same
+    /// host.
+    pub shared_memory: Option<bool>,
+}
+
+impl ZenohSessionOptions {
+    /// Combines the options with the options of `overlay`, which take precedence.
+    fn merge(self, overlay: ZenohSessionOptions) -> ZenohSessionOptions {
+        ZenohSessionOptions {
+            multicast_scouting: overlay.multicast_scouting.or(self.multicast_scouting),
+            gossip_scouting: overlay.gossip_scouting.or(self.gossip_scouting),
+            shared_memory: overlay.shared_memory.or(self.shared_memory),
+        }
+    }
+
+    /// Applies the options that are set to the provided zenoh configuration.
+    fn apply(&self, z_config: &mut ZenohConfig) -> Result<(), CreationError> {
+        let options = [
+            ("scouting/multicast/enabled", self.multicast_scouting),
+            ("scouting/gossip/enabled", self.gossip_scouting),
+            ("transport/shared_memory/enabled", self.shared_memory),
+        ];
+
+        for (key, value) in options {
+            if let Some(value) = value {
+                z_config
+                    .insert_json5(key, &value.to_string())
+                    .map_err(|e| CreationError::InvalidZenohSessionOption {
+                        key: key.to_string(),
+                        reason: e.to_string(),
+                    })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The services excluded from tunneling, see [`TunnelConfig::service_blocklist`].
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceBlocklist {
+    /// The ids of the excluded services.
+    pub service_ids: Vec<IceoryxServiceId>,
+    /// Patterns matching the names of the excluded services, in which `*` matches any
+    /// sequence of characters and `?` matches a single character.
+    pub name_patterns: Vec<String>,
+}
+
+/// The weights and expected values from which [`Tunnel::service_quality_score()`] is computed.
+/// The weights are relative to each other and do not need to add up to `1.0`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+pub struct QualityScoreConfig {
+    /// The weight of the share of successful propagations.
+    pub error_rate_weight: f64,
+    /// The weight of the latency of the payloads compared to the latency budget.
+    pub latency_weight: f64,
+    /// The weight of the throughput compared to the expected throughput.
+    pub throughput_weight: f64,
+    /// The weight of the share of local samples that were not missed, see
+    /// [`Tunnel::service_missed_samples()`].
+    pub missed_samples_weight: f64,
+    /// The latency budget of the services for which no budget was set with
+    /// [`Tunnel::set_service_latency_budget()`]. Only used to rate the latency of remote
+    /// payloads, which requires the `latency-tracking` feature.
+    pub latency_budget: Duration,
+    /// The number of messages a service is expected to forward per second. When not set,
+    /// the throughput is not rated.
+    pub expected_messages_per_second: Option<f64>,
+}
+
+impl Default for QualityScoreConfig {
+    fn default() -> Self {
+        Self {
+            error_rate_weight: 0.4,
+            latency_weight: 0.3,
+            throughput_weight: 0.2,
+            missed_samples_weight: 0.1,
+            latency_budget: Duration::from_millis(10),
+            expected_messages_per_second: None,
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "toml-config", serde(default))]
+pub struct TunnelConfig {
+    pub discovery_service: Option<String>,
+    pub propagation_policy: PropagationPolicy,
+    /// The minimum interval between two discoveries, calls to [`Tunnel::discover()`] within
+    /// the interval are skipped, including the discoveries of [`Tunnel::run()`].
+    pub min_discovery_interval: Option<Duration>,
+    /// The zenoh key expression on which remote service details are queried, defaults to
+    /// [`keys::discovery()`], or [`keys::service_registries()`] when discovering via
+    /// [`DiscoveryMode::Queryable`].
+    pub discovery_key_expression: Option<String>,
+    /// Determines how the details of remote services are retrieved. Tunnels announce each of
+    /// their services regardless of their mode, so tunnels in [`DiscoveryMode::PerService`]
+    /// discover tunnels in either mode, while tunnels in [`DiscoveryMode::Queryable`] only
+    /// discover the tunnels in the same mode.
+    pub discovery_mode: DiscoveryMode,
+    /// Disables the dead node monitoring of the iceoryx2 node of the tunnel, which otherwise
+    /// checks for and cleans up dead nodes whenever the node is created or dropped.
+    ///
+    /// This removes the monitoring overhead, but the stale resources of crashed nodes are no
+    /// longer reaped automatically by the tunnel.
+    pub disable_iox_monitoring: bool,
+    /// Compresses the publish-subscribe payloads larger than
+    /// [`DEFAULT_COMPRESSION_THRESHOLD`](crate::DEFAULT_COMPRESSION_THRESHOLD) before
+    /// propagating them to remote hosts.
+    #[cfg(feature = "compression")]
+    pub compression: bool,
+    /// The codec the publish-subscribe payloads are compressed with when
+    /// [`TunnelConfig::compression`] is enabled.
+    #[cfg(feature = "compression")]
+    pub compression_codec: CompressionCodec,
+    /// Appends a checksum computed with the provided algorithm to the publish-subscribe
+    /// payloads propagated to remote hosts and drops the remote payloads whose checksum does
+    /// not match, e.g. to detect payloads corrupted on unreliable links. The dropped payloads
+    /// are counted in [`TunnelStatistics::corrupted_messages_count`]. Changes the format of
+    /// the payloads on zenoh, all connected tunnels must use the same algorithm.
+    #[cfg(feature = "checksum")]
+    pub checksum_verification: Option<ChecksumAlgorithm>,
+    /// Prefixes the publish-subscribe payloads propagated to remote hosts with a sequence
+    /// number incremented per service, e.g. to correlate a payload across hosts when tracing.
+    /// The sequence number of the most recent remote payload is available via
+    /// [`Tunnel::last_received_message_id()`]. Changes the format of the payloads on zenoh,
+    /// all connected tunnels must track message ids.
+    pub track_message_ids: bool,
+    /// The maximum duration a connection spends receiving local payloads per propagation, as
+    /// a continuous stream of payloads would otherwise stall the propagation indefinitely.
+    /// When exceeded, the propagation continues with the next connection. Applies to the local
+    /// payloads of publish-subscribe services, the events of event services and the requests
+    /// of request-response services.
+    pub iceoryx_receive_timeout: Option<Duration>,
+    /// Determines how the local payloads of publish-subscribe services are handled while
+    /// their rate limit is exhausted, see [`Tunnel::set_rate_limit()`], or while zenoh is
+    /// congested, see [`BackpressurePolicy`]. When not set, the payloads exceeding the rate
+    /// limit are dropped like with [`BackpressurePolicy::DropNewest`] and the congestion
+    /// control of zenoh follows the [`TunnelConfig::qos_mapping`].
+    pub backpressure_policy: Option<BackpressurePolicy>,
+    /// The maximum duration a publish-subscribe or event connection stays idle on zenoh. When
+    /// no local payload or event was propagated within the interval, an empty keep-alive is
+    /// published instead, so that zenoh routers with an idle timeout keep the connection.
+    /// Keep-alives are not propagated to the local host, all connected tunnels must
+    /// understand them.
+    pub keep_alive_interval: Option<Duration>,
+    /// Replays the history of publish-subscribe services with a `history_size()` to the remote
+    /// tunnels that subscribe to them after their payloads were propagated, like iceoryx2
+    /// delivers the history to late-joining local subscribers. Remote tunnels query the
+    /// history once when they subscribe, so all connected tunnels must enable the replay. The
+    /// remote tunnels that subscribed before do not receive the history again.
+    pub replay_history_on_connect: bool,
+    /// The maximum number of services tunneled at the same time, unlimited if not set, e.g.
+    /// to not exhaust the file descriptors or memory of embedded devices. Only
+    /// publish-subscribe and event services count towards the limit.
+    pub max_connections: Option<usize>,
+    /// Determines how newly discovered services are handled once
+    /// [`TunnelConfig::max_connections`] is reached.
+    pub max_connections_policy: MaxConnectionsPolicy,
+    /// Determines whether [`Tunnel::diagnostic_report()`] highlights its output with ANSI
+    /// colors. When not set, colors are used unless the `NO_COLOR` environment variable is
+    /// set or stdout or stderr is not a terminal.
+    pub use_color: Option<bool>,
+    /// Zenoh session properties applied on top of the zenoh configuration provided on
+    /// creation.
+    pub zenoh_session_options: ZenohSessionOptions,
+    /// The maximum duration to close the zenoh session when the tunnel is dropped. When not
+    /// set, the session is closed with the default timeout of zenoh.
+    pub close_timeout: Option<Duration>,
+    /// The names of the services to tunnel, services with other names are not connected when
+    /// discovered locally or on remote hosts. When not set or empty, all services are
+    /// tunneled. See [`Tunnel::with_service_allowed_list()`].
+    pub service_name_allowlist: Option<Vec<String>>,
+    /// The services that are not tunneled, e.g. internal services of a system that
+    /// otherwise tunnels all services. Blocked services are not connected even if they are
+    /// allowed by the [`TunnelConfig::service_name_allowlist`].
+    pub service_blocklist: Option<ServiceBlocklist>,
+    /// Determines whether the tunnel reconnects to zenoh on its own when the zenoh session
+    /// is lost. Not applied by [`Tunnel::propagate()`], see [`ReconnectPolicy`].
+    pub reconnect_policy: ReconnectPolicy,
+    /// Maps the tunneled services to the zenoh key expressions of their connections, e.g. to
+    /// integrate with an existing zenoh topic hierarchy. When not set, the keys of
+    /// [`keys`] are used. Tunnels only exchange the payloads of a service when their mappers
+    /// produce the same key expression for it. A service mapped to the key expression of an
+    /// already connected service is not connected, while the payloads of services whose key
+    /// expressions include each other are mixed, see
+    /// [`Tunnel::service_zenoh_key_collision_map()`].
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub key_expr_mapper: Option<Arc<KeyExprMapper>>,
+    /// Treats [`Scope::Iceoryx`] and [`Scope::Zenoh`] as [`Scope::Both`] when discovering, e.g.
+    /// for hybrid topologies in which the distinction between local and remote services is
+    /// meaningless. The scope in which a service was discovered is still recorded, see
+    /// [`Tunnel::service_discovery_source()`].
+    pub scope_aliasing: bool,
+    /// The directions in which the payloads of publish-subscribe services are propagated,
+    /// e.g. only [`ConnectionDirection::IceoryxToZenoh`] on a host that only produces. Event
+    /// services are always connected bidirectionally.
+    pub connection_direction: ConnectionDirection,
+    /// Determines the quality of service of the zenoh publishers of publish-subscribe
+    /// services. By default, all payloads are propagated reliably.
+    pub qos_mapping: QosMapping,
+    /// Propagates the local events of each event service in batches instead of one zenoh
+    /// payload per event, e.g. to reduce the number of zenoh messages at high event rates.
+    /// Tunnels receiving the batches must understand the batched format, which was added
+    /// alongside this setting.
+    pub event_batching: Option<EventBatching>,
+    /// Called whenever the connection of a service was created, e.g. to notify an
+    /// orchestration system without polling [`Tunnel::tunneled_service_infos()`]. The
+    /// callback is called from within discovery and must not block.
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub on_connection_created: Option<Arc<ConnectionCreatedCallback>>,
+    /// Called whenever the connection of a service was removed, e.g. because the service no
+    /// longer exists or was evicted. The callback is called from within discovery and must
+    /// not block.
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub on_connection_removed: Option<Arc<ConnectionRemovedCallback>>,
+    /// Restricts the zenoh instances the local payloads and events are published to, e.g.
+    /// [`Locality::SessionLocal`] to keep them within the local zenoh session and prevent
+    /// propagation beyond the intended network boundary. When not set, they are published to
+    /// [`Locality::Remote`] instances only.
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub zenoh_allowed_destination: Option<Locality>,
+    /// Determines how [`Tunnel::run()`] and [`Tunnel::run_until_cancelled()`] react to failed
+    /// discoveries and propagations.
+    pub run_error_policy: RunErrorPolicy,
+    /// The delays between the discoveries of [`Tunnel::wait_for_service()`].
+    pub wait_backoff: WaitBackoff,
+    /// Restricts the discovery of remote services to the zenoh instance at the provided
+    /// endpoint, e.g. `tcp/10.0.0.1:7447`, for environments without multicast. The remote
+    /// services are queried via a dedicated zenoh session that does not scout for other zenoh
+    /// instances, while the payloads are still exchanged via the zenoh session of the tunnel.
+    pub discovery_scouting_endpoint: Option<String>,
+    /// The zenoh configurations of additional sessions opened beside the session of the zenoh
+    /// configuration provided on creation, e.g. to bridge the local services to a LAN router
+    /// and a cloud router at the same time. Remote services are discovered on all sessions,
+    /// while the publish-subscribe payloads and events of each service are propagated over
+    /// all of them. Request-response services are only connected via the primary session.
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub zenoh_sessions: Vec<ZenohConfig>,
+    /// The maximum number of publishers of the publish-subscribe services created by the
+    /// tunnel, e.g. to reduce the memory footprint on resource-constrained systems. When not
+    /// set, the limit of the discovered service is used. The tunnel requires one publisher
+    /// per service itself.
+    pub iceoryx_max_publishers: Option<u64>,
+    /// The maximum number of subscribers of the publish-subscribe services created by the
+    /// tunnel. When not set, the limit of the discovered service is used. The tunnel
+    /// requires one subscriber per service itself.
+    pub iceoryx_max_subscribers: Option<u64>,
+    /// Rejects the services unknown to the schema registry enforced with
+    /// [`Tunnel::enforce_schema_registry()`] instead of tunneling them unchecked.
+    pub reject_unknown_services: bool,
+    /// The weights and expected values of [`Tunnel::service_quality_score()`]. When not set,
+    /// [`QualityScoreConfig::default()`] is used.
+    pub quality_score_config: Option<QualityScoreConfig>,
+}
+
+#[cfg(feature = "toml-config")]
+impl TunnelConfig {
+    /// Loads the tunnel configuration from a TOML file, e.g. when the tunnel is launched by
+    /// a system service. Fields missing in the file keep their default value, while the
+    /// [`TunnelConfig::key_expr_mapper`] and the connection callbacks cannot be loaded and
+    /// remain unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the TOML file
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TunnelConfig)` - The configuration stored in the file
+    /// * `Err(ConfigLoadError::FailedToReadConfigFileContents)` - If the file could not be read
+    /// * `Err(ConfigLoadError::UnableToDeserializeContents)` - If the file is not a valid
+    ///   TOML tunnel configuration
+    pub fn from_toml_file(path: &Path) -> Result<TunnelConfig, ConfigLoadError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            error!(
+                "Failed to read tunnel config file {}: {}",
+                path.display(),
+                e
+            );
+            ConfigLoadError::FailedToReadConfigFileContents
+        })?;
+
+        toml::from_str(&contents).map_err(|e| {
+            error!(
+                "Failed to deserialize tunnel config file {}: {}",
+                path.display(),
+                e
+            );
+            ConfigLoadError::UnableToDeserializeContents
+        })
+    }
+}
+
+/// The settings of a [`TunnelConfig`] to override with [`Tunnel::merge_configs()`], e.g. the
+/// settings provided via CLI flags on top of a config file.
+///
+/// Each field overrides the field of the same name of the [`TunnelConfig`] when set, the
+/// fields that are not set keep the value of the base configuration. As the optional settings
+/// of [`TunnelConfig`] are overridden with their value, they cannot be unset by an overlay.
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "toml-config", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "toml-config", serde(default))]
+pub struct TunnelConfigOverlay {
+    pub discovery_service: Option<String>,
+    pub propagation_policy: Option<PropagationPolicy>,
+    pub min_discovery_interval: Option<Duration>,
+    pub discovery_key_expression: Option<String>,
+    pub discovery_mode: Option<DiscoveryMode>,
+    pub disable_iox_monitoring: Option<bool>,
+    #[cfg(feature = "compression")]
+    pub compression: Option<bool>,
+    #[cfg(feature = "compression")]
+    pub compression_codec: Option<CompressionCodec>,
+    #[cfg(feature = "checksum")]
+    pub checksum_verification: Option<ChecksumAlgorithm>,
+    pub track_message_ids: Option<bool>,
+    pub iceoryx_receive_timeout: Option<Duration>,
+    pub backpressure_policy: Option<BackpressurePolicy>,
+    pub keep_alive_interval: Option<Duration>,
+    pub replay_history_on_connect: Option<bool>,
+    pub max_connections: Option<usize>,
+    pub max_connections_policy: Option<MaxConnectionsPolicy>,
+    pub use_color: Option<bool>,
+    /// The zenoh session properties set in the overlay override the ones of the base
+    /// configuration, see [`ZenohSessionOptions`].
+    pub zenoh_session_options: ZenohSessionOptions,
+    pub close_timeout: Option<Duration>,
+    pub service_name_allowlist: Option<Vec<String>>,
+    pub service_blocklist: Option<ServiceBlocklist>,
+    pub reconnect_policy: Option<ReconnectPolicy>,
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub key_expr_mapper: Option<Arc<KeyExprMapper>>,
+    pub scope_aliasing: Option<bool>,
+    pub connection_direction: Option<ConnectionDirection>,
+    pub qos_mapping: Option<QosMapping>,
+    pub event_batching: Option<EventBatching>,
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub on_connection_created: Option<Arc<ConnectionCreatedCallback>>,
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub on_connection_removed: Option<Arc<ConnectionRemovedCallback>>,
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub zenoh_allowed_destination: Option<Locality>,
+    pub run_error_policy: Option<RunErrorPolicy>,
+    pub wait_backoff: Option<WaitBackoff>,
+    pub discovery_scouting_endpoint: Option<String>,
+    #[cfg_attr(feature = "toml-config", serde(skip))]
+    pub zenoh_sessions: Option<Vec<ZenohConfig>>,
+    pub iceoryx_max_publishers: Option<u64>,
+    pub iceoryx_max_subscribers: Option<u64>,
+    pub reject_unknown_services: Option<bool>,
+    pub quality_score_config: Option<QualityScoreConfig>,
+}
+
+/// Combines the tunnel, iceoryx2 and zenoh configurations of a tunnel, e.g. for deployments
+/// that configure the tunnel with a single file, see [`Tunnel::create_from_unified()`].
+///
+/// The configurations are stored in the `[tunnel]`, `[iceoryx]` and `[zenoh]` sections. A
+/// missing section keeps the default configuration, whereas the `[iceoryx]` section must be
+/// a complete iceoryx2 configuration.
+#[cfg(feature = "toml-config")]
+#[derive(Default, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct UnifiedTunnelConfig {
+    /// The configuration of the tunnel.
+    pub tunnel: TunnelConfig,
+    /// The iceoryx2 configuration to be used.
+    pub iceoryx: IceoryxConfig,
+    /// The zenoh configuration to be used, in the format of a zenoh configuration file.
+    #[serde(deserialize_with = "deserialize_zenoh_config")]
+    pub zenoh: ZenohConfig,
+}
+
+#[cfg(feature = "toml-config")]
+impl UnifiedTunnelConfig {
+    /// Loads the unified configuration from a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the TOML file
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(UnifiedTunnelConfig)` - The configuration stored in the file
+    /// * `Err(ConfigLoadError::FailedToReadConfigFileContents)` - If the file could not be read
+    /// * `Err(ConfigLoadError::UnableToDeserializeContents)` - If the file is not a valid
+    ///   TOML unified configuration
+    pub fn from_toml_file(path: &Path) -> Result<UnifiedTunnelConfig, ConfigLoadError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            error!(
+                "Failed to read unified tunnel config file {}: {}",
+                path.display(),
+                e
+            );
+            ConfigLoadError::FailedToReadConfigFileContents
+        })?;
+
+        toml::from_str(&contents).map_err(|e| {
+            error!(
+                "Failed to deserialize unified tunnel config file {}: {}",
+                path.display(),
+                e
+            );
+            ConfigLoadError::UnableToDeserializeContents
+        })
+    }
+}
+
+/// Deserializes the zenoh configuration of the `[zenoh]` section by handing it to zenoh as
+/// JSON, as the zenoh configuration does not implement `Deserialize`.
+#[cfg(feature = "toml-config")]
+fn deserialize_zenoh_config<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ZenohConfig, D::Error> {
+    let z_config: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+
+    ZenohConfig::from_json5(&z_config.to_string()).map_err(serde::de::Error::custom)
+}
+
+#[cfg(feature = "toml-config")]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ConfigLoadError {
+    /// The configuration file could not be read.
+    FailedToReadConfigFileContents,
+    /// The contents of the configuration file are not a valid TOML tunnel configuration.
+    UnableToDeserializeContents,
+}
+
+#[cfg(feature = "toml-config")]
+impl core::fmt::Display for ConfigLoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "ConfigLoadError::{self:?}")
+    }
+}
+
+#[cfg(feature = "toml-config")]
+impl core::error::Error for ConfigLoadError {}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum CreationError {
+    /// An option of the [`ZenohSessionOptions`] could not be applied to the zenoh
+    /// configuration.
+    InvalidZenohSessionOption {
+        /// The key of the option in the zenoh configuration.
+        key: String,
+        /// The reason why the option could not be applied.
+        reason: String,
+    },
+    /// The zenoh session could not be opened with the provided zenoh configuration.
+    ZenohSessionOpenFailed {
+        /// The reason why the zenoh session could not be opened.
+        reason: String,
+    },
+    /// The iceoryx2 node of the tunnel could not be created.
+    IceoryxNodeCreationFailed(NodeCreationFailure),
+    /// The query for the service details announced by remote hosts could not be declared.
+    ZenohDiscoveryCreationFailed {
+        /// The reason why the query could not be declared.
+        reason: String,
+    },
+    /// The discovery of local iceoryx2 services could not be set up, e.g. due to an invalid
+    /// [`TunnelConfig::discovery_service`].
+    IceoryxDiscoveryCreationFailed {
+        /// The reason why the discovery could not be set up.
+        reason: String,
+    },
+    /// The liveliness token announcing the primary tunnel of a hot-standby pair could not
+    /// be declared, see [`Tunnel::create_ha_pair()`].
+    HaTokenDeclarationFailed {
+        /// The reason why the liveliness token could not be declared.
+        reason: String,
+    },
+    /// The tunnel was not created within the timeout of [`TunnelCreationFuture::wait()`].
+    Timeout,
+    /// The background creation of the tunnel ended without providing a tunnel, e.g. because
+    /// the created tunnel was already returned by [`TunnelCreationFuture::wait()`].
+    CreationAborted,
+    /// No iceoryx2 configuration was provided to the [`TunnelBuilder`].
+    MissingIceoryxConfig,
+    /// The zenoh key expression of a service is already used by the connection of another
+    /// service, e.g. due to colliding service name hashes.
+    KeyExpressionCollision {
+        conflicting_service: IceoryxServiceId,
+    },
+    /// The zenoh key expression of a service is not a valid key expression, e.g. because the
+    /// [`TunnelConfig::key_expr_mapper`] maps a service name containing `#` into it.
+    InvalidKeyExpression {
+        service_name: String,
+        key_expr: String,
+        reason: String,
+    },
+    /// The service does not use the messaging pattern required by the operation, e.g. a
+    /// publish-subscribe service was provided to [`Tunnel::add_event_connection_manually()`].
+    UnexpectedMessagingPattern,
+    /// The iceoryx2 ports or zenoh entities of the connection of a service could not be
+    /// created.
+    ConnectionCreationFailed,
+    /// The configuration file provided to [`Tunnel::create_from_unified()`] could not be
+    /// loaded.
+    #[cfg(feature = "toml-config")]
+    ConfigLoadFailed(ConfigLoadError),
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            CreationError::IceoryxNodeCreationFailed(cause) => Some(cause),
+            #[cfg(feature = "toml-config")]
+            CreationError::ConfigLoadFailed(cause) => Some(cause),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum WatchError {
+    Error,
+}
+
+impl core::fmt::Display for WatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "WatchError::{self:?}")
+    }
+}
+
+impl core::error::Error for WatchError {}
+
+#[cfg(feature = "crossbeam")]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ObserveError {
+    /// No publish-subscribe service with the provided id is tunneled.
+    ServiceNotFound,
+}
+
+#[cfg(feature = "crossbeam")]
+impl core::fmt::Display for ObserveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "ObserveError::{self:?}")
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl core::error::Error for ObserveError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ConnectionCreationError {
+    Error,
+    /// The messaging pattern of the service is not tunneled.
+    UnsupportedMessagingPattern,
+    /// The zenoh key expression of the service is already used by the connection of another
+    /// service, e.g. due to colliding service name hashes.
+    KeyExpressionCollision,
+    /// The zenoh key expression of the service, as determined by the
+    /// [`TunnelConfig::key_expr_mapper`], is no valid key expression.
+    InvalidKeyExpression,
+    /// The message types of the service differ from the schema registry, see
+    /// [`Tunnel::enforce_schema_registry()`].
+    SchemaTypeMismatch,
+    /// The service is unknown to the schema registry, see
+    /// [`Tunnel::enforce_schema_registry()`], and unknown services are rejected.
+    UnknownToSchemaRegistry,
+}
+
+impl core::fmt::Display for ConnectionCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "ConnectionCreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for ConnectionCreationError {}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum DiscoveryError {
+    /// Discovering the local iceoryx2 services failed, either via the iceoryx2 discovery of
+    /// the tunnel or via the custom discovery.
+    IceoryxDiscoveryFailed {
+        /// The reason why the discovery failed.
+        reason: String,
+    },
+    /// Discovering the services announced by remote hosts via zenoh failed.
+    ZenohDiscoveryFailed {
+        /// The reason why the discovery failed.
+        reason: String,
+    },
+    /// The key expression provided to [`Tunnel::set_discovery_key_expression()`] is not a
+    /// valid zenoh key expression.
+    InvalidKeyExpression {
+        /// The invalid key expression.
+        key_expr: String,
+        /// The reason why the key expression is invalid.
+        reason: String,
+    },
+    /// The connection of a discovered service could not be created. The other discovered
+    /// services are connected regardless.
+    ServiceConnectionFailed {
+        /// The id of the service whose connection could not be created.
+        service_id: String,
+        /// The name of the service whose connection could not be created.
+        service_name: String,
+        /// The reason why the connection could not be created.
+        reason: String,
+    },
+}
+
+#[cfg(not(feature = "strict-errors"))]
+impl DiscoveryError {
+    fn service_connection_failed(
+        iox_service_config: &IceoryxServiceConfig,
+        reason: impl core::fmt::Debug,
+    ) -> Self {
+        Self::ServiceConnectionFailed {
+            service_id: iox_service_config.service_id().as_str().to_string(),
+            service_name: iox_service_config.name().as_str().to_string(),
+            reason: format!("{reason:?}"),
+        }
+    }
+}
+
+impl core::fmt::Display for DiscoveryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "DiscoveryError::{self:?}")
+    }
+}
+
+impl core::error::Error for DiscoveryError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CrossCheckError {
+    Error,
+}
+
+impl core::fmt::Display for CrossCheckError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CrossCheckError::{self:?}")
+    }
+}
+
+impl core::error::Error for CrossCheckError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DrainError {
+    /// The pending local payloads were not drained within the timeout.
+    Timeout,
+}
+
+impl core::fmt::Display for DrainError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "DrainError::{self:?}")
+    }
+}
+
+impl core::error::Error for DrainError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FlushError {
+    /// Pending local events were left after the timeout.
+    Timeout,
+    /// The propagation over an event connection failed.
+    PropagationFailed(PropagationError),
+}
+
+impl core::fmt::Display for FlushError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "FlushError::{self:?}")
+    }
+}
+
+impl core::error::Error for FlushError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FailoverError {
+    /// The liveliness token of the primary tunnel is still declared, activating the standby
+    /// tunnel would propagate every message twice.
+    PrimaryAlive,
+    /// The standby tunnel was activated already.
+    AlreadyActive,
+}
+
+impl core::fmt::Display for FailoverError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "FailoverError::{self:?}")
+    }
+}
+
+impl core::error::Error for FailoverError {}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SchemaEnforcementError {
+    /// The connections of tunneled services that violate the schema registry were removed
+    /// and recorded as failed services.
+    ServicesRejected {
+        /// The names of the services whose message types differ from the registry.
+        mismatched: Vec<String>,
+        /// The names of the services unknown to the registry, see
+        /// [`TunnelConfig::reject_unknown_services`].
+        unknown: Vec<String>,
+    },
+}
+
+impl core::fmt::Display for SchemaEnforcementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "SchemaEnforcementError::{self:?}")
+    }
+}
+
+impl core::error::Error for SchemaEnforcementError {}
+
+/// The reason a service is not tunneled due to the schema registry.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum SchemaViolation {
+    /// The message types of the service differ from the registry.
+    TypeMismatch,
+    /// The service is unknown to the registry.
+    UnknownService,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundtripError {
+    /// No publish-subscribe service with the provided name is tunneled.
+    ServiceNotFound,
+    /// The payload is empty or not a multiple of the payload size of the service.
+    InvalidPayload,
+    /// The payload was not received within the timeout.
+    Timeout,
+    Error,
+}
+
+impl core::fmt::Display for RoundtripError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "RoundtripError::{self:?}")
+    }
+}
+
+impl core::error::Error for RoundtripError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AnnounceError {
+    /// The key prefix does not form a valid zenoh key expression.
+    InvalidKeyExpression,
+    /// The capabilities could not be published on zenoh.
+    PublicationFailed,
+    Error,
+}
+
+impl core::fmt::Display for AnnounceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "AnnounceError::{self:?}")
+    }
+}
+
+impl core::error::Error for AnnounceError {}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum RunError {
+    /// A discovery failed while running with [`RunErrorPolicy::Abort`].
+    DiscoveryFailed(DiscoveryError),
+    /// The propagation over a connection failed while running with
+    /// [`RunErrorPolicy::Abort`].
+    PropagationFailed(PropagationError),
+    /// The lost zenoh session could not be reconnected according to the
+    /// [`TunnelConfig::reconnect_policy`] while running with [`RunErrorPolicy::Abort`].
+    ReconnectFailed,
+}
+
+impl core::fmt::Display for RunError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "RunError::{self:?}")
+    }
+}
+
+impl core::error::Error for RunError {}
+
+#[cfg(feature = "compression")]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CompressionError {
+    /// Some connections do not support the requested compression, e.g. since they do not
+    /// propagate local payloads to remote hosts. The other connections were updated
+    /// regardless.
+    UnsupportedConnections {
+        /// The number of connections the compression was applied to.
+        updated: usize,
+        /// The number of connections the compression could not be applied to.
+        unsupported: usize,
+    },
+}
+
+#[cfg(feature = "compression")]
+impl core::fmt::Display for CompressionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CompressionError::{self:?}")
+    }
+}
+
+#[cfg(feature = "compression")]
+impl core::error::Error for CompressionError {}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum WaitError {
+    /// No service with the provided name was discovered within the timeout.
+    Timeout,
+    /// A discovery failed while waiting.
+    DiscoveryFailed(DiscoveryError),
+}
+
+impl core::fmt::Display for WaitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "WaitError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitError {}
+
+/// Defines the operational scope for tunnel services.
+///
+/// This enum specifies which environment to use for tunnel operations:
+/// - `Iceoryx`: Only operate within the local Iceoryx environment
+/// - `Zenoh`: Only operate through the Zenoh network
+/// - `Both`: Operate in both Iceoryx and Zenoh environments
+/// - `None`: Do not operate in any environment, e.g. to skip a discovery
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scope {
+    Iceoryx,
+    Zenoh,
+    Both,
+    None,
+}
+
+impl Scope {
+    /// Returns `true` if the scope includes the local Iceoryx environment.
+    pub fn includes_iceoryx(&self) -> bool {
+        matches!(self, Scope::Iceoryx | Scope::Both)
+    }
+
+    /// Returns `true` if the scope includes the Zenoh network.
+    pub fn includes_zenoh(&self) -> bool {
+        matches!(self, Scope::Zenoh | Scope::Both)
+    }
+}
+
+impl core::fmt::Display for Scope {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scope::Iceoryx => write!(f, "iceoryx"),
+            Scope::Zenoh => write!(f, "zenoh"),
+            Scope::Both => write!(f, "both"),
+            Scope::None => write!(f, "none"),
+        }
+    }
+}
+
+/// The liveness of the iceoryx2 node of a tunnel, see [`Tunnel::check_iceoryx_node_health()`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum IceoryxNodeHealth {
+    /// The node is registered and its process is alive.
+    Alive,
+    /// The node is no longer registered or considered dead, e.g. because its resources were
+    /// cleaned up after a restart. Use [`Tunnel::reconnect_all()`] to continue under a new
+    /// node.
+    Stale,
+    /// The liveness of the node could not be determined, e.g. due to insufficient
+    /// permissions.
+    Unknown,
+}
+
+/// The failure of the connection of a tunneled service to propagate, see
+/// [`PropagationReport::failures()`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ServicePropagationError {
+    /// The connection of a publish-subscribe service failed to propagate.
+    PublishSubscribeFailed {
+        service_id: IceoryxServiceId,
+        source: PropagationError,
+    },
+    /// The connection of an event service failed to propagate.
+    EventFailed {
+        service_id: IceoryxServiceId,
+        source: PropagationError,
+    },
+    /// The connection of a request-response service failed to propagate.
+    RequestResponseFailed {
+        service_id: IceoryxServiceId,
+        source: PropagationError,
+    },
+}
+
+impl ServicePropagationError {
+    fn new(iox_service_config: &IceoryxServiceConfig, source: PropagationError) -> Self {
+        let service_id = iox_service_config.service_id().clone();
+        match iox_service_config.messaging_pattern() {
+            MessagingPattern::Event(_) => Self::EventFailed { service_id, source },
+            MessagingPattern::RequestResponse(_) => {
+                Self::RequestResponseFailed { service_id, source }
+            }
+            _ => Self::PublishSubscribeFailed { service_id, source },
+        }
+    }
+
+    /// Returns the id of the service whose connection failed.
+    pub fn service_id(&self) -> &IceoryxServiceId {
+        match self {
+            Self::PublishSubscribeFailed { service_id, .. }
+            | Self::EventFailed { service_id, .. }
+            | Self::RequestResponseFailed { service_id, .. } => service_id,
+        }
+    }
+
+    /// Returns the failure of the connection.
+    pub fn error(&self) -> PropagationError {
+        match self {
+            Self::PublishSubscribeFailed { source, .. }
+            | Self::EventFailed { source, .. }
+            | Self::RequestResponseFailed { source, .. } => *source,
+        }
+    }
+}
+
+impl core::fmt::Display for ServicePropagationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "ServicePropagationError::{self:?}")
+    }
+}
+
+impl core::error::Error for ServicePropagationError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::PublishSubscribeFailed { source, .. }
+            | Self::EventFailed { source, .. }
+            | Self::RequestResponseFailed { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Summarizes the outcome of propagating over all connections of a tunnel once.
+#[derive(Debug, Default)]
+pub struct PropagationReport {
+    propagated: usize,
+    failures: Vec<ServicePropagationError>,
+    skipped: usize,
+}
+
+impl PropagationReport {
+    /// Returns the number of payloads and notifications that were propagated.
+    pub fn propagated(&self) -> usize {
+        self.propagated
+    }
+
+    /// Returns the failures of the connections that failed to propagate, an empty slice if
+    /// all connections propagated.
+    pub fn failures(&self) -> &[ServicePropagationError] {
+        &self.failures
+    }
+
+    /// Returns the number of connections that were not propagated over because the deadline
+    /// of [`Tunnel::propagate_with_deadline()`] was reached.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Returns `true` if all connections propagated without failure.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// The details of a tunneled service, see [`Tunnel::tunneled_service_infos()`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TunneledServiceInfo {
+    /// The id of the service.
+    pub service_id: IceoryxServiceId,
+    /// The name of the service.
+    pub service_name: String,
+    /// The messaging pattern of the service.
+    pub pattern: MessagingPatternKind,
+    /// The scope in which the service was discovered when its connection was created.
+    pub discovery_scope: Scope,
+}
+
+/// The quality of service currently applied to the connection of a tunneled service, see
+/// [`Tunnel::service_effective_qos()`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EffectiveQoS {
+    /// The priority of the propagated payloads or events relative to other zenoh traffic.
+    pub priority: Priority,
+    /// Whether lost payloads or events are retransmitted.
+    pub reliability: Reliability,
+    /// The maximum rate at which local payloads are propagated in messages per second, if
+    /// limited with [`Tunnel::set_rate_limit()`].
+    pub rate_limit: Option<f64>,
+    /// Whether payloads or events are dropped or the propagation blocks when the
+    /// transmission queue is full.
+    pub congestion_control: CongestionControl,
+}
+
+/// The connections of a tunnel captured by [`Tunnel::snapshot()`], e.g. to restore them with
+/// [`Tunnel::restore_from_snapshot()`] after a planned restart instead of waiting for
+/// discovery.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TunnelSnapshot {
+    services: Vec<(IceoryxServiceConfig, Scope)>,
+}
+
+impl TunnelSnapshot {
+    /// Returns the static configs of the captured services along with the scope in which
+    /// they were discovered, ordered by service id.
+    pub fn services(&self) -> &[(IceoryxServiceConfig, Scope)] {
+        &self.services
+    }
+
+    /// Returns the number of captured services.
+    pub fn len(&self) -> usize {
+        self.services.len()
+    }
+
+    /// Returns `true` if no service was captured.
+    pub fn is_empty(&self) -> bool {
+        self.services.is_empty()
+    }
+}
+
+/// Summarizes the validation of all connections of a tunnel, see
+/// [`Tunnel::validate_all_connections()`].
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    healthy: Vec<IceoryxServiceId>,
+    degraded: Vec<(IceoryxServiceId, String)>,
+    failed: Vec<(IceoryxServiceId, String)>,
+}
+
+impl ValidationReport {
+    /// Returns the services whose connections are usable and propagate without failure.
+    pub fn healthy(&self) -> &[IceoryxServiceId] {
+        &self.healthy
+    }
+
+    /// Returns the services whose connections are usable but fail to propagate, along with
+    /// the reason.
+    pub fn degraded(&self) -> &[(IceoryxServiceId, String)] {
+        &self.degraded
+    }
+
+    /// Returns the services whose connections can no longer propagate, along with the reason.
+    pub fn failed(&self) -> &[(IceoryxServiceId, String)] {
+        &self.failed
+    }
+
+    /// Returns `true` if all connections are healthy.
+    pub fn is_healthy(&self) -> bool {
+        self.degraded.is_empty() && self.failed.is_empty()
+    }
+
+    fn record(&mut self, id: &IceoryxServiceId, validation: ConnectionValidation) {
+        match validation {
+            ConnectionValidation::Healthy => self.healthy.push(id.clone()),
+            ConnectionValidation::Degraded { reason } => self.degraded.push((id.clone(), reason)),
+            ConnectionValidation::Failed { reason } => self.failed.push((id.clone(), reason)),
+        }
+    }
+}
+
+/// A service whose connection was moved to another tunnel by [`Tunnel::rebalance()`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RebalancedService {
+    /// The id of the service.
+    pub service_id: IceoryxServiceId,
+    /// The index of the tunnel the connection was removed from.
+    pub from: usize,
+    /// The index of the tunnel the connection was created in.
+    pub to: usize,
+}
+
+/// Summarizes the connections moved between tunnels by [`Tunnel::rebalance()`].
+#[derive(Debug, Default)]
+pub struct RebalanceReport {
+    moved: Vec<RebalancedService>,
+}
+
+impl RebalanceReport {
+    /// Returns the services that were moved, in the order they were moved.
+    pub fn moved(&self) -> &[RebalancedService] {
+        &self.moved
+    }
+
+    /// Returns `true` if no service was moved, e.g. because the tunnels were balanced already.
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty()
+    }
+}
+
+/// Summarizes what was forwarded per service during a single call of
+/// [`Tunnel::propagate_once_and_report()`].
+#[derive(Debug, Default)]
+pub struct IterationReport {
+    forwarded: HashMap<IceoryxServiceId, (usize, usize)>,
+    propagation: PropagationReport,
+}
+
+impl IterationReport {
+    /// Returns the number of payloads and notifications forwarded per service during the call,
+    /// as a tuple of the numbers forwarded from iceoryx to zenoh and from zenoh to iceoryx.
+    pub fn forwarded(&self) -> &HashMap<IceoryxServiceId, (usize, usize)> {
+        &self.forwarded
+    }
+
+    /// Returns the numbers forwarded during the call for a service, as a tuple of the numbers
+    /// forwarded from iceoryx to zenoh and from zenoh to iceoryx.
+    pub fn forwarded_for(&self, id: &IceoryxServiceId) -> Option<(usize, usize)> {
+        self.forwarded.get(id).copied()
+    }
+
+    /// Returns the outcome of the propagation over all connections.
+    pub fn propagation(&self) -> &PropagationReport {
+        &self.propagation
+    }
+}
+
+/// Summarizes the payloads and notifications drained per service by
+/// [`Tunnel::pause_all_and_drain()`].
+#[derive(Debug, Default)]
+pub struct DrainReport {
+    drained: HashMap<IceoryxServiceId, usize>,
+}
+
+impl DrainReport {
+    /// Returns the number of payloads and notifications drained to zenoh per service.
+    pub fn drained(&self) -> &HashMap<IceoryxServiceId, usize> {
+        &self.drained
+    }
+
+    /// Returns the number of payloads and notifications drained to zenoh for a service.
+    pub fn drained_for(&self, id: &IceoryxServiceId) -> Option<usize> {
+        self.drained.get(id).copied()
+    }
+
+    /// Returns the number of payloads and notifications drained to zenoh for all services.
+    pub fn total(&self) -> usize {
+        self.drained.values().sum()
+    }
+}
+
+/// The outcome of [`Tunnel::test_message_roundtrip()`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct RoundtripResult {
+    iceoryx_to_zenoh_latency: Duration,
+    zenoh_to_iceoryx_latency: Duration,
+    payload_matches: bool,
+}
+
+impl RoundtripResult {
+    /// Returns the duration from publishing the payload on iceoryx until it was received on
+    /// zenoh.
+    pub fn iceoryx_to_zenoh_latency(&self) -> Duration {
+        self.iceoryx_to_zenoh_latency
+    }
+
+    /// Returns the duration from publishing the payload on zenoh until it was received on
+    /// iceoryx.
+    pub fn zenoh_to_iceoryx_latency(&self) -> Duration {
+        self.zenoh_to_iceoryx_latency
+    }
+
+    /// Returns `true` if the payload was received unaltered in both directions.
+    pub fn payload_matches(&self) -> bool {
+        self.payload_matches
+    }
+}
+
+/// Compares the services tunneled locally with the services announced on the zenoh network.
+#[derive(Debug, Default)]
+pub struct CrossCheckReport {
+    synchronized: Vec<String>,
+    missing_from_zenoh: Vec<String>,
+    unknown_locally: Vec<String>,
+}
+
+impl CrossCheckReport {
+    /// Returns the ids of the services that are tunneled locally and announced on zenoh.
+    pub fn synchronized(&self) -> &[String] {
+        &self.synchronized
+    }
+
+    /// Returns the ids of the services that are tunneled locally but not announced on zenoh.
+    pub fn missing_from_zenoh(&self) -> &[String] {
+        &self.missing_from_zenoh
+    }
+
+    /// Returns the ids of the services that are announced on zenoh but not tunneled locally.
+    pub fn unknown_locally(&self) -> &[String] {
+        &self.unknown_locally
+    }
+
+    /// Returns `true` if all locally tunneled services are announced on zenoh.
+    pub fn is_synchronized(&self) -> bool {
+        self.missing_from_zenoh.is_empty()
+    }
+}
+
+/// The optional features supported by a tunnel, announced to remote tunnels with
+/// [`Tunnel::announce_tunnel_capabilities()`] so that they can adapt their behavior.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct TunnelCapabilities {
+    /// The version of the propagated payload format, see [`TUNNEL_CAPABILITIES_VERSION`].
+    pub version: u32,
+    /// Whether the payloads are compressed, i.e. the tunnel was built with the
+    /// `compression` feature.
+    pub supports_compression: bool,
+    /// Whether the payloads are encrypted.
+    pub supports_encryption: bool,
+    /// Whether batched event notifications are understood.
+    pub supports_event_batching: bool,
+    /// Whether request-response services are tunneled.
+    pub supports_request_response: bool,
+}
+
+impl Default for TunnelCapabilities {
+    fn default() -> Self {
+        Self {
+            version: TUNNEL_CAPABILITIES_VERSION,
+            supports_compression: cfg!(feature = "compression"),
+            supports_encryption: false,
+            supports_event_batching: true,
+            supports_request_response: true,
+        }
+    }
+}
+
+impl TunnelCapabilities {
+    /// Serializes the capabilities into the JSON format announced on zenoh.
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "version": self.version,
+            "supports_compression": self.supports_compression,
+            "supports_encryption": self.supports_encryption,
+            "supports_event_batching": self.supports_event_batching,
+            "supports_request_response": self.supports_request_response,
+        })
+        .to_string()
+    }
+
+    /// Deserializes the capabilities announced by a remote tunnel.
+    ///
+    /// Capabilities missing in the announcement, e.g. since the remote tunnel is older, are
+    /// treated as unsupported.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The payload of the announcement
+    ///
+    /// # Returns
+    ///
+    /// * `Some(TunnelCapabilities)` - The announced capabilities
+    /// * `None` - If the payload is not a valid announcement
+    pub fn from_json(json: &[u8]) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_slice(json).ok()?;
+        let version = u32::try_from(value.get("version")?.as_u64()?).ok()?;
+        let supports = |key: &str| {
+            value
+                .get(key)
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false)
+        };
+
+        Some(Self {
+            version,
+            supports_compression: supports("supports_compression"),
+            supports_encryption: supports("supports_encryption"),
+            supports_event_batching: supports("supports_event_batching"),
+            supports_request_response: supports("supports_request_response"),
+        })
+    }
+}
+
+/// The changes between two snapshots of the tunneled services, see
+/// [`Tunnel::service_list_diff()`].
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct ServiceListDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl ServiceListDiff {
+    /// Returns the ids of the services that are only contained in the newer snapshot.
+    pub fn added(&self) -> &[String] {
+        &self.added
+    }
+
+    /// Returns the ids of the services that are only contained in the older snapshot.
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    /// Returns `true` if both snapshots contain the same services.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// The connection age ranges counted by an [`AgeHistogram`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AgeBucket {
+    /// Connections younger than one second.
+    Below1s,
+    /// Connections of at least one second and younger than ten seconds.
+    Below10s,
+    /// Connections of at least ten seconds and younger than one minute.
+    Below1m,
+    /// Connections of at least one minute and younger than ten minutes.
+    Below10m,
+    /// Connections of ten minutes or older.
+    From10m,
+}
+
+impl AgeBucket {
+    /// All buckets in ascending order of connection age.
+    pub const ALL: [AgeBucket; 5] = [
+        AgeBucket::Below1s,
+        AgeBucket::Below10s,
+        AgeBucket::Below1m,
+        AgeBucket::Below10m,
+        AgeBucket::From10m,
+    ];
+
+    /// Returns the bucket a connection of the provided age is counted in.
+    pub fn of(age: Duration) -> Self {
+        match age.as_secs() {
+            0 => AgeBucket::Below1s,
+            1..10 => AgeBucket::Below10s,
+            10..60 => AgeBucket::Below1m,
+            60..600 => AgeBucket::Below10m,
+            _ => AgeBucket::From10m,
+        }
+    }
+}
+
+/// Counts the tunneled connections per [`AgeBucket`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct AgeHistogram {
+    counts: [usize; AgeBucket::ALL.len()],
+}
+
+impl AgeHistogram {
+    /// Returns the number of connections counted in the provided bucket.
+    pub fn count(&self, bucket: AgeBucket) -> usize {
+        self.counts[bucket as usize]
+    }
+
+    /// Returns the number of connections counted in all buckets.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
+/// The approximate heap memory used by a tunnel, see [`Tunnel::estimate_memory_usage()`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct MemoryUsageEstimate {
+    /// The bytes allocated for the maps of the connections.
+    pub connection_map_bytes: usize,
+    /// The bytes of the remote payloads the connections buffer at most.
+    pub payload_buffer_bytes: usize,
+    /// The bytes allocated for the bookkeeping of the tunneled and failed services and for
+    /// the histories of the connections.
+    pub metadata_bytes: usize,
+    /// The sum of all bytes.
+    pub total_bytes: usize,
+}
+
+/// The traffic forwarded over the connection of a tunneled service in both directions, see
+/// [`Tunnel::statistics()`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct TunnelStatistics {
+    /// The number of payloads, events, requests and responses forwarded.
+    pub messages_forwarded: u64,
+    /// The number of payload bytes forwarded, events carry no payload and are not counted.
+    pub bytes_forwarded: u64,
+    /// The number of failed propagations.
+    pub propagation_errors: u64,
+    /// The number of remote payloads dropped since their checksum did not match, see
+    /// `TunnelConfig::checksum_verification` of the `checksum` feature.
+    pub corrupted_messages_count: u64,
+}
+
+impl TunnelStatistics {
+    fn of(statistics: &ConnectionStatistics) -> Self {
+        Self {
+            messages_forwarded: statistics.forwarded_iceoryx_to_zenoh()
+                + statistics.forwarded_zenoh_to_iceoryx(),
+            bytes_forwarded: statistics.bytes_iceoryx_to_zenoh()
+                + statistics.bytes_zenoh_to_iceoryx(),
+            propagation_errors: statistics.errors(),
+            corrupted_messages_count: statistics.corrupted(),
+        }
+    }
+}
+
+/// The connection of a tunneled publish-subscribe or event service, borrowed from its
+/// tunnel, see [`Tunnel::connection_for_service()`].
+pub struct ConnectionRef<'t, 'a, Service: iceoryx2::service::Service> {
+    connection: BorrowedConnection<'t, 'a, Service>,
+}
+
+enum BorrowedConnection<'t, 'a, Service: iceoryx2::service::Service> {
+    PublishSubscribe(&'t BidirectionalPublishSubscribeConnection<'a, Service>),
+    Event(&'t BidirectionalEventConnection<'a, Service>),
+}
+
+impl<Service: iceoryx2::service::Service> ConnectionRef<'_, '_, Service> {
+    /// Returns the messaging pattern of the service.
+    pub fn messaging_pattern(&self) -> MessagingPatternKind {
+        match self.connection {
+            BorrowedConnection::PublishSubscribe(_) => MessagingPatternKind::PublishSubscribe,
+            BorrowedConnection::Event(_) => MessagingPatternKind::Event,
+        }
+    }
+
+    /// Returns the liveness of the connection, see [`Tunnel::health_check()`].
+    pub fn health(&self) -> ConnectionHealth {
+        match self.connection {
+            BorrowedConnection::PublishSubscribe(connection) => connection.health(),
+            BorrowedConnection::Event(connection) => connection.health(),
+        }
+    }
+
+    /// Returns the traffic forwarded over the connection, see [`Tunnel::statistics()`].
+    pub fn statistics(&self) -> TunnelStatistics {
+        match self.connection {
+            BorrowedConnection::PublishSubscribe(connection) => {
+                TunnelStatistics::of(connection.statistics())
+            }
+            BorrowedConnection::Event(connection) => TunnelStatistics::of(connection.statistics()),
+        }
+    }
+}
+
+/// Controls the standby tunnel of a hot-standby pair, see [`Tunnel::create_ha_pair()`].
+///
+/// The standby tunnel discovers and connects services like the primary tunnel, but does not
+/// propagate until it is activated. Payloads and events that arrive in the meantime remain
+/// queued in its connections, as far as their buffers permit, and are propagated after the
+/// failover. The primary tunnel announces its liveness with a zenoh liveliness token, which
+/// is undeclared when the primary tunnel is dropped or its process terminates.
+pub struct TunnelHAController<Service: iceoryx2::service::Service> {
+    standby: Tunnel<'static, Service>,
+    z_primary_key: String,
+}
+
+impl<Service: iceoryx2::service::Service> TunnelHAController<Service> {
+    /// Returns the standby tunnel.
+    pub fn standby(&self) -> &Tunnel<'static, Service> {
+        &self.standby
+    }
+
+    /// Returns the standby tunnel, e.g. to discover services and to propagate once it was
+    /// activated.
+    pub fn standby_mut(&mut self) -> &mut Tunnel<'static, Service> {
+        &mut self.standby
+    }
+
+    /// Releases the standby tunnel, e.g. once it took over from the primary tunnel.
+    pub fn into_standby(self) -> Tunnel<'static, Service> {
+        self.standby
+    }
+
+    /// Returns `true` if the standby tunnel propagates, i.e. it was activated with
+    /// [`TunnelHAController::activate_standby()`].
+    pub fn is_standby_active(&self) -> bool {
+        self.standby.is_global_propagation_enabled()
+    }
+
+    /// Returns `true` if the liveliness token of the primary tunnel is declared. The primary
+    /// tunnel is considered alive when its liveliness cannot be queried, so that both
+    /// tunnels never propagate at the same time.
+    pub fn is_primary_alive(&self) -> bool {
+        let z_replies = match self
+            .standby
+            .z_session
+            .liveliness()
+            .get(&self.z_primary_key)
+            .timeout(HA_LIVELINESS_QUERY_TIMEOUT)
+            .wait()
+        {
+            Ok(z_replies) => z_replies,
+            Err(e) => {
+                warn!(
+                    "{}Failed to query the liveliness of the primary tunnel: {}",
+                    self.standby.log_context, e
+                );
+                return true;
+            }
+        };
+
+        while let Ok(z_reply) = z_replies.recv() {
+            if z_reply.result().is_ok() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Switches the propagation over to the standby tunnel once the primary tunnel failed,
+    /// i.e. its liveliness token was undeclared. The standby tunnel declares the liveliness
+    /// token of the primary tunnel afterwards.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the standby tunnel propagates from now on
+    /// * `Err(FailoverError::AlreadyActive)` - If the standby tunnel was activated already
+    /// * `Err(FailoverError::PrimaryAlive)` - If the primary tunnel is still alive
+    pub fn activate_standby(&mut self) -> Result<(), FailoverError> {
+        if self.is_standby_active() {
+            return Err(FailoverError::AlreadyActive);
+        }
+        if self.is_primary_alive() {
+            return Err(FailoverError::PrimaryAlive);
+        }
+
+        info!("{}FAILOVER to the standby tunnel", self.standby.log_context);
+        self.standby.set_global_propagation_enabled(true);
+        if let Err(e) = self.standby.declare_ha_token(self.z_primary_key.clone()) {
+            warn!(
+                "{}Failed to declare the liveliness token of the primary tunnel: {}",
+                self.standby.log_context, e
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// A tunnel that is being created on a background thread, see
+/// [`Tunnel::create_in_background()`].
+pub struct TunnelCreationFuture<Service: iceoryx2::service::Service> {
+    receiver: mpsc::Receiver<Result<Tunnel<'static, Service>, CreationError>>,
+}
+
+impl<Service: iceoryx2::service::Service> TunnelCreationFuture<Service> {
+    /// Blocks until the tunnel was created or the timeout elapsed.
+    ///
+    /// The creation continues in the background when the timeout elapsed, so that waiting
+    /// can be resumed with another call. Once the created tunnel was returned, further calls
+    /// fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum duration to wait for the creation to complete
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Tunnel)` - The created tunnel
+    /// * `Err(CreationError::Timeout)` - If the creation did not complete within the timeout
+    /// * `Err(CreationError)` - If the creation failed
+    pub fn wait(&self, timeout: Duration) -> Result<Tunnel<'static, Service>, CreationError> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(CreationError::Timeout),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(CreationError::CreationAborted),
+        }
+    }
+}
+
+/// A tunnel that discovers and propagates on a dedicated thread, see
+/// [`Tunnel::propagate_in_thread()`].
+///
+/// The thread is stopped when the handle is dropped.
+pub struct PropagationThreadHandle<Service: iceoryx2::service::Service> {
+    keep_running: Arc<IoxAtomicBool>,
+    tunneled_services: Arc<Mutex<Vec<String>>>,
+    thread: Option<std::thread::JoinHandle<Tunnel<'static, Service>>>,
+}
+
+impl<Service: iceoryx2::service::Service> PropagationThreadHandle<Service> {
+    /// Returns the ids of the services tunneled as of the most recent discovery of the
+    /// thread.
+    pub fn tunneled_services(&self) -> Vec<String> {
+        self.tunneled_services.lock().unwrap().clone()
+    }
+
+    /// Stops the thread after its current iteration and returns the tunnel.
+    pub fn stop(mut self) -> Tunnel<'static, Service> {
+        self.keep_running.store(false, Ordering::Relaxed);
+        let thread = self.thread.take().unwrap();
+
+        // The thread only terminates abnormally if the tunnel panicked
+        thread
+            .join()
+            .unwrap_or_else(|e| std::panic::resume_unwind(e))
+    }
+}
+
+impl<Service: iceoryx2::service::Service> Drop for PropagationThreadHandle<Service> {
+    fn drop(&mut self) {
+        self.keep_running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Stops [`Tunnel::run_until_cancelled()`] from another thread or task. Clones share the
+/// cancellation, so a clone can be handed to a signal handler while the tunnel runs.
+#[derive(Debug, Clone)]
+pub struct CancellationToken {
+    cancelled: Arc<IoxAtomicBool>,
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancellationToken {
+    /// Creates a token that is not cancelled.
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(IoxAtomicBool::new(false)),
+        }
+    }
+
+    /// Cancels the token, the tunnel stops running after its current tick.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Creates a [`Tunnel`] from the configurations provided step by step.
+///
+/// Only the iceoryx2 configuration is mandatory, the zenoh and tunnel configurations
+/// default to [`ZenohConfig::default()`] and [`TunnelConfig::default()`].
+#[derive(Default, Clone)]
+pub struct TunnelBuilder {
+    iox_config: Option<IceoryxConfig>,
+    z_config: Option<ZenohConfig>,
+    tunnel_config: Option<TunnelConfig>,
+    service_filter: Option<Vec<String>>,
+}
+
+impl TunnelBuilder {
+    /// Creates a new [`TunnelBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the iceoryx2 configuration used to discover and connect the local services.
+    pub fn iceoryx_config(mut self, value: &IceoryxConfig) -> Self {
+        self.iox_config = Some(value.clone());
+        self
+    }
+
+    /// Sets the zenoh configuration used to open the zenoh session of the tunnel.
+    pub fn zenoh_config(mut self, value: &ZenohConfig) -> Self {
+        self.z_config = Some(value.clone());
+        self
+    }
+
+    /// Sets the configuration of the tunnel itself.
+    pub fn tunnel_config(mut self, value: &TunnelConfig) -> Self {
+        self.tunnel_config = Some(value.clone());
+        self
+    }
+
+    /// Restricts the tunnel to the services with the provided names, takes precedence over
+    /// the [`TunnelConfig::service_name_allowlist`] of the tunnel configuration.
+    pub fn service_filter(mut self, names: Vec<String>) -> Self {
+        self.service_filter = Some(names);
+        self
+    }
+
+    /// Creates a new [`Tunnel`] for a specific [`iceoryx2::service::Service`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Tunnel)` - A new tunnel instance if creation was successful
+    /// * `Err(CreationError::MissingIceoryxConfig)` - If no iceoryx2 configuration was provided
+    /// * `Err(CreationError)` - If any other part of the tunnel creation failed
+    pub fn build<'a, Service: iceoryx2::service::Service>(
+        self,
+    ) -> Result<Tunnel<'a, Service>, CreationError> {
+        let iox_config = self.iox_config.ok_or(CreationError::MissingIceoryxConfig)?;
+        let z_config = self.z_config.unwrap_or_default();
+        let mut tunnel_config = self.tunnel_config.unwrap_or_default();
+        if let Some(names) = self.service_filter {
+            tunnel_config.service_name_allowlist = Some(names);
+        }
+
+        Tunnel::create(&tunnel_config, &iox_config, &z_config)
+    }
+}
+
+/// Keeps the callback installed with [`Tunnel::watch_service()`], the callback is removed
+/// when the handle is dropped.
+pub struct WatchHandle {
+    taps: Weak<PayloadTaps>,
+    id: u64,
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        // The connection may have been removed from the tunnel in the meantime.
+        if let Some(taps) = self.taps.upgrade() {
+            taps.remove(self.id);
+        }
+    }
+}
+
+/// Keeps the capabilities announced with [`Tunnel::announce_tunnel_capabilities()`]
+/// queryable, the announcement is withdrawn when the handle is dropped.
+pub struct CapabilityHandle {
+    _z_queryable: ZenohQueryable<()>,
+    key: String,
+    capabilities: TunnelCapabilities,
+}
+
+impl CapabilityHandle {
+    /// Returns the zenoh key expression the capabilities are announced on.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the announced capabilities.
+    pub fn capabilities(&self) -> TunnelCapabilities {
+        self.capabilities
+    }
+}
+
+/// A tunnel for propagating iceoryx2 payloads across hosts via the Zenoh network middleware.
+///
+/// # Thread Safety
+///
+/// The tunnel is [`Send`] and [`Sync`] if the ports of the iceoryx2 service type are, i.e.
+/// for thread-safe service types like [`iceoryx2::service::ipc_threadsafe::Service`], while
+/// tunnels of other service types must stay on the thread that created them.
+///
+/// Methods taking `&mut self`, e.g. [`Tunnel::discover()`] or [`Tunnel::reconnect()`], change
+/// the set of connections and require exclusive access, e.g. via `Arc<Mutex<Tunnel>>`.
+/// Methods taking `&self` may be called from multiple threads concurrently. This includes
+/// [`Tunnel::propagate()`], although concurrent propagations over the same connection
+/// interleave their payloads, as well as [`Tunnel::set_global_propagation_enabled()`] to stop
+/// a propagating thread from another thread.
+pub struct Tunnel<'a, ServiceType: iceoryx2::service::Service> {
+    z_config: ZenohConfig,
+    z_session_options: ZenohSessionOptions,
+    z_session: ZenohSession,
+    z_session_id: OnceLock<String>,
+    z_discovery: ZenohDiscovery<'a, ServiceType>,
+    // Labeled with the zenoh id of the session
+    z_additional_sessions: Vec<(String, ZenohSession)>,
+    z_additional_discoveries: Vec<ZenohDiscovery<'a, ServiceType>>,
+    iox_node: IceoryxNode<ServiceType>,
+    iox_discovery: IceoryxDiscovery<ServiceType>,
+    // Only `Send`, the mutex keeps the tunnel `Sync`
+    custom_discovery: Option<Mutex<CustomDiscovery<'a>>>,
+    publish_subscribe_connectons:
+        HashMap<IceoryxServiceId, BidirectionalPublishSubscribeConnection<'a, ServiceType>>,
+    event_connections: HashMap<IceoryxServiceId, BidirectionalEventConnection<'a, ServiceType>>,
+    request_response_connections:
+        HashMap<IceoryxServiceId, BidirectionalRequestResponseConnection<'a, ServiceType>>,
+    failed_services: HashMap<IceoryxServiceId, (Scope, IceoryxServiceConfig)>,
+    service_sources: HashMap<IceoryxServiceId, Scope>,
+    service_ids_by_name: HashMap<String, IceoryxServiceId>,
+    connection_metadata: HashMap<IceoryxServiceId, HashMap<String, String>>,
+    iceoryx_to_zenoh_disabled: bool,
+    zenoh_to_iceoryx_disabled: bool,
+    propagation_policy: PropagationPolicy,
+    min_discovery_interval: Option<Duration>,
+    last_discovery: Option<Instant>,
+    connection_defaults: ConnectionDefaults,
+    service_filter: ServiceFilter,
+    connection_limit: ConnectionLimit,
+    use_color: bool,
+    close_timeout: Option<Duration>,
+    reconnect_policy: ReconnectPolicy,
+    log_context: LogContext,
+    key_expr_mapper: Option<Arc<KeyExprMapper>>,
+    schema_registry: Option<Arc<dyn SchemaRegistry + Send + Sync>>,
+    reject_unknown_services: bool,
+    total_services_discovered: u64,
+    discovered_in_run: Option<HashSet<IceoryxServiceId>>,
+    scope_aliasing: bool,
+    connection_direction: ConnectionDirection,
+    qos_mapping: QosMapping,
+    z_allowed_destination: Locality,
+    iox_service_limits: IceoryxServiceLimits,
+    run_error_policy: RunErrorPolicy,
+    wait_backoff: WaitBackoff,
+    propagation_enabled: IoxAtomicBool,
+    connection_callbacks: ConnectionCallbacks,
+    quality_score_config: QualityScoreConfig,
+    // The key and the token announcing the liveness of the primary tunnel of a hot-standby pair
+    z_ha_token: Option<(String, LivelinessToken)>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> Drop for Tunnel<'_, ServiceType> {
+    fn drop(&mut self) {
+        let Some(close_timeout) = self.close_timeout else {
+            return;
+        };
+
+        // The connections undeclare their zenoh entities, which requires an open session.
+        self.publish_subscribe_connectons.clear();
+        self.event_connections.clear();
+        self.request_response_connections.clear();
+        self.z_ha_token = None;
+
+        if let Err(e) = self.z_session.close().timeout(close_timeout).wait() {
+            warn!(
+                "{}Failed to close zenoh session within {:?}: {}",
+                self.log_context, close_timeout, e
+            );
+        }
+        for (label, z_session) in &self.z_additional_sessions {
+            if let Err(e) = z_session.close().timeout(close_timeout).wait() {
+                warn!(
+                    "{}Failed to close zenoh session {} within {:?}: {}",
+                    self.log_context, label, close_timeout, e
+                );
+            }
+        }
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> core::fmt::Debug for Tunnel<'_, ServiceType> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The configured endpoints instead of the session, which exposes internal handles
+        let z_endpoints = self
+            .z_config
+            .get_json("connect/endpoints")
+            .unwrap_or_default();
+        f.debug_struct("Tunnel")
+            .field(
+                "publish_subscribe_connections",
+                &self.publish_subscribe_connectons.len(),
+            )
+            .field("event_connections", &self.event_connections.len())
+            .field(
+                "request_response_connections",
+                &self.request_response_connections.len(),
+            )
+            .field("zenoh_session_id", &self.zenoh_session_id())
+            .field("zenoh_endpoints", &z_endpoints)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Determines which discovered services are tunneled.
+#[derive(Debug, Default)]
+struct ServiceFilter {
+    allowed_names: HashSet<String>,
+    blocklist: ServiceBlocklist,
+    handed_over: HashSet<IceoryxServiceId>,
+    // Only services of this messaging pattern are tunneled when set
+    messaging_pattern: Option<MessagingPatternKind>,
+}
+
+impl ServiceFilter {
+    /// Returns `true` if the service shall be tunneled.
+    fn allows(&self, iox_service_config: &IceoryxServiceConfig) -> bool {
+        self.permits(iox_service_config)
+            && !self.handed_over.contains(iox_service_config.service_id())
+    }
+
+    /// Returns `true` if the service is neither blocked nor missing from the allowed names
+    /// and has the permitted messaging pattern, regardless of whether the service was handed
+    /// over to another tunnel.
+    fn permits(&self, iox_service_config: &IceoryxServiceConfig) -> bool {
+        !self.blocks(iox_service_config)
+            && self.permits_messaging_pattern(iox_service_config)
+            && (self.allowed_names.is_empty()
+                || self
+                    .allowed_names
+                    .contains(iox_service_config.name().as_str()))
+    }
+
+    /// Returns `true` if the tunnel is not limited to a messaging pattern or the service has
+    /// the messaging pattern the tunnel is limited to.
+    fn permits_messaging_pattern(&self, iox_service_config: &IceoryxServiceConfig) -> bool {
+        let Some(permitted) = self.messaging_pattern else {
+            return true;
+        };
+
+        let pattern = match iox_service_config.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(_) => MessagingPatternKind::PublishSubscribe,
+            MessagingPattern::Event(_) => MessagingPatternKind::Event,
+            MessagingPattern::RequestResponse(_) => MessagingPatternKind::RequestResponse,
+            _ => return false,
+        };
+        pattern == permitted
+    }
+
+    /// Returns `true` if the id of the service or, failing that, its name is blocked.
+    fn blocks(&self, iox_service_config: &IceoryxServiceConfig) -> bool {
+        if self
+            .blocklist
+            .service_ids
+            .contains(iox_service_config.service_id())
+        {
+            return true;
+        }
+
+        let name = iox_service_config.name().as_str();
+        self.blocklist
+            .name_patterns
+            .iter()
+            .any(|pattern| matches_name_pattern(pattern, name))
+    }
+}
+
+/// Returns `true` if the name matches the pattern, in which `*` matches any sequence of
+/// characters and `?` matches a single character.
+fn matches_name_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` in the pattern and the position in the name it was
+    // matched up to, to backtrack to when the remainder does not match
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// The key-value pairs prepended to the log messages of a tunnel.
+#[derive(Debug, Default, Clone)]
+struct LogContext {
+    prefix: String,
+}
+
+impl LogContext {
+    /// Renders the key-value pairs ordered by key, so that the prefix is stable.
+    fn new(ctx: &HashMap<&'static str, String>) -> Self {
+        let mut pairs: Vec<(&&'static str, &String)> = ctx.iter().collect();
+        pairs.sort_by_key(|(key, _)| **key);
+
+        Self {
+            prefix: pairs
+                .iter()
+                .map(|(key, value)| format!("{key}={value} "))
+                .collect(),
+        }
+    }
+
+    /// Returns the key-value pairs without the trailing separator, to be recorded as a
+    /// tracing span field.
+    #[cfg(feature = "tracing")]
+    fn as_fields(&self) -> &str {
+        self.prefix.trim_end()
+    }
+}
+
+impl core::fmt::Display for LogContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.prefix)
+    }
+}
+
+/// The user-defined callbacks notified about the creation and removal of connections, along
+/// with the number of removed connections.
+#[derive(Default)]
+struct ConnectionCallbacks {
+    on_created: Option<Arc<ConnectionCreatedCallback>>,
+    on_removed: Option<Arc<ConnectionRemovedCallback>>,
+    removals: IoxAtomicU64,
+}
+
+impl ConnectionCallbacks {
+    /// Notifies that the connection of a service was created.
+    fn created(
+        &self,
+        iox_service_config: &IceoryxServiceConfig,
+        pattern: MessagingPatternKind,
+        scope: Scope,
+    ) {
+        if let Some(on_created) = &self.on_created {
+            on_created(&TunneledServiceInfo {
+                service_id: iox_service_config.service_id().clone(),
+                service_name: iox_service_config.name().to_string(),
+                pattern,
+                discovery_scope: scope,
+            });
+        }
+    }
+
+    /// Notifies that the connection of a service was removed.
+    fn removed(&self, id: &IceoryxServiceId) {
+        self.removals.fetch_add(1, Ordering::Relaxed);
+        if let Some(on_removed) = &self.on_removed {
+            on_removed(id);
+        }
+    }
+}
+
+impl core::fmt::Debug for ConnectionCallbacks {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionCallbacks")
+            .field("on_created", &self.on_created.is_some())
+            .field("on_removed", &self.on_removed.is_some())
+            .field("removals", &self.removals)
+            .finish()
+    }
+}
+
+/// Where a service was discovered, along with the context to log the discovery in and the
+/// callbacks to notify about the resulting connections.
+#[derive(Debug, Clone, Copy)]
+struct DiscoverySource<'c> {
+    scope: Scope,
+    log_context: &'c LogContext,
+    callbacks: &'c ConnectionCallbacks,
+}
+
+/// The entities used to create the connection of a discovered service.
+struct ConnectionContext<'c, ServiceType: iceoryx2::service::Service> {
+    iox_node: &'c IceoryxNode<ServiceType>,
+    z_session: &'c ZenohSession,
+    key_expr_mapper: Option<&'c KeyExprMapper>,
+    schema_registry: Option<&'c (dyn SchemaRegistry + Send + Sync)>,
+    reject_unknown_services: bool,
+    connection_direction: ConnectionDirection,
+    qos_mapping: QosMapping,
+    backpressure_policy: Option<BackpressurePolicy>,
+    z_allowed_destination: Locality,
+    iox_service_limits: IceoryxServiceLimits,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ConnectionContext<'_, ServiceType> {
+    /// Returns the settings of the zenoh publisher propagating the local payloads of the
+    /// provided publish-subscribe service. The congestion control derived from the quality
+    /// of service is overridden by a backpressure policy with a zenoh equivalent.
+    fn z_publisher_config(
+        &self,
+        iox_service_config: &IceoryxServiceConfig,
+    ) -> ZenohPublisherConfig {
+        let mut options = self.qos_mapping.publisher_options(iox_service_config);
+        if let Some(congestion_control) = self
+            .backpressure_policy
+            .and_then(|policy| policy.z_congestion_control())
+        {
+            options.congestion_control = congestion_control;
+        }
+
+        ZenohPublisherConfig {
+            allowed_destination: self.z_allowed_destination,
+            options,
+        }
+    }
+
+    /// Returns the zenoh key expression for the connection of the provided service.
+    fn z_key_expression(&self, iox_service_config: &IceoryxServiceConfig) -> String {
+        z_key_expression(iox_service_config, self.key_expr_mapper)
+    }
+
+    /// Checks that the zenoh key expression for the connection of the provided service is
+    /// valid.
+    fn validate_z_key_expression(
+        &self,
+        iox_service_config: &IceoryxServiceConfig,
+    ) -> Result<(), CreationError> {
+        validate_z_key_expression(iox_service_config, self.key_expr_mapper)
+    }
+
+    /// Checks that the message types of the provided service match the schema registry.
+    fn validate_schema(
+        &self,
+        iox_service_config: &IceoryxServiceConfig,
+    ) -> Result<(), SchemaViolation> {
+        validate_schema(
+            iox_service_config,
+            self.schema_registry,
+            self.reject_unknown_services,
+        )
+    }
+}
+
+/// Limits the number of services tunneled at the same time.
+#[derive(Debug, Default, Clone)]
+struct ConnectionLimit {
+    max_connections: Option<usize>,
+    policy: MaxConnectionsPolicy,
+    // The services skipped due to the limit, oldest first
+    pending: VecDeque<(Scope, IceoryxServiceConfig)>,
+}
+
+/// The settings applied to every newly created connection of a tunnel.
+#[derive(Debug, Default, Clone, Copy)]
+struct ConnectionDefaults {
+    #[cfg(feature = "compression")]
+    compression_codec: CompressionCodec,
+    #[cfg(feature = "compression")]
+    compression_threshold: Option<usize>,
+    iceoryx_receive_timeout: Option<Duration>,
+    backpressure_policy: Option<BackpressurePolicy>,
+    keep_alive_interval: Option<Duration>,
+    replay_history_on_connect: bool,
+    #[cfg(feature = "checksum")]
+    checksum_verification: Option<ChecksumAlgorithm>,
+    track_message_ids: bool,
+    event_batching: Option<EventBatching>,
+}
+
+impl<Service: iceoryx2::service::Service> Tunnel<'static, Service> {
+    /// Creates a new tunnel with the provided configuration on a background thread and
+    /// returns immediately, e.g. to not delay the startup of latency-sensitive applications
+    /// while connecting to zenoh.
+    ///
+    /// Only available for service types whose tunnel can be moved across threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `tunnel_config` - Tunnel configuration
+    /// * `iox_config` - Iceoryx configuration to be used
+    /// * `z_config` - Zenoh configuration to be used
+    ///
+    /// # Returns
+    ///
+    /// * `TunnelCreationFuture` - The handle to wait for the created tunnel
+    pub fn create_in_background(
+        tunnel_config: &TunnelConfig,
+        iox_config: &IceoryxConfig,
+        z_config: &ZenohConfig,
+    ) -> TunnelCreationFuture<Service>
+    where
+        Self: Send,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let tunnel_config = tunnel_config.clone();
+        let iox_config = iox_config.clone();
+        let z_config = z_config.clone();
+
+        std::thread::spawn(move || {
+            // The receiver is gone if the caller is no longer interested in the tunnel.
+            let _ = sender.send(Self::create(&tunnel_config, &iox_config, &z_config));
+        });
+
+        TunnelCreationFuture { receiver }
+    }
+
+    /// Moves the tunnel to a dedicated thread that repeatedly discovers services in the
+    /// provided scope and propagates over all connections, until the returned handle is
+    /// stopped. A lost zenoh session is reconnected according to the configured
+    /// [`TunnelConfig::reconnect_policy`].
+    ///
+    /// Only available for service types whose tunnel can be moved across threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the scope of the discoveries
+    /// * `tick` - The duration to wait between two iterations
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationThreadHandle` - The handle to observe the thread and to stop it to
+    ///   reclaim the tunnel
+    pub fn propagate_in_thread(
+        mut self,
+        scope: Scope,
+        tick: Duration,
+    ) -> PropagationThreadHandle<Service>
+    where
+        Self: Send,
+    {
+        let keep_running = Arc::new(IoxAtomicBool::new(true));
+        let tunneled_services = Arc::new(Mutex::new(self.tunneled_service_ids()));
+
+        let thread = {
+            let keep_running = keep_running.clone();
+            let tunneled_services = tunneled_services.clone();
+            std::thread::spawn(move || {
+                while keep_running.load(Ordering::Relaxed) {
+                    if let Err(e) = self.discover(scope) {
+                        warn!("{}Failed to discover services: {}", self.log_context, e);
+                    }
+                    if let Err(e) = self.propagate_with_reconnect() {
+                        warn!("{}Failed to reconnect to zenoh: {}", self.log_context, e);
+                    }
+                    *tunneled_services.lock().unwrap() = self.tunneled_service_ids();
+
+                    std::thread::sleep(tick);
+                }
+
+                self
+            })
+        };
+
+        PropagationThreadHandle {
+            keep_running,
+            tunneled_services,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl<'a, Service: iceoryx2::service::Service> Tunnel<'a, Service> {
+    /// The estimated bytes occupied by the connection of a publish-subscribe service, e.g. for
+    /// capacity planning.
+    ///
+    /// The estimate is the size of the entry in the connection map plus the heap allocated
+    /// for the propagation history at full capacity. The remote payloads buffered by the
+    /// connection depend on the payload size of the service and are not included, see
+    /// [`MemoryUsageEstimate::payload_buffer_bytes`].
+    pub const ESTIMATED_PUBLISH_SUBSCRIBE_CONNECTION_BYTES: usize = core::mem::size_of::<(
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'a, Service>,
+    )>()
+        + crate::PROPAGATION_HISTORY_BYTES;
+
+    /// The estimated bytes occupied by the connection of an event service, e.g. for capacity
+    /// planning.
+    ///
+    /// The estimate is the size of the entry in the connection map plus the heap allocated
+    /// for the propagation and event histories at full capacity.
+    pub const ESTIMATED_EVENT_CONNECTION_BYTES: usize =
+        core::mem::size_of::<(IceoryxServiceId, BidirectionalEventConnection<'a, Service>)>()
+            + crate::PROPAGATION_HISTORY_BYTES
+            + crate::EVENT_HISTORY_BYTES;
+
+    /// Compares two snapshots of the tunneled services, e.g. the service ids of two calls to
+    /// [`Tunnel::tunneled_service_infos()`], to determine how the tunneled services changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The ids of the services in the older snapshot
+    /// * `new` - The ids of the services in the newer snapshot
+    ///
+    /// # Returns
+    ///
+    /// * `ServiceListDiff` - The services added and removed in between, in the order of the
+    ///   snapshots
+    pub fn service_list_diff(old: &[String], new: &[String]) -> ServiceListDiff {
+        let old_services: HashSet<&String> = old.iter().collect();
+        let new_services: HashSet<&String> = new.iter().collect();
+
+        ServiceListDiff {
+            added: new
+                .iter()
+                .filter(|id| !old_services.contains(id))
+                .cloned()
+                .collect(),
+            removed: old
+                .iter()
+                .filter(|id| !new_services.contains(id))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Combines a tunnel configuration with the settings of an overlay, e.g. a config file
+    /// with the settings of CLI flags.
+    ///
+    /// The settings set in `overlay` take precedence over the ones of `base`, including the
+    /// settings that are set to their default value or to `false`. Settings that are not set
+    /// in `overlay` keep the value of `base`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The configuration providing the fallback settings
+    /// * `overlay` - The settings taking precedence
+    ///
+    /// # Returns
+    ///
+    /// * `TunnelConfig` - The combined configuration
+    pub fn merge_configs(base: TunnelConfig, overlay: TunnelConfigOverlay) -> TunnelConfig {
+        TunnelConfig {
+            discovery_service: overlay.discovery_service.or(base.discovery_service),
+            propagation_policy: overlay
+                .propagation_policy
+                .unwrap_or(base.propagation_policy),
+            min_discovery_interval: overlay
+                .min_discovery_interval
+                .or(base.min_discovery_interval),
+            discovery_key_expression: overlay
+                .discovery_key_expression
+                .or(base.discovery_key_expression),
+            discovery_mode: overlay.discovery_mode.unwrap_or(base.discovery_mode),
+            disable_iox_monitoring: overlay
+                .disable_iox_monitoring
+                .unwrap_or(base.disable_iox_monitoring),
+            #[cfg(feature = "compression")]
+            compression: overlay.compression.unwrap_or(base.compression),
+            #[cfg(feature = "compression")]
+            compression_codec: overlay.compression_codec.unwrap_or(base.compression_codec),
+            iceoryx_receive_timeout: overlay
+                .iceoryx_receive_timeout
+                .or(base.iceoryx_receive_timeout),
+            backpressure_policy: overlay.backpressure_policy.or(base.backpressure_policy),
+            keep_alive_interval: overlay.keep_alive_interval.or(base.keep_alive_interval),
+            replay_history_on_connect: overlay
+                .replay_history_on_connect
+                .unwrap_or(base.replay_history_on_connect),
+            #[cfg(feature = "checksum")]
+            checksum_verification: overlay.checksum_verification.or(base.checksum_verification),
+            track_message_ids: overlay.track_message_ids.unwrap_or(base.track_message_ids),
+            max_connections: overlay.max_connections.or(base.max_connections),
+            max_connections_policy: overlay
+                .max_connections_policy
+                .unwrap_or(base.max_connections_policy),
+            use_color: overlay.use_color.or(base.use_color),
+            zenoh_session_options: base
+                .zenoh_session_options
+                .merge(overlay.zenoh_session_options),
+            close_timeout: overlay.close_timeout.or(base.close_timeout),
+            service_name_allowlist: overlay
+                .service_name_allowlist
+                .or(base.service_name_allowlist),
+            service_blocklist: overlay.service_blocklist.or(base.service_blocklist),
+            reconnect_policy: overlay.reconnect_policy.unwrap_or(base.reconnect_policy),
+            key_expr_mapper: overlay.key_expr_mapper.or(base.key_expr_mapper),
+            scope_aliasing: overlay.scope_aliasing.unwrap_or(base.scope_aliasing),
+            connection_direction: overlay
+                .connection_direction
+                .unwrap_or(base.connection_direction),
+            qos_mapping: overlay.qos_mapping.unwrap_or(base.qos_mapping),
+            event_batching: overlay.event_batching.or(base.event_batching),
+            on_connection_created: overlay.on_connection_created.or(base.on_connection_created),
+            on_connection_removed: overlay.on_connection_removed.or(base.on_connection_removed),
+            zenoh_allowed_destination: overlay
+                .zenoh_allowed_destination
+                .or(base.zenoh_allowed_destination),
+            run_error_policy: overlay.run_error_policy.unwrap_or(base.run_error_policy),
+            wait_backoff: overlay.wait_backoff.unwrap_or(base.wait_backoff),
+            discovery_scouting_endpoint: overlay
+                .discovery_scouting_endpoint
+                .or(base.discovery_scouting_endpoint),
+            zenoh_sessions: overlay.zenoh_sessions.unwrap_or(base.zenoh_sessions),
+            iceoryx_max_publishers: overlay
+                .iceoryx_max_publishers
+                .or(base.iceoryx_max_publishers),
+            iceoryx_max_subscribers: overlay
+                .iceoryx_max_subscribers
+                .or(base.iceoryx_max_subscribers),
+            reject_unknown_services: overlay
+                .reject_unknown_services
+                .unwrap_or(base.reject_unknown_services),
+            quality_score_config: overlay.quality_score_config.or(base.quality_score_config),
+        }
+    }
+
+    /// Creates a new tunnel with the provided configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `tunnel_config` - Tunnel configuration
+    /// * `iox_config` - Iceoryx configuration to be used
+    /// * `z_config` - Zenoh configuration to be used
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - A new tunnel instance if creation was successful
+    /// * `Err(CreationError)` - If any part of the tunnel creation failed
+    pub fn create(
+        tunnel_config: &TunnelConfig,
+        iox_config: &IceoryxConfig,
+        z_config: &ZenohConfig,
+    ) -> Result<Self, CreationError> {
+        Self::create_with_discovery(tunnel_config, iox_config, z_config, None)
+    }
+
+    /// Creates a hot-standby pair of tunnels, e.g. for high-availability deployments in which
+    /// a second tunnel takes over once the first one failed.
+    ///
+    /// The primary tunnel propagates right away and announces its liveness with a zenoh
+    /// liveliness token. The standby tunnel is halted with
+    /// [`Tunnel::set_global_propagation_enabled()`] until it is activated with
+    /// [`TunnelHAController::activate_standby()`] after the primary tunnel failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary_config` - Iceoryx configuration of the primary tunnel
+    /// * `standby_config` - Iceoryx configuration of the standby tunnel
+    /// * `tunnel_config` - Tunnel configuration of both tunnels
+    /// * `shared_discovery_config` - Zenoh configuration of both tunnels, both must reach the
+    ///   same zenoh network to coordinate the failover
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Self, TunnelHAController))` - The primary tunnel and the controller of the
+    ///   standby tunnel
+    /// * `Err(CreationError)` - If one of the tunnels could not be created or the liveliness
+    ///   token of the primary tunnel could not be declared
+    pub fn create_ha_pair(
+        primary_config: &IceoryxConfig,
+        standby_config: &IceoryxConfig,
+        tunnel_config: &TunnelConfig,
+        shared_discovery_config: &ZenohConfig,
+    ) -> Result<(Self, TunnelHAController<Service>), CreationError> {
+        let mut primary = Self::create(tunnel_config, primary_config, shared_discovery_config)?;
+        let standby = Self::create(tunnel_config, standby_config, shared_discovery_config)?;
+        standby.set_global_propagation_enabled(false);
+
+        // The pair is identified by the session of the primary tunnel at creation, its key
+        // remains the same when the primary tunnel reconnects
+        let z_primary_key = keys::ha_primary(&primary.zenoh_session_id());
+        if let Err(e) = primary.declare_ha_token(z_primary_key.clone()) {
+            error!(
+                "{}Failed to declare the liveliness token of the primary tunnel: {}",
+                primary.log_context, e
+            );
+            return Err(CreationError::HaTokenDeclarationFailed {
+                reason: e.to_string(),
+            });
+        }
+        info!(
+            "{}CREATED hot-standby pair {}",
+            primary.log_context, z_primary_key
+        );
+
+        Ok((
+            primary,
+            TunnelHAController {
+                standby,
+                z_primary_key,
+            },
+        ))
+    }
+
+    /// Creates a new tunnel with the configurations stored in a single TOML file, see
+    /// [`UnifiedTunnelConfig`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the TOML file
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - A new tunnel instance if creation was successful
+    /// * `Err(CreationError::ConfigLoadFailed)` - If the configuration could not be loaded
+    /// * `Err(CreationError)` - If any other part of the tunnel creation failed
+    #[cfg(feature = "toml-config")]
+    pub fn create_from_unified(path: &Path) -> Result<Self, CreationError> {
+        let config =
+            UnifiedTunnelConfig::from_toml_file(path).map_err(CreationError::ConfigLoadFailed)?;
+
+        Self::create(&config.tunnel, &config.iceoryx, &config.zenoh)
+    }
+
+    /// Creates a new tunnel that only tunnels the services of a single messaging pattern, e.g.
+    /// for deployments that only bridge events.
+    ///
+    /// Services of other messaging patterns are ignored when they are discovered, no
+    /// connections are created for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `tunnel_config` - Tunnel configuration
+    /// * `iox_config` - Iceoryx configuration to be used
+    /// * `z_config` - Zenoh configuration to be used
+    /// * `pattern` - The messaging pattern of the services to be tunneled
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - A new tunnel instance if creation was successful
+    /// * `Err(CreationError)` - If any part of the tunnel creation failed
+    pub fn create_limited_to_pattern(
+        tunnel_config: &TunnelConfig,
+        iox_config: &IceoryxConfig,
+        z_config: &ZenohConfig,
+        pattern: MessagingPatternKind,
+    ) -> Result<Self, CreationError> {
+        let mut tunnel = Self::create(tunnel_config, iox_config, z_config)?;
+        tunnel.service_filter.messaging_pattern = Some(pattern);
+
+        Ok(tunnel)
+    }
+
+    /// Creates a new short-lived tunnel, e.g. for tests or one-off transfers, whose resources
+    /// are released quickly when it is dropped, including on panic.
+    ///
+    /// Unless a [`TunnelConfig::close_timeout`] is configured, the zenoh session is closed
+    /// with a shorter timeout than the default of zenoh.
+    ///
+    /// # Arguments
+    ///
+    /// * `tunnel_config` - Tunnel configuration
+    /// * `iox_config` - Iceoryx configuration to be used
+    /// * `z_config` - Zenoh configuration to be used
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - A new tunnel instance if creation was successful
+    /// * `Err(CreationError)` - If any part of the tunnel creation failed
+    pub fn create_transient(
+        tunnel_config: &TunnelConfig,
+        iox_config: &IceoryxConfig,
+        z_config: &ZenohConfig,
+    ) -> Result<Self, CreationError> {
+        let tunnel_config = TunnelConfig {
+            close_timeout: tunnel_config
+                .close_timeout
+                .or(Some(TRANSIENT_CLOSE_TIMEOUT)),
+            ..tunnel_config.clone()
+        };
+
+        Self::create(&tunnel_config, iox_config, z_config)
+    }
+
+    /// Creates a new tunnel that discovers the local services via a user-defined discovery
+    /// source instead of iceoryx2, e.g. a service registry of the deployment.
+    ///
+    /// Discoveries in the [`Scope::Iceoryx`] query the provided source, the discovery of
+    /// remote services via zenoh is unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `tunnel_config` - Tunnel configuration
+    /// * `iox_config` - Iceoryx configuration to be used
+    /// * `z_config` - Zenoh configuration to be used
+    /// * `discovery` - The source of the local services to be tunneled
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - A new tunnel instance if creation was successful
+    /// * `Err(CreationError)` - If any part of the tunnel creation failed
+    pub fn create_with_custom_discovery<D: Discovery<Service> + Send + 'a>(
+        tunnel_config: &TunnelConfig,
+        iox_config: &IceoryxConfig,
+        z_config: &ZenohConfig,
+        mut discovery: D,
+    ) -> Result<Self, CreationError> {
+        let custom_discovery: CustomDiscovery<'a> = Box::new(
+            move |mut on_discovered: &mut dyn FnMut(&IceoryxServiceConfig)| {
+                discovery.discover(&mut on_discovered)
+            },
+        );
+
+        Self::create_with_discovery(tunnel_config, iox_config, z_config, Some(custom_discovery))
+    }
+
+    fn create_with_discovery(
+        tunnel_config: &TunnelConfig,
+        iox_config: &IceoryxConfig,
+        z_config: &ZenohConfig,
+        custom_discovery: Option<CustomDiscovery<'a>>,
+    ) -> Result<Self, CreationError> {
+        info!("STARTING Zenoh Tunnel");
+
+        let mut z_config = z_config.clone();
+        tunnel_config.zenoh_session_options.apply(&mut z_config)?;
+
+        let z_session = zenoh::open(z_config.clone()).wait().map_err(|e| {
+            CreationError::ZenohSessionOpenFailed {
+                reason: e.to_string(),
+            }
+        })?;
+        let z_discovery_mode = tunnel_config.discovery_mode;
+        let z_discovery_key_expression = tunnel_config
+            .discovery_key_expression
+            .clone()
+            .unwrap_or_else(|| match z_discovery_mode {
+                DiscoveryMode::PerService => keys::discovery(),
+                DiscoveryMode::Queryable => keys::service_registries(),
+            });
+        let z_discovery = match &tunnel_config.discovery_scouting_endpoint {
+            Some(endpoint) => ZenohDiscovery::with_scouting_endpoint(
+                endpoint,
+                &z_discovery_key_expression,
+                z_discovery_mode,
+            ),
+            None => {
+                ZenohDiscovery::create(&z_session, &z_discovery_key_expression, z_discovery_mode)
+            }
+        }
+        .map_err(|e| CreationError::ZenohDiscoveryCreationFailed {
+            reason: e.to_string(),
+        })?;
+
+        let mut z_additional_sessions = Vec::with_capacity(tunnel_config.zenoh_sessions.len());
+        let mut z_additional_discoveries = Vec::with_capacity(tunnel_config.zenoh_sessions.len());
+        for z_additional_config in &tunnel_config.zenoh_sessions {
+            let mut z_additional_config = z_additional_config.clone();
+            tunnel_config
+                .zenoh_session_options
+                .apply(&mut z_additional_config)?;
+            let z_additional_session = zenoh::open(z_additional_config).wait().map_err(|e| {
+                CreationError::ZenohSessionOpenFailed {
+                    reason: e.to_string(),
+                }
+            })?;
+            z_additional_discoveries.push(
+                ZenohDiscovery::create(
+                    &z_additional_session,
+                    &z_discovery_key_expression,
+                    z_discovery_mode,
+                )
+                .map_err(|e| CreationError::ZenohDiscoveryCreationFailed {
+                    reason: e.to_string(),
+                })?,
+            );
+            let label = z_additional_session.info().zid().wait().to_string();
+            z_additional_sessions.push((label, z_additional_session));
+        }
+
+        let mut iox_node_config = iox_config.clone();
+        if tunnel_config.disable_iox_monitoring {
+            iox_node_config.global.node.cleanup_dead_nodes_on_creation = false;
+            iox_node_config
+                .global
+                .node
+                .cleanup_dead_nodes_on_destruction = false;
+        }
+        let iox_node = NodeBuilder::new()
+            .config(&iox_node_config)
+            .create::<Service>()
+            .map_err(CreationError::IceoryxNodeCreationFailed)?;
+        let iox_discovery =
+            IceoryxDiscovery::create(iox_config, &iox_node, &tunnel_config.discovery_service)
+                .map_err(|e| CreationError::IceoryxDiscoveryCreationFailed {
+                    reason: e.to_string(),
+                })?;
+
+        let publish_subscribe_connectons: HashMap<
+            IceoryxServiceId,
+            BidirectionalPublishSubscribeConnection<Service>,
+        > = HashMap::new();
+        let event_connections: HashMap<IceoryxServiceId, BidirectionalEventConnection<Service>> =
+            HashMap::new();
+        let request_response_connections: HashMap<
+            IceoryxServiceId,
+            BidirectionalRequestResponseConnection<Service>,
+        > = HashMap::new();
+
+        Ok(Self {
+            z_config,
+            z_session_options: tunnel_config.zenoh_session_options,
+            z_session,
+            z_session_id: OnceLock::new(),
+            z_discovery,
+            z_additional_sessions,
+            z_additional_discoveries,
+            iox_node,
+            iox_discovery,
+            custom_discovery: custom_discovery.map(Mutex::new),
+            publish_subscribe_connectons,
+            event_connections,
+            request_response_connections,
+            failed_services: HashMap::new(),
+            service_sources: HashMap::new(),
+            service_ids_by_name: HashMap::new(),
+            connection_metadata: HashMap::new(),
+            iceoryx_to_zenoh_disabled: false,
+            zenoh_to_iceoryx_disabled: false,
+            propagation_policy: tunnel_config.propagation_policy,
+            min_discovery_interval: tunnel_config.min_discovery_interval,
+            last_discovery: None,
+            connection_defaults: ConnectionDefaults {
+                #[cfg(feature = "compression")]
+                compression_codec: tunnel_config.compression_codec,
+                #[cfg(feature = "compression")]
+                compression_threshold: tunnel_config
+                    .compression
+                    .then_some(crate::DEFAULT_COMPRESSION_THRESHOLD),
+                iceoryx_receive_timeout: tunnel_config.iceoryx_receive_timeout,
+                backpressure_policy: tunnel_config.backpressure_policy,
+                keep_alive_interval: tunnel_config.keep_alive_interval,
+                replay_history_on_connect: tunnel_config.replay_history_on_connect,
+                #[cfg(feature = "checksum")]
+                checksum_verification: tunnel_config.checksum_verification,
+                track_message_ids: tunnel_config.track_message_ids,
+                event_batching: tunnel_config.event_batching,
+            },
+            service_filter: ServiceFilter {
+                allowed_names: tunnel_config
+                    .service_name_allowlist
+                    .iter()
+                    .flatten()
+                    .cloned()
+                    .collect(),
+                blocklist: tunnel_config.service_blocklist.clone().unwrap_or_default(),
+                handed_over: HashSet::new(),
+                messaging_pattern: None,
+            },
+            connection_limit: ConnectionLimit {
+                max_connections: tunnel_config.max_connections,
+                policy: tunnel_config.max_connections_policy,
+                pending: VecDeque::new(),
+            },
+            use_color: tunnel_config.use_color.unwrap_or_else(use_color_by_default),
+            close_timeout: tunnel_config.close_timeout,
+            reconnect_policy: tunnel_config.reconnect_policy,
+            log_context: LogContext::default(),
+            key_expr_mapper: tunnel_config.key_expr_mapper.clone(),
+            schema_registry: None,
+            reject_unknown_services: tunnel_config.reject_unknown_services,
+            total_services_discovered: 0,
+            discovered_in_run: None,
+            scope_aliasing: tunnel_config.scope_aliasing,
+            connection_direction: tunnel_config.connection_direction,
+            qos_mapping: tunnel_config.qos_mapping,
+            z_allowed_destination: tunnel_config
+                .zenoh_allowed_destination
+                .unwrap_or(Locality::Remote),
+            iox_service_limits: IceoryxServiceLimits {
+                max_publishers: tunnel_config.iceoryx_max_publishers,
+                max_subscribers: tunnel_config.iceoryx_max_subscribers,
+            },
+            run_error_policy: tunnel_config.run_error_policy,
+            wait_backoff: tunnel_config.wait_backoff,
+            propagation_enabled: IoxAtomicBool::new(true),
+            connection_callbacks: ConnectionCallbacks {
+                on_created: tunnel_config.on_connection_created.clone(),
+                on_removed: tunnel_config.on_connection_removed.clone(),
+                removals: IoxAtomicU64::new(0),
+            },
+            quality_score_config: tunnel_config.quality_score_config.unwrap_or_default(),
+            z_ha_token: None,
+        })
+    }
+
+    /// Discover iceoryx services across all connected hosts.
+    ///
+    /// If a [`TunnelConfig::min_discovery_interval`] is configured and the previous discovery
+    /// is more recent than the interval, the discovery is skipped. A discovery in
+    /// [`Scope::None`] is always skipped, e.g. when the connections were added manually.
+    ///
+    /// With [`TunnelConfig::scope_aliasing`], a discovery in [`Scope::Iceoryx`] or
+    /// [`Scope::Zenoh`] discovers in [`Scope::Both`].
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the discovery scope
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If discovery was successful or skipped
+    /// * `Err(DiscoveryError)` - If discovery failed
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(log_context = self.log_context.as_fields()))
+    )]
+    pub fn discover(&mut self, scope: Scope) -> Result<(), DiscoveryError> {
+        if scope == Scope::None {
+            return Ok(());
+        }
+        let scope = if self.scope_aliasing {
+            Scope::Both
+        } else {
+            scope
+        };
+
+        if let (Some(min_discovery_interval), Some(last_discovery)) =
+            (self.min_discovery_interval, self.last_discovery)
+        {
+            if last_discovery.elapsed() < min_discovery_interval {
+                return Ok(());
+            }
+        }
+
+        self.last_discovery = Some(Instant::now());
+        self.run_discovery(scope)
+    }
+
+    /// Discover iceoryx services across all connected hosts right away, regardless of
+    /// the configured [`TunnelConfig::min_discovery_interval`].
+    ///
+    /// The throttling interval restarts from this discovery.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the discovery scope
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If discovery was successful
+    /// * `Err(DiscoveryError)` - If discovery failed
+    pub fn force_immediate_discovery(&mut self, scope: Scope) -> Result<(), DiscoveryError> {
+        self.last_discovery = None;
+        self.discover(scope)
+    }
+
+    /// Removes the connections of all services discovered in the provided scope and
+    /// discovers the services of the scope anew, regardless of the configured
+    /// [`TunnelConfig::min_discovery_interval`], e.g. to recover from a network partition
+    /// during which services restarted with a new id or changed their QoS.
+    ///
+    /// The payloads and events are not propagated until the services are connected again.
+    /// The statistics, settings and metadata of the removed connections are reset, while
+    /// services connected manually are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the discovery scope whose connections are recreated
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If discovery was successful
+    /// * `Err(DiscoveryError)` - If discovery failed
+    pub fn force_rediscover(&mut self, scope: Scope) -> Result<(), DiscoveryError> {
+        if scope == Scope::None {
+            return Ok(());
+        }
+        let scope = if self.scope_aliasing {
+            Scope::Both
+        } else {
+            scope
+        };
+        let is_in_scope = |source: &Scope| {
+            (source.includes_iceoryx() && scope.includes_iceoryx())
+                || (source.includes_zenoh() && scope.includes_zenoh())
+        };
+
+        let stale_services: Vec<IceoryxServiceId> = self
+            .service_sources
+            .iter()
+            .filter(|(_, source)| is_in_scope(source))
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in stale_services {
+            self.publish_subscribe_connectons.remove(&id);
+            self.event_connections.remove(&id);
+            self.request_response_connections.remove(&id);
+            self.service_sources.remove(&id);
+            self.service_ids_by_name
+                .retain(|_, service_id| *service_id != id);
+            info!(
+                "{}REMOVED {}: forced rediscovery",
+                self.log_context,
+                id.as_str()
+            );
+            self.connection_callbacks.removed(&id);
+        }
+        self.failed_services
+            .retain(|_, (source, _)| !is_in_scope(source));
+        self.remove_stale_metadata();
+
+        self.force_immediate_discovery(scope)
+    }
+
+    /// Creates the connections for the local services cached by the iceoryx2 discovery of the
+    /// tunnel right away, without querying for changes, e.g. to trade the freshness of the
+    /// discovery for low latency when propagation takes priority. Use
+    /// [`Tunnel::refresh_discovery_cache()`] to update the cache separately.
+    ///
+    /// Nothing is discovered when the tunnel was created with a custom discovery.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of cached services, including the already tunneled ones
+    /// * `Err(DiscoveryError::ServiceConnectionFailed)` - If the connection of a cached
+    ///   service could not be created
+    pub fn discover_cached(&mut self) -> Result<usize, DiscoveryError> {
+        self.reset_discovered_in_run();
+        let mut connection_error = None;
+        let discovered = self.run_iceoryx_discovery(true, &mut connection_error)?;
+
+        // Newly discovered connections propagate in the currently enabled directions only
+        self.apply_directions();
+        self.remove_stale_metadata();
+        self.update_service_registries();
+
+        connection_error.map_or(Ok(discovered), Err)
+    }
+
+    /// Updates the cache of the iceoryx2 discovery of the tunnel with the local services
+    /// added or removed since the previous update, without creating connections for them.
+    /// The connections are created with [`Tunnel::discover_cached()`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the cache was updated or the tunnel uses a custom discovery
+    /// * `Err(DiscoveryError)` - If the local services could not be queried
+    pub fn refresh_discovery_cache(&mut self) -> Result<(), DiscoveryError> {
+        if self.custom_discovery.is_some() {
+            return Ok(());
+        }
+
+        self.iox_discovery.refresh()
+    }
+
+    /// Discovers the local iceoryx2 services and creates the connections for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_cache` - Discovers the services cached by the iceoryx2 discovery without
+    ///   querying for changes, connections of stale services are kept
+    /// * `connection_error` - Receives the first error of the connections that could not be
+    ///   created
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of services discovered from the cache, `0` if not
+    ///   discovering from the cache
+    /// * `Err(DiscoveryError)` - If discovery failed
+    fn run_iceoryx_discovery(
+        &mut self,
+        from_cache: bool,
+        connection_error: &mut Option<DiscoveryError>,
+    ) -> Result<usize, DiscoveryError> {
+        // The discovered services are connected once the discovery released the tunnel
+        let mut discovered_services = Vec::new();
+        let mut on_discovered = |iox_service_config: &IceoryxServiceConfig| {
+            if is_newly_discovered(
+                &self.service_filter,
+                &mut self.discovered_in_run,
+                iox_service_config,
+            ) {
+                discovered_services.push(iox_service_config.clone());
+            }
+        };
+
+        let discovery_result = if from_cache {
+            // A custom discovery does not cache the services it discovered
+            Ok(match self.custom_discovery {
+                Some(_) => 0,
+                None => self.iox_discovery.discover_non_blocking(&mut on_discovered),
+            })
+        } else {
+            match &mut self.custom_discovery {
+                Some(custom_discovery) => custom_discovery
+                    .get_mut()
+                    .unwrap_or_else(PoisonError::into_inner)(
+                    &mut on_discovered
+                )
+                .map_err(|e| DiscoveryError::IceoryxDiscoveryFailed {
+                    reason: e.to_string(),
+                }),
+                None => self.iox_discovery.discover(&mut on_discovered),
+            }
+            .map(|_| 0)
+        };
+
+        // The services discovered before a failing discovery are connected nonetheless
+        for iox_service_config in &discovered_services {
+            // The other discovered services are connected regardless
+            if let Err(e) = self.connect_discovered_service(Scope::Iceoryx, iox_service_config) {
+                connection_error.get_or_insert(e);
+            }
+        }
+        let cached = discovery_result?;
+
+        if from_cache {
+            return Ok(cached);
+        }
+
+        if self.custom_discovery.is_none() {
+            if let Some(live_services) = self.iox_discovery.live_services() {
+                remove_stale_connections(
+                    DiscoverySource {
+                        scope: Scope::Iceoryx,
+                        log_context: &self.log_context,
+                        callbacks: &self.connection_callbacks,
+                    },
+                    &live_services,
+                    &mut self.publish_subscribe_connectons,
+                    &mut self.event_connections,
+                    &mut self.request_response_connections,
+                    &mut self.service_ids_by_name,
+                    &mut self.service_sources,
+                );
+                // Pending services that disappeared in the meantime are not connected
+                self.connection_limit
+                    .pending
+                    .retain(|(source, iox_service_config)| {
+                        *source != Scope::Iceoryx
+                            || live_services.contains(iox_service_config.service_id())
+                    });
+                self.connect_pending_services();
+            }
+        }
+
+        Ok(0)
+    }
+
+    fn run_discovery(&mut self, scope: Scope) -> Result<(), DiscoveryError> {
+        self.reset_discovered_in_run();
+        let mut connection_error = None;
+
+        if scope.includes_iceoryx() {
+            self.run_iceoryx_discovery(false, &mut connection_error)?;
+        }
+
+        if scope.includes_zenoh() {
+            let mut announced_services = HashSet::new();
+            let mut discovered_services = Vec::new();
+            let mut on_discovered = |iox_service_config: &IceoryxServiceConfig| {
+                announced_services.insert(iox_service_config.service_id().clone());
+                if is_newly_discovered(
+                    &self.service_filter,
+                    &mut self.discovered_in_run,
+                    iox_service_config,
+                ) {
+                    discovered_services.push(iox_service_config.clone());
+                }
+            };
+
+            let discovery_result = core::iter::once(&mut self.z_discovery)
+                .chain(&mut self.z_additional_discoveries)
+                .try_for_each(|z_discovery| z_discovery.discover(&mut on_discovered));
+
+            // The services discovered before a failing discovery are connected nonetheless
+            for iox_service_config in &discovered_services {
+                // The other discovered services are connected regardless
+                if let Err(e) = self.connect_discovered_service(Scope::Zenoh, iox_service_config) {
+                    connection_error.get_or_insert(e);
+                }
+            }
+            discovery_result?;
+
+            // Pending remote services that are not announced anymore are not connected
+            self.connection_limit
+                .pending
+                .retain(|(source, iox_service_config)| {
+                    *source != Scope::Zenoh
+                        || announced_services.contains(iox_service_config.service_id())
+                });
+            self.connect_pending_services();
+        }
+
+        // Newly discovered connections propagate in the currently enabled directions only
+        self.apply_directions();
+        self.remove_stale_metadata();
+        self.update_service_registries();
+
+        connection_error.map_or(Ok(()), Err)
+    }
+
+    /// Creates the connection of a discovered service, unless the maximum number of
+    /// connections is reached and no room can be made for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The scope in which the service was discovered
+    /// * `iox_service_config` - The static config of the discovered service
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the service was connected, is connected already or was skipped
+    /// * `Err(DiscoveryError)` - If the connection could not be created
+    fn connect_discovered_service(
+        &mut self,
+        scope: Scope,
+        iox_service_config: &IceoryxServiceConfig,
+    ) -> Result<(), DiscoveryError> {
+        let context = ConnectionContext {
+            iox_node: &self.iox_node,
+            z_session: &self.z_session,
+            key_expr_mapper: self.key_expr_mapper.as_deref(),
+            schema_registry: self.schema_registry.as_deref(),
+            reject_unknown_services: self.reject_unknown_services,
+            connection_direction: self.connection_direction,
+            qos_mapping: self.qos_mapping,
+            backpressure_policy: self.connection_defaults.backpressure_policy,
+            z_allowed_destination: self.z_allowed_destination,
+            iox_service_limits: self.iox_service_limits,
+        };
+
+        // Only services that can be connected within the limit are tunneled, nothing is
+        // evicted for services that are not connected anyway
+        if is_limited_connection(
+            iox_service_config,
+            &context,
+            &self.publish_subscribe_connectons,
+            &self.event_connections,
+            &self.request_response_connections,
+            &self.failed_services,
+        ) && !make_room_for_service(
+            iox_service_config,
+            &mut self.connection_limit,
+            &mut self.publish_subscribe_connectons,
+            &mut self.event_connections,
+            &mut self.service_ids_by_name,
+            &mut self.service_sources,
+            DiscoverySource {
+                scope,
+                log_context: &self.log_context,
+                callbacks: &self.connection_callbacks,
+            },
+        ) {
+            return Ok(());
+        }
+
+        self.attach_service(iox_service_config, scope).map(|_| ())
+    }
+
+    /// Processes each discovered service at most once per discovery, even if it is discovered
+    /// in both scopes, e.g. when the local host is also a zenoh peer announcing its own
+    /// services. Disabled by default, the already tunneled services are skipped regardless.
+    pub fn enable_discovery_deduplication(&mut self) {
+        self.discovered_in_run.get_or_insert_with(HashSet::new);
+    }
+
+    /// Processes every discovered service in each scope it is discovered in, which is the
+    /// default.
+    pub fn disable_discovery_deduplication(&mut self) {
+        self.discovered_in_run = None;
+    }
+
+    /// Replies to the registry queries of remote tunnels with the currently tunneled services.
+    fn update_service_registries(&self) {
+        for z_discovery in core::iter::once(&self.z_discovery).chain(&self.z_additional_discoveries)
+        {
+            z_discovery.set_registered_services(
+                self.publish_subscribe_connectons
+                    .values()
+                    .map(|connection| connection.service_config())
+                    .chain(
+                        self.event_connections
+                            .values()
+                            .map(|connection| connection.service_config()),
+                    )
+                    .chain(
+                        self.request_response_connections
+                            .values()
+                            .map(|connection| connection.service_config()),
+                    ),
+            );
+        }
+    }
+
+    /// Forgets the services processed by the previous discovery when deduplicating.
+    fn reset_discovered_in_run(&mut self) {
+        if let Some(discovered_in_run) = &mut self.discovered_in_run {
+            discovered_in_run.clear();
+        }
+    }
+
+    /// Removes the metadata of the connections that were removed during discovery, so that
+    /// the metadata does not carry over to a connection recreated for the same service.
+    fn remove_stale_metadata(&mut self) {
+        self.connection_metadata
+            .retain(|id, _| self.service_sources.contains_key(id));
+    }
+
+    /// Returns the configurations of all local services with the provided messaging pattern,
+    /// regardless of whether they are tunneled, e.g. to inspect the candidates for tunneling
+    /// without creating connections for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The messaging pattern of the services to return
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<IceoryxServiceConfig>)` - The configurations of the matching services
+    /// * `Err(DiscoveryError)` - If the local services could not be queried
+    pub fn services_matching_pattern(
+        &self,
+        pattern: MessagingPatternKind,
+    ) -> Result<Vec<IceoryxServiceConfig>, DiscoveryError> {
+        self.iox_discovery.services_matching_pattern(pattern)
+    }
+
+    /// Returns the configurations of all services in the provided scope that can be tunneled,
+    /// regardless of whether they are tunneled, e.g. for diagnostic tooling. No connections
+    /// are created.
+    ///
+    /// The local services are queried right away. The remote services are the ones that
+    /// replied since the previous discovery, listing them consumes the replies so that
+    /// remote services not tunneled yet are only connected by the discovery after the next.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines whether local services, remote services or both are listed
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<IceoryxServiceConfig>)` - The configurations of the services, each service
+    ///   once
+    /// * `Err(DiscoveryError)` - If the services could not be queried
+    pub fn list_services(
+        &mut self,
+        scope: Scope,
+    ) -> Result<Vec<IceoryxServiceConfig>, DiscoveryError> {
+        let mut iox_service_configs = Vec::new();
+        if scope.includes_iceoryx() {
+            iox_service_configs.extend(self.iox_discovery.list_services()?);
+        }
+        if scope.includes_zenoh() {
+            for z_discovery in
+                core::iter::once(&mut self.z_discovery).chain(&mut self.z_additional_discoveries)
+            {
+                iox_service_configs.extend(z_discovery.list_services()?);
+            }
+        }
+
+        let mut listed = HashSet::new();
+        iox_service_configs
+            .retain(|iox_service_config| listed.insert(iox_service_config.service_id().clone()));
+
+        Ok(iox_service_configs)
+    }
+
+    /// Restricts the tunnel to the services with the provided names, services with other names
+    /// are no longer connected when discovered. An empty list allows all services, which is the
+    /// default.
+    ///
+    /// Services that are already tunneled remain connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names of the services to tunnel
+    pub fn with_service_allowed_list(&mut self, names: Vec<String>) {
+        self.service_filter.allowed_names = names.into_iter().collect();
+    }
+
+    /// Changes the zenoh key expression on which remote service details are queried, e.g. to
+    /// restrict the discovery to a subset of the remote services.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The zenoh key expression to query
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the queries on the new key expression were set up
+    /// * `Err(DiscoveryError)` - If the key expression is invalid or the query failed
+    pub fn set_discovery_key_expression(&mut self, key: &str) -> Result<(), DiscoveryError> {
+        self.z_discovery.set_discovery_key_expression(key)
+    }
+
+    /// Disables propagating local payloads and events to remote hosts over all connections,
+    /// including the ones discovered later on, e.g. to test the other direction in isolation.
+    pub fn disable_iceoryx_to_zenoh(&mut self) {
+        self.iceoryx_to_zenoh_disabled = true;
+        self.apply_directions();
+    }
+
+    /// Re-enables propagating local payloads and events to remote hosts over all connections.
+    pub fn enable_iceoryx_to_zenoh(&mut self) {
+        self.iceoryx_to_zenoh_disabled = false;
+        self.apply_directions();
+    }
+
+    /// Disables propagating remote payloads and events to the local host over all connections,
+    /// including the ones discovered later on, e.g. to test the other direction in isolation.
+    pub fn disable_zenoh_to_iceoryx(&mut self) {
+        self.zenoh_to_iceoryx_disabled = true;
+        self.apply_directions();
+    }
+
+    /// Re-enables propagating remote payloads and events to the local host over all connections.
+    pub fn enable_zenoh_to_iceoryx(&mut self) {
+        self.zenoh_to_iceoryx_disabled = false;
+        self.apply_directions();
+    }
+
+    /// Pauses all connections and delivers the pending local payloads and events to remote
+    /// hosts beforehand, e.g. before a planned maintenance, so that no messages are lost.
+    ///
+    /// Propagating remote payloads and events to the local host is paused right away. The
+    /// pending local payloads and events are propagated until none are left, then
+    /// propagating to remote hosts is paused as well. The connections are paused in both
+    /// directions even when draining timed out and can be resumed with
+    /// [`Tunnel::enable_iceoryx_to_zenoh()`] and [`Tunnel::enable_zenoh_to_iceoryx()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum duration to drain the pending local payloads and events
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DrainReport)` - The numbers drained per service
+    /// * `Err(DrainError)` - If pending local payloads or events were left after the timeout
+    pub fn pause_all_and_drain(&mut self, timeout: Duration) -> Result<DrainReport, DrainError> {
+        let deadline = Instant::now() + timeout;
+        self.disable_zenoh_to_iceoryx();
+
+        let mut report = DrainReport::default();
+        let result = loop {
+            let iteration = self.propagate_once_and_report();
+            let mut drained_in_iteration = 0;
+            for (id, (iceoryx_to_zenoh, _)) in iteration.forwarded() {
+                *report.drained.entry(id.clone()).or_default() += iceoryx_to_zenoh;
+                drained_in_iteration += iceoryx_to_zenoh;
+            }
+
+            if drained_in_iteration == 0 {
+                break Ok(report);
+            }
+            if Instant::now() >= deadline {
+                break Err(DrainError::Timeout);
+            }
+        };
+
+        self.disable_iceoryx_to_zenoh();
+
+        result
+    }
+
+    /// Propagates the local events of all event connections to remote hosts until none are
+    /// pending anymore, e.g. before suspending the propagation loop to enter a low-power mode.
+    ///
+    /// Events collected in a batch, see [`EventBatching`], are pending until the batch is due,
+    /// so the timeout should exceed the batch timeout. Remote events are not propagated to the
+    /// local host while flushing. Connections disabled by their failures are skipped and
+    /// nothing is flushed while the global propagation is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum duration to flush the pending local events
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of local events propagated to remote hosts
+    /// * `Err(FlushError)` - If pending local events were left after the timeout or
+    ///   propagating over a connection failed
+    pub fn flush_event_connections(&self, timeout: Duration) -> Result<u64, FlushError> {
+        if !self.is_global_propagation_enabled() {
+            return Ok(0);
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut flushed = 0;
+        loop {
+            let connections = self
+                .event_connections
+                .values()
+                .filter(|connection| !connection.failures().is_disabled());
+            for connection in connections.clone() {
+                flushed += connection.flush().map_err(FlushError::PropagationFailed)? as u64;
+            }
+
+            if connections
+                .clone()
+                .all(|connection| connection.pending_events() == 0)
+            {
+                return Ok(flushed);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(FlushError::Timeout);
+            }
+
+            // Sleep until the next pending batch is due instead of spinning
+            let next_due = connections
+                .filter_map(|connection| connection.pending_batch_due_in())
+                .min()
+                .unwrap_or(Duration::ZERO);
+            std::thread::sleep(next_due.min(deadline - now));
+        }
+    }
+
+    fn apply_directions(&mut self) {
+        for connection in self.publish_subscribe_connectons.values_mut() {
+            connection.set_iceoryx_to_zenoh_disabled(self.iceoryx_to_zenoh_disabled);
+            connection.set_zenoh_to_iceoryx_disabled(self.zenoh_to_iceoryx_disabled);
+        }
+        for connection in self.event_connections.values_mut() {
+            connection.set_iceoryx_to_zenoh_disabled(self.iceoryx_to_zenoh_disabled);
+            connection.set_zenoh_to_iceoryx_disabled(self.zenoh_to_iceoryx_disabled);
+        }
+        for connection in self.request_response_connections.values_mut() {
+            connection.set_iceoryx_to_zenoh_disabled(self.iceoryx_to_zenoh_disabled);
+            connection.set_zenoh_to_iceoryx_disabled(self.zenoh_to_iceoryx_disabled);
+        }
+    }
+
+    /// Propagates over all connections like [`Tunnel::propagate()`] and recreates the
+    /// connections that failed to propagate, e.g. to recover from transient errors without
+    /// waiting for the caller to intervene. Connections that are merely busy, i.e. failed
+    /// with [`PropagationError::ReceiveTimeout`] or [`PropagationError::Backpressure`], are
+    /// kept as they are.
+    ///
+    /// A failed connection is recreated and propagated over again until it succeeds or
+    /// `max_reset_attempts` is reached. Connections that could not be recovered are removed
+    /// and recorded as failed services, see [`Tunnel::retry_failed_services()`]. The
+    /// statistics, settings and metadata of recreated connections are reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_reset_attempts` - The maximum number of times a failed connection is recreated
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationReport` - The outcome of the propagation, listing only the failures of
+    ///   the connections that could not be recovered
+    pub fn propagate_with_error_recovery(&mut self, max_reset_attempts: u32) -> PropagationReport {
+        let mut report = self.propagate();
+        let failed_ids: Vec<IceoryxServiceId> = report
+            .failures
+            .iter()
+            .filter(|e| !e.error().is_busy())
+            .map(|e| e.service_id().clone())
+            .collect();
+
+        for id in failed_ids {
+            let Some((iox_service_config, source)) = self.detach_service(&id) else {
+                continue;
+            };
+
+            let mut is_recovered = false;
+            for attempt in 1..=max_reset_attempts {
+                warn!(
+                    "{}RESETTING {} after failed propagation (attempt {} of {})",
+                    self.log_context,
+                    id.as_str(),
+                    attempt,
+                    max_reset_attempts
+                );
+                if !matches!(self.attach_service(&iox_service_config, source), Ok(true)) {
+                    continue;
+                }
+                self.apply_directions();
+
+                let retry_report = self.propagate_until(None, |other| *other == id);
+                if retry_report.is_success() {
+                    report.propagated += retry_report.propagated;
+                    is_recovered = true;
+                    break;
+                }
+                self.detach_service(&id);
+            }
+
+            if is_recovered {
+                report.failures.retain(|e| *e.service_id() != id);
+            } else {
+                error!(
+                    "{}FAILED {} after {} reset attempts",
+                    self.log_context,
+                    id.as_str(),
+                    max_reset_attempts
+                );
+                self.service_filter.handed_over.remove(&id);
+                self.failed_services
+                    .insert(id, (source, iox_service_config));
+            }
+        }
+
+        report
+    }
+
+    /// Halts or resumes the propagation over all connections at once, e.g. as an emergency
+    /// stop that neither tears down the connections nor pauses each service.
+    ///
+    /// While halted, all propagate functions of the tunnel return without propagating.
+    /// Payloads and notifications that arrive in the meantime remain queued in the
+    /// connections, as far as their buffers permit.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `false` to halt all propagation, `true` to resume it
+    pub fn set_global_propagation_enabled(&self, enabled: bool) {
+        info!(
+            "{}{} propagation over all connections",
+            self.log_context,
+            if enabled { "RESUMED" } else { "HALTED" }
+        );
+        self.propagation_enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns `false` if the propagation over all connections was halted with
+    /// [`Tunnel::set_global_propagation_enabled()`].
+    pub fn is_global_propagation_enabled(&self) -> bool {
+        self.propagation_enabled.load(Ordering::Relaxed)
+    }
+
+    /// Propagates payloads between all connected hosts.
+    ///
+    /// All connections are propagated, even if some of them fail. Connections that were
+    /// disabled according to the configured [`PropagationPolicy`] are skipped. The
+    /// [`TunnelConfig::reconnect_policy`] is not applied, use
+    /// [`Tunnel::propagate_with_reconnect()`] to reconnect a lost zenoh session first.
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationReport` - The outcome of the propagation over all connections
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                log_context = self.log_context.as_fields(),
+                messages_forwarded = tracing::field::Empty,
+            )
+        )
+    )]
+    pub fn propagate(&self) -> PropagationReport {
+        let report = self.propagate_until(None, |_| true);
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("messages_forwarded", report.propagated());
+
+        report
+    }
+
+    /// Propagates payloads between all connected hosts like [`Tunnel::propagate()`] and
+    /// returns a copy of the payloads forwarded over the connections of publish-subscribe
+    /// services, e.g. to inspect the messages that traversed the tunnel in tests.
+    ///
+    /// Intended for testing only: every returned payload is copied into a separate heap
+    /// allocation. Use [`Tunnel::watch_service()`] to inspect the payloads of a service
+    /// continuously.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_per_service` - The maximum number of payloads returned per service, further
+    ///   payloads are propagated but not returned
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<IceoryxServiceId, Vec<(Direction, Vec<u8>)>>` - The forwarded payloads of
+    ///   each service that forwarded any, in the order in which they were forwarded
+    pub fn propagate_and_return_new_messages(
+        &self,
+        max_per_service: usize,
+    ) -> HashMap<IceoryxServiceId, Vec<(Direction, Vec<u8>)>> {
+        // The payloads are recorded with taps that are removed again after propagating
+        let recordings: Vec<_> = self
+            .publish_subscribe_connectons
+            .iter()
+            .map(|(id, connection)| {
+                let recording = Arc::new(Mutex::new(Vec::new()));
+                let sink = recording.clone();
+                let tap_id = connection.taps().install(Box::new(
+                    move |direction: Direction, payload: &[u8]| {
+                        let mut messages = sink.lock().unwrap();
+                        if messages.len() < max_per_service {
+                            messages.push((direction, payload.to_vec()));
+                        }
+                        true
+                    },
+                ));
+                (id.clone(), recording, tap_id)
+            })
+            .collect();
+
+        self.propagate();
+
+        recordings
+            .into_iter()
+            .filter_map(|(id, recording, tap_id)| {
+                if let Some(connection) = self.publish_subscribe_connectons.get(&id) {
+                    connection.taps().remove(tap_id);
+                }
+                let messages = core::mem::take(&mut *recording.lock().unwrap());
+                (!messages.is_empty()).then_some((id, messages))
+            })
+            .collect()
+    }
+
+    /// Propagates payloads between all connected hosts and stops at the first connection
+    /// that fails, e.g. for test harnesses that require propagation to succeed.
+    ///
+    /// Connections that were disabled according to the configured [`PropagationPolicy`]
+    /// are skipped. Use [`Tunnel::propagate()`] to propagate over all connections and
+    /// collect every failure.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If all connections propagated without failure
+    /// * `Err(ServicePropagationError)` - The failure of the first connection that failed
+    pub fn propagate_or_fail_fast(&self) -> Result<(), ServicePropagationError> {
+        let mut report = PropagationReport::default();
+        if !self.is_global_propagation_enabled() {
+            return Ok(());
+        }
+
+        let mut failure = None;
+        self.for_each_connection(|_, connection| {
+            if failure.is_some() {
+                return;
+            }
+
+            propagate_connection(
+                connection.service_config(),
+                connection,
+                connection.failures(),
+                self.propagation_policy,
+                &self.log_context,
+                &mut report,
+            );
+            failure = report.failures.pop();
+        });
+
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the ids of all tunneled services sorted by the number of payloads and
+    /// notifications forwarded in the provided direction, most active first, e.g. to
+    /// prioritize buffer sizing or compression.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The direction in which the forwarded messages are counted
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(IceoryxServiceId, u64)>` - The ids of the services along with their message
+    ///   counts, in descending order of the counts
+    pub fn connection_ids_sorted_by_message_count(
+        &self,
+        direction: Direction,
+    ) -> Vec<(IceoryxServiceId, u64)> {
+        let message_count = |statistics: &ConnectionStatistics| match direction {
+            Direction::IceoryxToZenoh => statistics.forwarded_iceoryx_to_zenoh(),
+            Direction::ZenohToIceoryx => statistics.forwarded_zenoh_to_iceoryx(),
+        };
+
+        let mut message_counts: Vec<(IceoryxServiceId, u64)> = Vec::new();
+        self.for_each_connection(|id, connection| {
+            message_counts.push((id.clone(), message_count(connection.statistics())));
+        });
+        message_counts.sort_unstable_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs));
+
+        message_counts
+    }
+
+    /// Returns a snapshot of the traffic forwarded over the connections of all tunneled
+    /// services. The counters are updated atomically during propagation, so the snapshot
+    /// can be taken while another thread propagates.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<IceoryxServiceId, TunnelStatistics>` - The statistics of each tunneled
+    ///   service
+    pub fn statistics(&self) -> HashMap<IceoryxServiceId, TunnelStatistics> {
+        self.publish_subscribe_connectons
+            .iter()
+            .map(|(id, connection)| (id.clone(), TunnelStatistics::of(connection.statistics())))
+            .chain(self.event_connections.iter().map(|(id, connection)| {
+                (id.clone(), TunnelStatistics::of(connection.statistics()))
+            }))
+            .chain(
+                self.request_response_connections
+                    .iter()
+                    .map(|(id, connection)| {
+                        (id.clone(), TunnelStatistics::of(connection.statistics()))
+                    }),
+            )
+            .collect()
+    }
+
+    /// Returns the latency percentiles of the publish-subscribe payloads received from remote
+    /// hosts, i.e. the duration between their publication on zenoh by the remote tunnel and
+    /// their receipt by this tunnel. The percentiles cover the most recent payloads of each
+    /// service and rely on synchronized clocks of the hosts.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<IceoryxServiceId, LatencyStats>` - The latency percentiles of each tunneled
+    ///   publish-subscribe service that received payloads from remote hosts
+    #[cfg(feature = "latency-tracking")]
+    pub fn latency_report(&self) -> HashMap<IceoryxServiceId, LatencyStats> {
+        self.publish_subscribe_connectons
+            .iter()
+            .filter_map(|(id, connection)| {
+                connection
+                    .statistics()
+                    .latency_stats()
+                    .map(|latency_stats| (id.clone(), latency_stats))
+            })
+            .collect()
+    }
+
+    /// Resets the statistics returned by [`Tunnel::statistics()`] of all tunneled services,
+    /// e.g. to exclude the warm-up of a benchmark.
+    pub fn reset_statistics(&self) {
+        for connection in self.publish_subscribe_connectons.values() {
+            connection.statistics().reset_counters();
+        }
+        for connection in self.event_connections.values() {
+            connection.statistics().reset_counters();
+        }
+        for connection in self.request_response_connections.values() {
+            connection.statistics().reset_counters();
+        }
+    }
+
+    /// Returns the services in the order in which their connections are visited by the next
+    /// [`Tunnel::propagate()`], e.g. for debugging.
+    ///
+    /// The publish-subscribe services are visited before the event services, which are
+    /// visited before the request-response services. Within each messaging pattern, the order
+    /// is unspecified and may change when services are added or removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<IceoryxServiceId>` - The ids of the tunneled services in propagation order
+    pub fn service_propagation_order(&self) -> Vec<IceoryxServiceId> {
+        let mut order = Vec::new();
+        self.for_each_connection(|id, _| order.push(id.clone()));
+
+        order
+    }
+
+    /// Propagates payloads between connected hosts until the provided deadline is reached.
+    ///
+    /// Once the deadline is past, no further connections are propagated over. A propagation
+    /// over a connection that is already in progress is completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - The point in time after which no further connection is propagated over
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationReport` - The outcome of the propagation, including the number of
+    ///   connections skipped due to the deadline
+    pub fn propagate_with_deadline(&self, deadline: Instant) -> PropagationReport {
+        self.propagate_until(Some(deadline), |_| true)
+    }
+
+    /// Propagates payloads between connected hosts only over the connections of the services
+    /// matching the provided predicate, e.g. for staged propagation in systems with timing
+    /// constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Determines whether the connection of a service is propagated over
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationReport` - The outcome of the propagation over the matching connections
+    pub fn propagate_selective<F: Fn(&IceoryxServiceId) -> bool>(
+        &self,
+        predicate: F,
+    ) -> PropagationReport {
+        self.propagate_until(None, predicate)
+    }
+
+    /// Propagates payloads between connected hosts over the connections the provided
+    /// interceptor accepts for the provided context, without storing the context in the
+    /// tunnel.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context passed to the interceptor for every connection
+    /// * `interceptor` - Determines whether the connection of a service is propagated over
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationReport` - The outcome of the propagation over the accepted connections
+    pub fn propagate_with_context<C: Send + Sync, I: PropagationInterceptor<C>>(
+        &self,
+        context: &C,
+        interceptor: &I,
+    ) -> PropagationReport {
+        self.propagate_until(None, |id| interceptor.intercept(id, context))
+    }
+
+    fn propagate_until<F: Fn(&IceoryxServiceId) -> bool>(
+        &self,
+        deadline: Option<Instant>,
+        predicate: F,
+    ) -> PropagationReport {
+        let mut report = PropagationReport::default();
+        if !self.is_global_propagation_enabled() {
+            return report;
+        }
+
+        let is_past_deadline = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+        self.for_each_connection(|id, connection| {
+            if !predicate(id) {
+                return;
+            }
+            if is_past_deadline() {
+                report.skipped += 1;
+                return;
+            }
+
+            propagate_connection(
+                connection.service_config(),
+                connection,
+                connection.failures(),
+                self.propagation_policy,
+                &self.log_context,
+                &mut report,
+            );
+        });
+
+        report
+    }
+
+    /// Visits the connections of all tunneled services in propagation order, see
+    /// [`Tunnel::service_propagation_order()`].
+    fn for_each_connection<'t>(
+        &'t self,
+        mut f: impl FnMut(&'t IceoryxServiceId, &'t dyn TunneledConnection),
+    ) {
+        for (id, connection) in &self.publish_subscribe_connectons {
+            f(id, connection);
+        }
+        for (id, connection) in &self.event_connections {
+            f(id, connection);
+        }
+        for (id, connection) in &self.request_response_connections {
+            f(id, connection);
+        }
+    }
+
+    /// Propagates payloads between all connected hosts once and reports what was forwarded
+    /// per service during this call, independent of the cumulative statistics.
+    ///
+    /// # Returns
+    ///
+    /// * `IterationReport` - The numbers forwarded per service and the outcome of the
+    ///   propagation over all connections
+    pub fn propagate_once_and_report(&self) -> IterationReport {
+        let mut report = IterationReport::default();
+        if !self.is_global_propagation_enabled() {
+            return report;
+        }
+
+        self.for_each_connection(|id, connection| {
+            let before = forwarded(connection.statistics());
+            propagate_connection(
+                connection.service_config(),
+                connection,
+                connection.failures(),
+                self.propagation_policy,
+                &self.log_context,
+                &mut report.propagation,
+            );
+            let after = forwarded(connection.statistics());
+            report
+                .forwarded
+                .insert(id.clone(), (after.0 - before.0, after.1 - before.1));
+        });
+
+        report
+    }
+
+    /// Propagates payloads between all connected hosts, propagating over the connections
+    /// concurrently on the `rayon` thread pool.
+    ///
+    /// Behaves like [`Tunnel::propagate()`] but a slow connection does not delay the
+    /// propagation over the remaining connections. Only available for thread-safe service
+    /// types.
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationReport` - The outcome of the propagation over all connections
+    #[cfg(feature = "rayon")]
+    // The connections are only `Sync` for thread-safe service types.
+    #[allow(private_bounds)]
+    pub fn propagate_parallel(&self) -> PropagationReport
+    where
+        BidirectionalPublishSubscribeConnection<'a, Service>: Sync,
+        BidirectionalEventConnection<'a, Service>: Sync,
+        BidirectionalRequestResponseConnection<'a, Service>: Sync,
+    {
+        use rayon::prelude::*;
+
+        if !self.is_global_propagation_enabled() {
+            return PropagationReport::default();
+        }
+
+        let propagation_policy = self.propagation_policy;
+        let log_context = &self.log_context;
+        let (publish_subscribe_connections, event_connections, request_response_connections) = (
+            &self.publish_subscribe_connectons,
+            &self.event_connections,
+            &self.request_response_connections,
+        );
+        let mut ids = Vec::new();
+        self.for_each_connection(|id, _| ids.push(id));
+
+        ids.into_par_iter()
+            .map(|id| {
+                let mut report = PropagationReport::default();
+                if let Some(connection) = find_connection(
+                    id,
+                    publish_subscribe_connections,
+                    event_connections,
+                    request_response_connections,
+                ) {
+                    propagate_connection(
+                        connection.service_config(),
+                        connection,
+                        connection.failures(),
+                        propagation_policy,
+                        log_context,
+                        &mut report,
+                    );
+                }
+                report
+            })
+            .reduce(PropagationReport::default, |mut report, other| {
+                report.propagated += other.propagated;
+                report.failures.extend(other.failures);
+                report.skipped += other.skipped;
+                report
+            })
+    }
+
+    /// Propagates payloads between all connected hosts from async code, returning control to
+    /// the async runtime after each connection so that other tasks are not blocked while
+    /// iterating the connections.
+    ///
+    /// Behaves like [`Tunnel::propagate()`] otherwise and does not depend on a specific
+    /// runtime.
+    ///
+    /// # Returns
+    ///
+    /// * `PropagationReport` - The outcome of the propagation over all connections
+    #[cfg(feature = "async")]
+    pub async fn propagate_async(&self) -> PropagationReport {
+        let mut report = PropagationReport::default();
+        if !self.is_global_propagation_enabled() {
+            return report;
+        }
+
+        // The connections are looked up again after every yield, so that no connection is held
+        // across an await point
+        let mut ids = Vec::new();
+        self.for_each_connection(|id, _| ids.push(id));
+        for id in ids {
+            if let Some(connection) = find_connection(
+                id,
+                &self.publish_subscribe_connectons,
+                &self.event_connections,
+                &self.request_response_connections,
+            ) {
+                propagate_connection(
+                    connection.service_config(),
+                    connection,
+                    connection.failures(),
+                    self.propagation_policy,
+                    &self.log_context,
+                    &mut report,
+                );
+            }
+            YieldNow::default().await;
+        }
+
+        report
+    }
+
+    /// Declares the liveliness token announcing the primary tunnel of a hot-standby pair.
+    fn declare_ha_token(&mut self, z_ha_key: String) -> Result<(), zenoh::Error> {
+        let z_ha_token = self
+            .z_session
+            .liveliness()
+            .declare_token(&z_ha_key)
+            .wait()?;
+        self.z_ha_token = Some((z_ha_key, z_ha_token));
+
+        Ok(())
+    }
+
+    /// Returns the id of the zenoh session of the tunnel, e.g. to correlate the tunnel logs
+    /// with the logs of zenoh routers.
+    ///
+    /// The id is retrieved from the session once and cached for subsequent calls.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The hex-encoded zenoh id of the session
+    pub fn zenoh_session_id(&self) -> String {
+        self.z_session_id
+            .get_or_init(|| self.z_session.info().zid().wait().to_string())
+            .clone()
+    }
+
+    /// Returns the labels of all zenoh sessions of the tunnel, starting with the session of
+    /// the zenoh configuration provided on creation followed by the sessions of
+    /// [`TunnelConfig::zenoh_sessions`]. Each session is labeled with its zenoh id.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The labels of the zenoh sessions
+    pub fn zenoh_session_labels(&self) -> Vec<String> {
+        core::iter::once(self.zenoh_session_id())
+            .chain(
+                self.z_additional_sessions
+                    .iter()
+                    .map(|(label, _)| label.clone()),
+            )
+            .collect()
+    }
+
+    /// Returns the ids of all zenoh routers the zenoh session of the tunnel is currently
+    /// connected to, e.g. to debug the topology of the zenoh network.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The hex-encoded zenoh ids of the connected routers
+    pub fn zenoh_router_list(&self) -> Vec<String> {
+        self.z_session
+            .info()
+            .routers_zid()
+            .wait()
+            .map(|zid| zid.to_string())
+            .collect()
+    }
+
+    /// Returns the ids of all zenoh peers the zenoh session of the tunnel is directly
+    /// connected to.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The hex-encoded zenoh ids of the connected peers
+    pub fn zenoh_peer_list(&self) -> Vec<String> {
+        self.z_session
+            .info()
+            .peers_zid()
+            .wait()
+            .map(|zid| zid.to_string())
+            .collect()
+    }
+
+    /// Checks whether the zenoh session of the tunnel is able to communicate, without
+    /// querying the zenoh network, e.g. to be called before every propagation.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the session is open and, in client mode, connected to a zenoh router
+    /// * `false` - If the session is closed or, in client mode, the zenoh router disconnected
+    pub fn is_zenoh_session_alive(&self) -> bool {
+        if self.z_session.is_closed() {
+            return false;
+        }
+
+        // Sessions in client mode communicate exclusively via zenoh routers
+        let is_client_mode = self
+            .z_config
+            .get_json("mode")
+            .is_ok_and(|mode| mode.contains("client"));
+        if is_client_mode {
+            return self.z_session.info().routers_zid().wait().next().is_some();
+        }
+
+        true
+    }
+
+    /// Replaces the zenoh session of the tunnel with a new session, e.g. after the connection
+    /// to the zenoh router was lost, and recreates the connections of all tunneled services
+    /// on the new session. Remote services are discovered anew afterwards.
+    ///
+    /// The [`TunnelConfig::zenoh_session_options`] are applied to the provided zenoh
+    /// configuration. The statistics and settings of the connections are reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `z_config` - The zenoh configuration of the new session
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the new session was opened
+    /// * `Err(CreationError)` - If the new session could not be opened, the tunnel keeps the
+    ///   previous session in this case
+    pub fn reconnect(&mut self, z_config: &ZenohConfig) -> Result<(), CreationError> {
+        info!("{}RECONNECTING Zenoh Tunnel", self.log_context);
+
+        let mut z_config = z_config.clone();
+        self.z_session_options.apply(&mut z_config)?;
+
+        let z_session = zenoh::open(z_config.clone()).wait().map_err(|e| {
+            CreationError::ZenohSessionOpenFailed {
+                reason: e.to_string(),
+            }
+        })?;
+        let z_discovery_key_expression = self.z_discovery.key_expression();
+        let z_discovery_mode = self.z_discovery.mode();
+        let z_discovery = match self.z_discovery.scouting_endpoint() {
+            Some(endpoint) => ZenohDiscovery::with_scouting_endpoint(
+                endpoint,
+                &z_discovery_key_expression,
+                z_discovery_mode,
+            ),
+            None => {
+                ZenohDiscovery::create(&z_session, &z_discovery_key_expression, z_discovery_mode)
+            }
+        }
+        .map_err(|e| CreationError::ZenohDiscoveryCreationFailed {
+            reason: e.to_string(),
+        })?;
+
+        let services: Vec<(IceoryxServiceId, IceoryxServiceConfig)> = self
+            .publish_subscribe_connectons
+            .iter()
+            .map(|(id, connection)| (id.clone(), connection.service_config().clone()))
+            .chain(
+                self.event_connections
+                    .iter()
+                    .map(|(id, connection)| (id.clone(), connection.service_config().clone())),
+            )
+            .chain(
+                self.request_response_connections
+                    .iter()
+                    .map(|(id, connection)| (id.clone(), connection.service_config().clone())),
+            )
+            .collect();
+
+        // The connections undeclare their zenoh entities, which requires the previous session
+        self.publish_subscribe_connectons.clear();
+        self.event_connections.clear();
+        self.request_response_connections.clear();
+        self.service_ids_by_name.clear();
+
+        self.z_discovery = z_discovery;
+        let previous_z_session = core::mem::replace(&mut self.z_session, z_session);
+        // The liveliness token is declared on the new session first, so that the standby
+        // tunnel of a hot-standby pair does not take over in between
+        if let Some((z_ha_key, _)) = self.z_ha_token.take() {
+            if let Err(e) = self.declare_ha_token(z_ha_key) {
+                warn!(
+                    "{}Failed to declare the liveliness token of the primary tunnel: {}",
+                    self.log_context, e
+                );
+            }
+        }
+        if let Err(e) = previous_z_session.close().wait() {
+            warn!(
+                "{}Failed to close previous zenoh session: {}",
+                self.log_context, e
+            );
+        }
+        self.z_config = z_config;
+        self.z_session_id = OnceLock::new();
+
+        // The services are connected again on the new session like failed services
+        for (id, iox_service_config) in services {
+            self.connection_callbacks.removed(&id);
+            let source = self.service_sources.remove(&id).unwrap_or(Scope::Iceoryx);
+            self.failed_services
+                .insert(id, (source, iox_service_config));
+        }
+        self.retry_failed_services();
+
+        if let Err(e) = self.run_discovery(Scope::Zenoh) {
+            warn!(
+                "{}Failed to discover remote services after reconnecting: {}",
+                self.log_context, e
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Determines whether the iceoryx2 node of the tunnel is still registered as alive, e.g.
+    /// to detect that its resources were cleaned up by another process.
+    ///
+    /// # Returns
+    ///
+    /// * `IceoryxNodeHealth` - The liveness of the node
+    pub fn check_iceoryx_node_health(&self) -> IceoryxNodeHealth {
+        let iox_node_id = *self.iox_node.id();
+
+        let mut health = IceoryxNodeHealth::Stale;
+        let result = IceoryxNode::<Service>::list(self.iox_node.config(), |iox_node_state| {
+            if *iox_node_state.node_id() != iox_node_id {
+                return CallbackProgression::Continue;
+            }
+
+            health = match iox_node_state {
+                NodeState::Alive(_) => IceoryxNodeHealth::Alive,
+                NodeState::Dead(_) => IceoryxNodeHealth::Stale,
+                NodeState::Inaccessible(_) | NodeState::Undefined(_) => IceoryxNodeHealth::Unknown,
+            };
+            CallbackProgression::Stop
+        });
+
+        match result {
+            Ok(()) => health,
+            Err(_) => IceoryxNodeHealth::Unknown,
+        }
+    }
+
+    /// Replaces the iceoryx2 node and the zenoh session of the tunnel with new ones, e.g.
+    /// after [`Tunnel::check_iceoryx_node_health()`] reported a stale node, and recreates the
+    /// connections of all tunneled services on them like [`Tunnel::reconnect()`].
+    ///
+    /// The new session uses the zenoh configuration of the current session. The discovery
+    /// of local services is kept.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the new node and session were created
+    /// * `Err(CreationError)` - If the new node or session could not be created, the tunnel
+    ///   keeps the previous node and session in this case
+    pub fn reconnect_all(&mut self) -> Result<(), CreationError> {
+        info!("{}RECONNECTING iceoryx2 Node", self.log_context);
+
+        let iox_node = NodeBuilder::new()
+            .config(self.iox_node.config())
+            .create::<Service>()
+            .map_err(CreationError::IceoryxNodeCreationFailed)?;
+        let previous_iox_node = core::mem::replace(&mut self.iox_node, iox_node);
+
+        let z_config = self.z_config.clone();
+        if let Err(e) = self.reconnect(&z_config) {
+            self.iox_node = previous_iox_node;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// Attaches a context to all subsequent log messages of the tunnel, e.g. to tell apart
+    /// the messages of multiple tunnels in the same process.
+    ///
+    /// The key-value pairs are prepended to each message as `key=value`, ordered by key.
+    /// With the `tracing` feature, they are also recorded as the `log_context` field of the
+    /// spans of the discoveries and propagations. An empty context removes a previously
+    /// attached one.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The key-value pairs to prepend to the log messages
+    pub fn with_logging_context(&mut self, ctx: HashMap<&'static str, String>) {
+        self.log_context = LogContext::new(&ctx);
+    }
+
+    /// Propagates payloads between all connected hosts like [`Tunnel::propagate()`], but
+    /// reconnects to zenoh according to the configured [`TunnelConfig::reconnect_policy`]
+    /// first when the zenoh session was lost.
+    ///
+    /// The tunnel blocks between two reconnection attempts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PropagationReport)` - The outcome of the propagation over all connections
+    /// * `Err(CreationError)` - The failure of the last reconnection attempt, if the zenoh
+    ///   session was lost and all attempts failed
+    pub fn propagate_with_reconnect(&mut self) -> Result<PropagationReport, CreationError> {
+        if let ReconnectPolicy::ExponentialBackoff {
+            initial_delay,
+            max_delay,
+            max_attempts,
+        } = self.reconnect_policy
+        {
+            if !self.is_zenoh_session_alive() {
+                let z_config = self.z_config.clone();
+                let mut delay = initial_delay;
+                let mut attempt = 1;
+                while let Err(e) = self.reconnect(&z_config) {
+                    if attempt >= max_attempts {
+                        return Err(e);
+                    }
+
+                    warn!(
+                        "{}Failed to reconnect to zenoh (attempt {} of {}), retrying in {:?}: {}",
+                        self.log_context, attempt, max_attempts, delay, e
+                    );
+                    std::thread::sleep(delay);
+                    delay = (delay * 2).min(max_delay);
+                    attempt += 1;
+                }
+            }
+        }
+
+        Ok(self.propagate())
+    }
+
+    /// Repeatedly discovers services in the provided scope and propagates over all
+    /// connections once per tick, forever. A lost zenoh session is reconnected according to
+    /// the configured [`TunnelConfig::reconnect_policy`] before propagating. Failures are
+    /// handled according to the configured [`TunnelConfig::run_error_policy`].
+    ///
+    /// An iteration that overruns its tick skips the discovery of the following iteration,
+    /// so that slow discoveries do not starve the propagation. Use
+    /// [`TunnelConfig::min_discovery_interval`] to discover less often than propagating, since
+    /// discovering scans all services, whereas propagating only drains queues.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the scope of the discoveries
+    /// * `tick` - The interval between the start of two iterations
+    ///
+    /// # Returns
+    ///
+    /// * `Err(RunError)` - The failure that stopped the tunnel with [`RunErrorPolicy::Abort`]
+    pub fn run(&mut self, scope: Scope, tick: Duration) -> Result<Infallible, RunError> {
+        let mut discovery_due = true;
+        loop {
+            discovery_due = self.run_tick(scope, tick, discovery_due)?;
+        }
+    }
+
+    /// Runs the tunnel like [`Tunnel::run()`] until the provided token is cancelled. The
+    /// token is checked once per tick.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the scope of the discoveries
+    /// * `tick` - The interval between the start of two iterations
+    /// * `token` - Stops the tunnel once cancelled
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the token was cancelled
+    /// * `Err(RunError)` - The failure that stopped the tunnel with [`RunErrorPolicy::Abort`]
+    pub fn run_until_cancelled(
+        &mut self,
+        scope: Scope,
+        tick: Duration,
+        token: &CancellationToken,
+    ) -> Result<(), RunError> {
+        let mut discovery_due = true;
+        while !token.is_cancelled() {
+            discovery_due = self.run_tick(scope, tick, discovery_due)?;
+        }
+
+        Ok(())
+    }
+
+    /// Blocks until a service with the provided name is tunneled, e.g. to sequence the startup
+    /// of an application after a remote service became available.
+    ///
+    /// The services are discovered in [`Scope::Both`] with increasing delays according to the
+    /// configured [`TunnelConfig::wait_backoff`] until the service is found or the timeout
+    /// elapsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the awaited service
+    /// * `timeout` - The maximum duration to wait for the service
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IceoryxServiceId)` - The id of the first tunneled service with the provided name
+    /// * `Err(WaitError::Timeout)` - If no such service was discovered within the timeout
+    /// * `Err(WaitError::DiscoveryFailed)` - If a discovery failed
+    pub fn wait_for_service(
+        &mut self,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<IceoryxServiceId, WaitError> {
+        let deadline = Instant::now() + timeout;
+        let mut delay = self.wait_backoff.initial_delay;
+        loop {
+            self.discover(Scope::Both)
+                .map_err(WaitError::DiscoveryFailed)?;
+            if let Some(id) = self.service_ids_by_name.get(name) {
+                return Ok(id.clone());
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(WaitError::Timeout);
+            }
+            std::thread::sleep(delay.min(remaining));
+            delay = delay
+                .saturating_mul(self.wait_backoff.multiplier)
+                .min(self.wait_backoff.max_delay);
+        }
+    }
+
+    /// Performs a single iteration of [`Tunnel::run()`] and waits for the remainder of the
+    /// tick. Returns whether the next iteration shall discover.
+    fn run_tick(&mut self, scope: Scope, tick: Duration, discover: bool) -> Result<bool, RunError> {
+        let deadline = Instant::now() + tick;
+
+        if discover {
+            if let Err(e) = self.discover(scope) {
+                match self.run_error_policy {
+                    RunErrorPolicy::LogAndContinue => {
+                        warn!("{}Failed to discover services: {}", self.log_context, e)
+                    }
+                    RunErrorPolicy::Abort => return Err(RunError::DiscoveryFailed(e)),
+                }
+            }
+        }
+
+        let report = match self.propagate_with_reconnect() {
+            Ok(report) => report,
+            Err(e) => match self.run_error_policy {
+                RunErrorPolicy::LogAndContinue => {
+                    warn!("{}Failed to reconnect to zenoh: {}", self.log_context, e);
+                    PropagationReport::default()
+                }
+                RunErrorPolicy::Abort => return Err(RunError::ReconnectFailed),
+            },
+        };
+        if let Some(e) = report.failures().first() {
+            match self.run_error_policy {
+                RunErrorPolicy::LogAndContinue => warn!(
+                    "{}Failed to propagate over {} connections, e.g. {}: {}",
+                    self.log_context,
+                    report.failures().len(),
+                    e.service_id().as_str(),
+                    e.error()
+                ),
+                RunErrorPolicy::Abort => return Err(RunError::PropagationFailed(e.error())),
+            }
+        }
+
+        let now = Instant::now();
+        std::thread::sleep(deadline.saturating_duration_since(now));
+
+        Ok(now <= deadline)
+    }
+
+    /// Verifies that the services tunneled locally are announced on the zenoh network.
+    ///
+    /// The announcements are queried via the zenoh session of the tunnel on the discovery key
+    /// expression, see [`Tunnel::set_discovery_key_expression()`], either from each service
+    /// or from the registry of each tunnel depending on the [`DiscoveryMode`]. The queries are
+    /// repeated until all local services were found or the timeout elapsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum duration to wait for the announcements
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CrossCheckReport)` - The comparison of the local and the announced services
+    /// * `Err(CrossCheckError)` - If the zenoh network could not be queried
+    pub fn cross_check_with_zenoh(
+        &self,
+        timeout: Duration,
+    ) -> Result<CrossCheckReport, CrossCheckError> {
+        let deadline = Instant::now() + timeout;
+        let local_services: HashSet<String> = self.tunneled_service_ids().into_iter().collect();
+        let mut announced_services: HashSet<String> = HashSet::new();
+
+        let z_key = self.z_discovery.key_expression();
+        loop {
+            // The own announcements are answered as well, so that they are verified alike
+            let z_replies = self
+                .z_session
+                .get(z_key.as_str())
+                .allowed_destination(Locality::Any)
+                .timeout(deadline.saturating_duration_since(Instant::now()))
+                .wait()
+                .map_err(|_e| CrossCheckError::Error)?;
+            while let Ok(z_reply) = z_replies.recv() {
+                let Ok(z_sample) = z_reply.result() else {
+                    continue;
+                };
+                let z_payload = z_sample.payload().to_bytes();
+                let iox_service_configs = match self.z_discovery.mode() {
+                    DiscoveryMode::PerService => {
+                        serde_json::from_slice::<IceoryxServiceConfig>(&z_payload)
+                            .map(|iox_service_config| vec![iox_service_config])
+                    }
+                    DiscoveryMode::Queryable => {
+                        serde_json::from_slice::<Vec<IceoryxServiceConfig>>(&z_payload)
+                    }
+                };
+                // Malformed announcements of other tunnels are ignored
+                for iox_service_config in iox_service_configs.unwrap_or_default() {
+                    announced_services.insert(iox_service_config.service_id().as_str().to_string());
+                }
+            }
+
+            if local_services.is_subset(&announced_services) || Instant::now() >= deadline {
+                break;
+            }
+            std::thread::sleep(CROSS_CHECK_RETRY_PERIOD);
+        }
+
+        Ok(CrossCheckReport {
+            synchronized: local_services
+                .intersection(&announced_services)
+                .cloned()
+                .collect(),
+            missing_from_zenoh: local_services
+                .difference(&announced_services)
+                .cloned()
+                .collect(),
+            unknown_locally: announced_services
+                .difference(&local_services)
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Announces the optional features supported by the tunnel to remote tunnels, e.g. to
+    /// negotiate compression before exchanging payloads.
+    ///
+    /// The [`TunnelCapabilities`] are published as JSON on `<key_prefix>/<zenoh session id>`
+    /// and answered on queries of this key until the returned handle is dropped, so that
+    /// remote tunnels started later can query the capabilities via `<key_prefix>/*`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_prefix` - The zenoh key expression prefix to announce the capabilities on
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CapabilityHandle)` - The handle keeping the announcement alive
+    /// * `Err(AnnounceError::InvalidKeyExpression)` - If the key prefix is not a valid zenoh
+    ///   key expression
+    /// * `Err(AnnounceError::PublicationFailed)` - If the capabilities could not be published
+    pub fn announce_tunnel_capabilities(
+        &self,
+        key_prefix: &str,
+    ) -> Result<CapabilityHandle, AnnounceError> {
+        let key = format!(
+            "{}/{}",
+            key_prefix.trim_end_matches('/'),
+            self.zenoh_session_id()
+        );
+        let z_key =
+            KeyExpr::try_from(key.clone()).map_err(|_e| AnnounceError::InvalidKeyExpression)?;
+        let capabilities = TunnelCapabilities::default();
+        let capabilities_json = capabilities.to_json();
+
+        info!(
+            "{}ANNOUNCING(zenoh): Capabilities on {}: {}",
+            self.log_context, key, capabilities_json
+        );
+
+        self.z_session
+            .put(z_key.clone(), capabilities_json.clone())
+            .allowed_destination(Locality::Remote)
+            .wait()
+            .map_err(|_e| AnnounceError::PublicationFailed)?;
+
+        let z_reply_key = z_key.clone();
+        let z_queryable = self
+            .z_session
+            .declare_queryable(z_key)
+            .callback(move |z_query| {
+                if let Err(e) = z_query
+                    .reply(z_reply_key.clone(), capabilities_json.clone())
+                    .wait()
+                {
+                    error!("Failed to reply to capabilities query: {}", e);
+                }
+            })
+            .allowed_origin(Locality::Remote)
+            .wait()
+            .map_err(|_e| AnnounceError::PublicationFailed)?;
+
+        Ok(CapabilityHandle {
+            _z_queryable: z_queryable,
+            key,
+            capabilities,
+        })
+    }
+
+    /// Sends a payload through the tunnel in both directions for a tunneled publish-subscribe
+    /// service, e.g. to smoke-test a new deployment without writing test code.
+    ///
+    /// The payload is published on iceoryx and received on zenoh, then published on zenoh
+    /// and received on iceoryx, via a temporary iceoryx node and zenoh session. The tunnel is
+    /// propagated while waiting for the payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `service_name` - The name of the tunneled publish-subscribe service
+    /// * `payload` - The raw payload, a multiple of the payload size of the service
+    /// * `timeout` - The maximum duration to wait for the payload in each direction
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RoundtripResult)` - The latencies of both directions and whether the payload
+    ///   was received unaltered
+    /// * `Err(RoundtripError)` - If the service is unknown, the payload is invalid or was not
+    ///   received within the timeout
+    pub fn test_message_roundtrip(
+        &self,
+        service_name: &str,
+        payload: Vec<u8>,
+        timeout: Duration,
+    ) -> Result<RoundtripResult, RoundtripError> {
+        let connection = self
+            .publish_subscribe_connectons
+            .values()
+            .find(|connection| connection.service_config().name().as_str() == service_name)
+            .ok_or(RoundtripError::ServiceNotFound)?;
+        let iox_service_config = connection.service_config();
+
+        let iox_payload_size = iox_service_config
+            .publish_subscribe()
+            .message_type_details()
+            .payload
+            .size;
+        if payload.is_empty() || iox_payload_size == 0 || payload.len() % iox_payload_size != 0 {
+            return Err(RoundtripError::InvalidPayload);
+        }
+
+        let iox_node = NodeBuilder::new()
+            .config(self.iox_node.config())
+            .create::<Service>()
+            .map_err(|_e| RoundtripError::Error)?;
+        let iox_service = iox_create_publish_subscribe_service::<Service>(
+            &iox_node,
+            iox_service_config,
+            self.iox_service_limits,
+        )
+        .map_err(|_e| RoundtripError::Error)?;
+        let iox_publisher = iox_create_publisher::<Service>(&iox_service, iox_service_config)
+            .map_err(|_e| RoundtripError::Error)?;
+        let iox_subscriber = iox_create_subscriber::<Service>(&iox_service, iox_service_config)
+            .map_err(|_e| RoundtripError::Error)?;
+
+        let z_key = z_key_expression(iox_service_config, self.key_expr_mapper.as_deref());
+        let z_session = zenoh::open(self.z_config.clone())
+            .wait()
+            .map_err(|_e| RoundtripError::Error)?;
+        let z_subscriber = z_session
+            .declare_subscriber(z_key.clone())
+            .allowed_origin(Locality::Remote)
+            .wait()
+            .map_err(|_e| RoundtripError::Error)?;
+        let z_publisher = z_session
+            .declare_publisher(z_key)
+            .allowed_destination(Locality::Remote)
+            .wait()
+            .map_err(|_e| RoundtripError::Error)?;
+
+        // iceoryx -> zenoh
+        let deadline = Instant::now() + timeout;
+        let (iceoryx_to_zenoh_latency, received_on_zenoh) = loop {
+            let sent_at = Instant::now();
+            iox_send_payload(&iox_publisher, &payload, iox_payload_size)?;
+            self.propagate();
+
+            if let Ok(Some(z_sample)) = z_subscriber.recv_timeout(ROUNDTRIP_RETRY_PERIOD) {
+                // The payload is decoded as the receiving tunnel would
+                let received = connection
+                    .decode_payload(z_sample.payload())
+                    .ok_or(RoundtripError::Error)?
+                    .map_err(|_e| RoundtripError::Error)?;
+                break (sent_at.elapsed(), received);
+            }
+
+            if Instant::now() >= deadline {
+                return Err(RoundtripError::Timeout);
+            }
+        };
+
+        // The payloads sent on iceoryx are received by the own subscriber as well
+        while let Ok(Some(_)) = unsafe { iox_subscriber.receive_custom_payload() } {}
+
+        // zenoh -> iceoryx
+        let deadline = Instant::now() + timeout;
+        let (zenoh_to_iceoryx_latency, received_on_iceoryx) = 'roundtrip: loop {
+            let sent_at = Instant::now();
+            // The payload is encoded as a sending tunnel would
+            let z_payload = connection
+                .encode_payload(&payload)
+                .ok_or(RoundtripError::Error)?;
+            z_publisher
+                .put(z_payload)
+                .wait()
+                .map_err(|_e| RoundtripError::Error)?;
+
+            let retry_at = sent_at + ROUNDTRIP_RETRY_PERIOD;
+            while Instant::now() < retry_at {
+                self.propagate();
+                if let Some(received) = iox_receive_payload(&iox_subscriber)? {
+                    break 'roundtrip (sent_at.elapsed(), received);
+                }
+                std::thread::yield_now();
+            }
+
+            if Instant::now() >= deadline {
+                return Err(RoundtripError::Timeout);
+            }
+        };
+
+        Ok(RoundtripResult {
+            iceoryx_to_zenoh_latency,
+            zenoh_to_iceoryx_latency,
+            payload_matches: received_on_zenoh == payload && received_on_iceoryx == payload,
+        })
+    }
+
+    /// Returns a list of all service IDs that are currently being tunneled.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - A vector containing the string representation of all service IDs
+    ///   that are currently being tunneled through this tunnel instance.
+    #[deprecated(note = "use `Tunnel::tunneled_service_infos()` instead")]
+    pub fn tunneled_services(&self) -> Vec<String> {
+        self.tunneled_service_ids()
+    }
+
+    /// Returns the details of all services that are currently being tunneled, e.g. to display
+    /// them without discovering them again.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<TunneledServiceInfo>` - The id, name, messaging pattern and discovery scope of
+    ///   every tunneled service
+    pub fn tunneled_service_infos(&self) -> Vec<TunneledServiceInfo> {
+        let services = self
+            .publish_subscribe_connectons
+            .iter()
+            .map(|(id, connection)| {
+                (
+                    id,
+                    connection.service_config(),
+                    MessagingPatternKind::PublishSubscribe,
+                )
+            })
+            .chain(self.event_connections.iter().map(|(id, connection)| {
+                (id, connection.service_config(), MessagingPatternKind::Event)
+            }))
+            .chain(
+                self.request_response_connections
+                    .iter()
+                    .map(|(id, connection)| {
+                        (
+                            id,
+                            connection.service_config(),
+                            MessagingPatternKind::RequestResponse,
+                        )
+                    }),
+            );
+
+        services
+            .map(|(id, iox_service_config, pattern)| TunneledServiceInfo {
+                service_id: id.clone(),
+                service_name: iox_service_config.name().to_string(),
+                pattern,
+                discovery_scope: self
+                    .service_sources
+                    .get(id)
+                    .copied()
+                    .unwrap_or(Scope::Iceoryx),
+            })
+            .collect()
+    }
+
+    /// Returns the names of all tunneled services in alphabetical order, e.g. for a display
+    /// that is stable across runs. Services of different messaging patterns sharing a name
+    /// are listed once per service.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The sorted names of all tunneled services
+    pub fn service_names_sorted(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| connection.service_config().name().to_string())
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| connection.service_config().name().to_string()),
+            )
+            .chain(
+                self.request_response_connections
+                    .values()
+                    .map(|connection| connection.service_config().name().to_string()),
+            )
+            .collect();
+        names.sort_unstable();
+
+        names
+    }
+
+    fn tunneled_service_ids(&self) -> Vec<String> {
+        self.publish_subscribe_connectons
+            .keys()
+            .chain(self.event_connections.keys())
+            .chain(self.request_response_connections.keys())
+            .map(|id| id.as_str().to_string())
+            .collect()
+    }
+
+    /// Returns the ids of all tunneled services as a JSON array of strings, e.g.
+    /// `["id1","id2"]`, for diagnostic endpoints on targets without `serde`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The JSON array of the tunneled service ids
+    pub fn service_ids_as_json_array(&self) -> String {
+        let mut json = String::from("[");
+        for (n, id) in self.tunneled_service_ids().iter().enumerate() {
+            if n > 0 {
+                json.push(',');
+            }
+            push_json_string(&mut json, id);
+        }
+        json.push(']');
+
+        json
+    }
+
+    /// Returns the number of tunneled publish-subscribe services without allocating, e.g. for
+    /// monitoring at high frequency.
+    #[inline]
+    pub fn num_total_publish_subscribe_connections(&self) -> usize {
+        self.publish_subscribe_connectons.len()
+    }
+
+    /// Returns the number of tunneled event services without allocating, e.g. for monitoring
+    /// at high frequency.
+    #[inline]
+    pub fn num_total_event_connections(&self) -> usize {
+        self.event_connections.len()
+    }
+
+    /// Exports the list of all tunneled services as a JSON array.
+    ///
+    /// Each entry contains the `id`, `name` and messaging `pattern` of the service, the
+    /// point in time the connection was established as `connected_at_iso8601`, the number of
+    /// payloads forwarded in each direction as `messages_forwarded_iox_to_zenoh` and
+    /// `messages_forwarded_zenoh_to_iox`, and the most recent propagation error as
+    /// `last_error`, which is `null` if no propagation failed.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The JSON array of the tunneled services
+    #[cfg(feature = "serde")]
+    pub fn export_service_list_json(&self) -> String {
+        let entries: Vec<ServiceListEntry> = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| {
+                ServiceListEntry::new(connection.service_config(), connection.statistics())
+            })
+            .chain(self.event_connections.values().map(|connection| {
+                ServiceListEntry::new(connection.service_config(), connection.statistics())
+            }))
+            .collect();
+
+        // Serializing plain strings and integers cannot fail.
+        serde_json::to_string(&entries).unwrap()
+    }
+
+    /// Exports the lifecycle of the connections of the tunnel as a state machine diagram, e.g.
+    /// for documentation or to relate the log output of the tunnel to the connection states.
+    ///
+    /// A connection is created when its service is discovered and is active until its
+    /// propagation is paused, it is recreated or it is closed. The diagram describes the
+    /// lifecycle common to all connections, not the current state of each connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The format of the diagram
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The state machine diagram
+    pub fn export_connection_state_machine_diagram(&self, format: DiagramFormat) -> String {
+        let (header, footer, separator) = match format {
+            DiagramFormat::Mermaid => ("stateDiagram-v2\n", "", ": "),
+            DiagramFormat::PlantUml => ("@startuml\n", "@enduml\n", " : "),
+        };
+
+        let mut diagram = String::from(header);
+        for (from, to, trigger) in CONNECTION_STATE_TRANSITIONS {
+            diagram.push_str(&format!("    {from} --> {to}{separator}{trigger}\n"));
+        }
+        diagram.push_str(footer);
+
+        diagram
+    }
+
+    /// Exports the statistics of all tunneled services as CSV, with a header row followed by
+    /// one row per service.
+    ///
+    /// The columns are `service_id`, `service_name`, `pattern`, `messages_iox_to_zenoh`,
+    /// `messages_zenoh_to_iox`, `bytes_iox_to_zenoh`, `bytes_zenoh_to_iox`, `errors` and
+    /// `uptime_seconds`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The CSV formatted statistics
+    pub fn service_stats_to_csv(&self) -> String {
+        let mut csv = String::from("service_id,service_name,pattern,messages_iox_to_zenoh,messages_zenoh_to_iox,bytes_iox_to_zenoh,bytes_zenoh_to_iox,errors,uptime_seconds\n");
+
+        let rows = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| (connection.service_config(), connection.statistics()))
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| (connection.service_config(), connection.statistics())),
+            );
+        for (iox_service_config, statistics) in rows {
+            csv.push_str(&csv_row(iox_service_config, statistics));
+        }
+
+        csv
+    }
+
+    /// Returns a human-readable summary of all active connections and the services for which
+    /// no connection could be created.
+    ///
+    /// Healthy connections are highlighted in green, connections with errors and failed
+    /// services in red, depending on [`TunnelConfig::use_color`].
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The summary with one line per service
+    pub fn diagnostic_report(&self) -> String {
+        let (green, red, reset) = if self.use_color {
+            ("\x1b[0;32m", "\x1b[0;31m", "\x1b[0m")
+        } else {
+            ("", "", "")
+        };
+
+        let mut report = format!(
+            "Tunnel {}: {} connections, {} failed services\n",
+            self.zenoh_session_id(),
+            self.publish_subscribe_connectons.len() + self.event_connections.len(),
+            self.failed_services.len()
+        );
+
+        let connections = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| (connection.service_config(), connection.statistics()))
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| (connection.service_config(), connection.statistics())),
+            );
+        for (iox_service_config, statistics) in connections {
+            let (color, status) = if statistics.errors() == 0 {
+                (green, "OK")
+            } else {
+                (red, "ERRORS")
+            };
+            report.push_str(&format!(
+                "{color}{status}{reset} {} [{}] {}: {} forwarded, {} errors\n",
+                iox_service_config.service_id().as_str(),
+                iox_service_config.name(),
+                iox_service_config.messaging_pattern(),
+                statistics.forwarded_iceoryx_to_zenoh() + statistics.forwarded_zenoh_to_iceoryx(),
+                statistics.errors()
+            ));
+        }
+
+        for (iox_service_id, (_, iox_service_config)) in &self.failed_services {
+            report.push_str(&format!(
+                "{red}FAILED{reset} {} [{}]\n",
+                iox_service_id.as_str(),
+                iox_service_config.name()
+            ));
+        }
+
+        report
+    }
+
+    /// Writes a human-readable summary of all active connections, their metrics and the
+    /// last error per service to stderr, for quick debugging without a monitoring setup.
+    ///
+    /// Every line is prefixed with the time since the unix epoch.
+    pub fn report_to_stderr(&self) {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        let (secs, millis) = (timestamp.as_secs(), timestamp.subsec_millis());
+
+        eprintln!(
+            "[{secs}.{millis:03}] Tunnel {}: {} connections, {} failed services",
+            self.zenoh_session_id(),
+            self.publish_subscribe_connectons.len() + self.event_connections.len(),
+            self.failed_services.len()
+        );
+
+        let connections = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| (connection.service_config(), connection.statistics()))
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| (connection.service_config(), connection.statistics())),
+            );
+        for (iox_service_config, statistics) in connections {
+            eprintln!(
+                "[{secs}.{millis:03}]   {} [{}] {}: iox->zenoh {} msgs / {} B, zenoh->iox {} msgs / {} B, {} errors, last error: {:?}",
+                iox_service_config.service_id().as_str(),
+                iox_service_config.name(),
+                iox_service_config.messaging_pattern(),
+                statistics.forwarded_iceoryx_to_zenoh(),
+                statistics.bytes_iceoryx_to_zenoh(),
+                statistics.forwarded_zenoh_to_iceoryx(),
+                statistics.bytes_zenoh_to_iceoryx(),
+                statistics.errors(),
+                statistics.last_error()
+            );
+        }
+
+        for (iox_service_id, (source, iox_service_config)) in &self.failed_services {
+            eprintln!(
+                "[{secs}.{millis:03}]   FAILED({}): {} [{}]",
+                source,
+                iox_service_id.as_str(),
+                iox_service_config.name()
+            );
+        }
+    }
+
+    /// Returns a list of all service IDs for which no connection could be created.
+    ///
+    /// Services are only recorded as failed when the `strict-errors` feature is enabled,
+    /// otherwise a failure to create a connection panics.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - A vector containing the string representation of all service IDs
+    ///   that failed to be tunneled through this tunnel instance.
+    pub fn failed_services(&self) -> Vec<String> {
+        self.failed_services
+            .keys()
+            .map(|id| id.as_str().to_string())
+            .collect()
+    }
+
+    /// Returns the number of services for which no connection could be created.
+    pub fn failed_service_count(&self) -> usize {
+        self.failed_services.len()
+    }
+
+    /// Asserts that a connection could be created for every discovered service, e.g. in
+    /// integration tests.
+    ///
+    /// # Panics
+    ///
+    /// If connections failed to be created, listing the failed services.
+    #[track_caller]
+    pub fn assert_no_failed_services(&self) {
+        if self.failed_services.is_empty() {
+            return;
+        }
+
+        let failed_services: Vec<String> = self
+            .failed_services
+            .iter()
+            .map(|(id, (source, iox_service_config))| {
+                format!(
+                    "{} [{}] discovered via {}",
+                    id.as_str(),
+                    iox_service_config.name(),
+                    source
+                )
+            })
+            .collect();
+
+        panic!(
+            "{} services failed to be tunneled: {}",
+            failed_services.len(),
+            failed_services.join(", ")
+        );
+    }
+
+    /// Asserts the number of tunneled services, e.g. in integration tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The expected number of tunneled services
+    ///
+    /// # Panics
+    ///
+    /// If the number of tunneled services differs, listing their names.
+    #[track_caller]
+    pub fn assert_service_count(&self, expected: usize) {
+        assert_service_names_len("tunneled", expected, self.service_names_sorted());
+    }
+
+    /// Asserts the number of tunneled publish-subscribe services, e.g. in integration tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The expected number of tunneled publish-subscribe services
+    ///
+    /// # Panics
+    ///
+    /// If the number of tunneled publish-subscribe services differs, listing their names.
+    #[track_caller]
+    pub fn assert_publish_subscribe_count(&self, expected: usize) {
+        assert_service_names_len(
+            "publish-subscribe",
+            expected,
+            self.publish_subscribe_connectons
+                .values()
+                .map(|connection| connection.service_config().name().to_string())
+                .collect(),
+        );
+    }
+
+    /// Asserts the number of tunneled event services, e.g. in integration tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The expected number of tunneled event services
+    ///
+    /// # Panics
+    ///
+    /// If the number of tunneled event services differs, listing their names.
+    #[track_caller]
+    pub fn assert_event_count(&self, expected: usize) {
+        assert_service_names_len(
+            "event",
+            expected,
+            self.event_connections
+                .values()
+                .map(|connection| connection.service_config().name().to_string())
+                .collect(),
+        );
+    }
+
+    /// Forgets all services for which no connection could be created, e.g. after the cause
+    /// of the failures was remediated.
+    ///
+    /// The services are attempted to be connected again once they are discovered anew. Use
+    /// [`Tunnel::retry_failed_services()`] to attempt the connection right away instead.
+    pub fn clear_failed_services(&mut self) {
+        self.failed_services.clear();
+    }
+
+    /// Attempts to create the connections for all services for which no connection could be
+    /// created so far, without waiting for the services to be discovered anew.
+    ///
+    /// Services that still fail to connect are recorded as failed again.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of services that were connected
+    pub fn retry_failed_services(&mut self) -> usize {
+        let failed_services = core::mem::take(&mut self.failed_services);
+
+        let mut connected = 0;
+        for (source, iox_service_config) in failed_services.values() {
+            // Failures were logged and, with the `strict-errors` feature, recorded again
+            if self.attach_service(iox_service_config, *source) == Ok(true) {
+                connected += 1;
+            }
+        }
+
+        self.apply_directions();
+
+        connected
+    }
+
+    /// Creates the connection of a service whose static config is known in advance, e.g. from
+    /// a manifest, without discovering it. Services connected this way are treated like
+    /// services discovered in the provided scope.
+    ///
+    /// The service filters of the tunnel are not applied. Adding a service that is connected
+    /// already has no effect, while a service that failed to connect before is attempted
+    /// again.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The static config of the service to connect
+    /// * `scope` - The scope the service is recorded as discovered in
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the service is connected
+    /// * `Err(ConnectionCreationError::UnsupportedMessagingPattern)` - If services with the
+    ///   messaging pattern of the service are not tunneled
+    /// * `Err(ConnectionCreationError::KeyExpressionCollision)` - If the zenoh key expression
+    ///   of the service is used by the connection of another service
+    /// * `Err(ConnectionCreationError::InvalidKeyExpression)` - If the zenoh key expression of
+    ///   the service is invalid
+    /// * `Err(ConnectionCreationError::SchemaTypeMismatch)` - If the message types of the
+    ///   service differ from the schema registry
+    /// * `Err(ConnectionCreationError::UnknownToSchemaRegistry)` - If the service is unknown
+    ///   to the schema registry and unknown services are rejected
+    /// * `Err(ConnectionCreationError::Error)` - If the connection could not be created
+    pub fn add_connection_for_service(
+        &mut self,
+        config: &IceoryxServiceConfig,
+        scope: Scope,
+    ) -> Result<(), ConnectionCreationError> {
+        let iox_service_id = config.service_id();
+        if self
+            .publish_subscribe_connectons
+            .contains_key(iox_service_id)
+            || self.event_connections.contains_key(iox_service_id)
+            || self
+                .request_response_connections
+                .contains_key(iox_service_id)
+        {
+            return Ok(());
+        }
+
+        if !matches!(
+            config.messaging_pattern(),
+            MessagingPattern::PublishSubscribe(_)
+                | MessagingPattern::Event(_)
+                | MessagingPattern::RequestResponse(_)
+        ) {
+            return Err(ConnectionCreationError::UnsupportedMessagingPattern);
+        }
+
+        // The same checks as during discovery, which only logs why a service is skipped
+        check_key_expression_collision(
+            config,
+            self.key_expr_mapper.as_deref(),
+            &self.publish_subscribe_connectons,
+            &self.event_connections,
+            &self.request_response_connections,
+        )
+        .map_err(|_| ConnectionCreationError::KeyExpressionCollision)?;
+        validate_z_key_expression(config, self.key_expr_mapper.as_deref())
+            .map_err(|_| ConnectionCreationError::InvalidKeyExpression)?;
+        validate_schema(
+            config,
+            self.schema_registry.as_deref(),
+            self.reject_unknown_services,
+        )
+        .map_err(|e| match e {
+            SchemaViolation::TypeMismatch => ConnectionCreationError::SchemaTypeMismatch,
+            SchemaViolation::UnknownService => ConnectionCreationError::UnknownToSchemaRegistry,
+        })?;
+
+        self.failed_services.remove(iox_service_id);
+        let result = self.attach_service(config, scope);
+        self.apply_directions();
+
+        match result {
+            Ok(true) => Ok(()),
+            // With the `strict-errors` feature, failures are recorded instead of returned
+            Ok(false) | Err(_) => Err(ConnectionCreationError::Error),
+        }
+    }
+
+    /// Captures the static configs of all tunneled services along with the scope in which
+    /// they were discovered, e.g. to persist them before a planned restart.
+    ///
+    /// # Returns
+    ///
+    /// * `TunnelSnapshot` - The tunneled services, ordered by service id
+    pub fn snapshot(&self) -> TunnelSnapshot {
+        let mut services: Vec<(IceoryxServiceConfig, Scope)> = self
+            .service_configs()
+            .into_iter()
+            .map(|iox_service_config| {
+                let scope = self
+                    .service_sources
+                    .get(iox_service_config.service_id())
+                    .copied()
+                    .unwrap_or(Scope::None);
+                (iox_service_config.clone(), scope)
+            })
+            .collect();
+        services
+            .sort_by(|(lhs, _), (rhs, _)| lhs.service_id().as_str().cmp(rhs.service_id().as_str()));
+
+        TunnelSnapshot { services }
+    }
+
+    /// Creates the connections of the services captured by [`Tunnel::snapshot()`], e.g.
+    /// after a planned restart, without waiting for discovery. See
+    /// [`Tunnel::add_connection_for_service()`] for how each connection is created.
+    ///
+    /// The restoration stops at the first connection that cannot be created, the
+    /// connections restored until then are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - The services to connect
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of connections created, services that are tunneled
+    ///   already are not counted
+    /// * `Err(ConnectionCreationError)` - If the connection of a service could not be created
+    pub fn restore_from_snapshot(
+        &mut self,
+        snapshot: TunnelSnapshot,
+    ) -> Result<usize, ConnectionCreationError> {
+        let mut restored = 0;
+        for (iox_service_config, scope) in snapshot.services {
+            let id = iox_service_config.service_id();
+            let is_tunneled = self.publish_subscribe_connectons.contains_key(id)
+                || self.event_connections.contains_key(id)
+                || self.request_response_connections.contains_key(id);
+            if is_tunneled {
+                continue;
+            }
+
+            self.add_connection_for_service(&iox_service_config, scope)?;
+            restored += 1;
+        }
+
+        Ok(restored)
+    }
+
+    /// Validates the message types of all services against the provided schema registry, e.g.
+    /// in deployments that require strict type safety across hosts. Services whose message
+    /// types differ from the registry are not tunneled, as are the services unknown to the
+    /// registry if [`TunnelConfig::reject_unknown_services`] is set.
+    ///
+    /// The services discovered from now on are validated before their connection is created,
+    /// while the connections of tunneled services that violate the registry are removed. The
+    /// rejected services are recorded as failed services, see
+    /// [`Tunnel::failed_services()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - The schema registry replacing the previously enforced one
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If all tunneled services conform to the registry
+    /// * `Err(SchemaEnforcementError::ServicesRejected)` - If the connections of tunneled
+    ///   services were removed
+    pub fn enforce_schema_registry(
+        &mut self,
+        registry: Arc<dyn SchemaRegistry + Send + Sync>,
+    ) -> Result<(), SchemaEnforcementError> {
+        let violations: Vec<(IceoryxServiceId, SchemaViolation)> = self
+            .service_configs()
+            .into_iter()
+            .filter_map(|iox_service_config| {
+                validate_schema(
+                    iox_service_config,
+                    Some(registry.as_ref()),
+                    self.reject_unknown_services,
+                )
+                .err()
+                .map(|violation| (iox_service_config.service_id().clone(), violation))
+            })
+            .collect();
+        self.schema_registry = Some(registry);
+
+        let mut mismatched = Vec::new();
+        let mut unknown = Vec::new();
+        for (id, violation) in violations {
+            let Some((iox_service_config, source)) = self.detach_service(&id) else {
+                continue;
+            };
+            error!(
+                "{}SCHEMA VIOLATION: {} [{}]: {:?}",
+                self.log_context,
+                id.as_str(),
+                iox_service_config.name(),
+                violation
+            );
+
+            let name = iox_service_config.name().to_string();
+            match violation {
+                SchemaViolation::TypeMismatch => mismatched.push(name),
+                SchemaViolation::UnknownService => unknown.push(name),
+            }
+            self.service_filter.handed_over.remove(&id);
+            self.failed_services
+                .insert(id, (source, iox_service_config));
+        }
+
+        if mismatched.is_empty() && unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaEnforcementError::ServicesRejected {
+                mismatched,
+                unknown,
+            })
+        }
+    }
+
+    /// Creates the connection of an event service without discovering it, e.g. in
+    /// deployments without discovery where the bridged event services are statically known.
+    ///
+    /// The connection is not removed when the service is no longer discovered, its discovery
+    /// scope is reported as [`Scope::None`]. Adding a service that is connected already has
+    /// no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The static config of the event service to connect
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the service is connected
+    /// * `Err(CreationError::UnexpectedMessagingPattern)` - If the service is not an event
+    ///   service
+    /// * `Err(CreationError::KeyExpressionCollision)` - If the zenoh key expression of the
+    ///   service is used by the connection of another service
+    /// * `Err(CreationError::InvalidKeyExpression)` - If the zenoh key expression of the
+    ///   service is not valid
+    /// * `Err(CreationError::ConnectionCreationFailed)` - If the connection could not be
+    ///   created
+    pub fn add_event_connection_manually(
+        &mut self,
+        config: IceoryxServiceConfig,
+    ) -> Result<(), CreationError> {
+        if !matches!(config.messaging_pattern(), MessagingPattern::Event(_)) {
+            return Err(CreationError::UnexpectedMessagingPattern);
+        }
+
+        check_key_expression_collision(
+            &config,
+            self.key_expr_mapper.as_deref(),
+            &self.publish_subscribe_connectons,
+            &self.event_connections,
+            &self.request_response_connections,
+        )?;
+        validate_z_key_expression(&config, self.key_expr_mapper.as_deref())?;
+
+        let iox_service_id = config.service_id();
+        if self.event_connections.contains_key(iox_service_id) {
+            return Ok(());
+        }
+
+        info!(
+            "{}ADDED: Event {} [{}]",
+            self.log_context,
+            iox_service_id.as_str(),
+            config.name()
+        );
+
+        let context = ConnectionContext {
+            iox_node: &self.iox_node,
+            z_session: &self.z_session,
+            key_expr_mapper: self.key_expr_mapper.as_deref(),
+            schema_registry: self.schema_registry.as_deref(),
+            reject_unknown_services: self.reject_unknown_services,
+            connection_direction: self.connection_direction,
+            qos_mapping: self.qos_mapping,
+            backpressure_policy: self.connection_defaults.backpressure_policy,
+            z_allowed_destination: self.z_allowed_destination,
+            iox_service_limits: self.iox_service_limits,
+        };
+        let connection = BidirectionalEventConnection::create(
+            context.iox_node,
+            context.z_session,
+            &config,
+            &context.z_key_expression(&config),
+            context.z_allowed_destination,
+        )
+        .map_err(|e| {
+            error!(
+                "{}FAILED: Event {} [{}]: {:?}",
+                self.log_context,
+                iox_service_id.as_str(),
+                config.name(),
+                e
+            );
+            CreationError::ConnectionCreationFailed
+        })?;
+
+        connection.statistics().record_discovered_at(Instant::now());
+        self.event_connections
+            .insert(iox_service_id.clone(), connection);
+        self.connection_callbacks
+            .created(&config, MessagingPatternKind::Event, Scope::None);
+        self.failed_services.remove(iox_service_id);
+        self.service_filter.handed_over.remove(iox_service_id);
+        self.total_services_discovered += 1;
+        index_service_name(&mut self.service_ids_by_name, &config);
+        self.service_sources
+            .insert(iox_service_id.clone(), Scope::None);
+        apply_connection_defaults(
+            &mut self.publish_subscribe_connectons,
+            &mut self.event_connections,
+            &mut self.request_response_connections,
+            &config,
+            &self.connection_defaults,
+            &self.z_additional_sessions,
+            self.z_allowed_destination,
+        );
+        self.apply_directions();
+
+        Ok(())
+    }
+
+    /// Moves connections from the tunnels with the most connections to the tunnels with the
+    /// fewest, until the number of connections of any two tunnels differs by at most one,
+    /// e.g. to even out the load after services were added to and removed from a cluster of
+    /// tunnels.
+    ///
+    /// A moved service is no longer connected by the tunnel it was moved from when it is
+    /// discovered anew. Services are only moved to tunnels whose allowed list permits them
+    /// and that do not tunnel them already, so the tunnels may remain unbalanced.
+    ///
+    /// # Arguments
+    ///
+    /// * `tunnels` - The tunnels to rebalance, whose indices identify them in the report
+    ///
+    /// # Returns
+    ///
+    /// * `RebalanceReport` - The services that were moved between the tunnels
+    pub fn rebalance(tunnels: &mut [Tunnel<'a, Service>]) -> RebalanceReport {
+        let mut report = RebalanceReport::default();
+
+        loop {
+            let Some(busiest) = (0..tunnels.len()).max_by_key(|i| tunnels[*i].num_connections())
+            else {
+                break;
+            };
+            let Some(idlest) = (0..tunnels.len()).min_by_key(|i| tunnels[*i].num_connections())
+            else {
+                break;
+            };
+            if tunnels[busiest].num_connections() <= tunnels[idlest].num_connections() + 1 {
+                break;
+            }
+
+            let mut candidates: Vec<&IceoryxServiceConfig> = tunnels[busiest].service_configs();
+            candidates.sort_by(|lhs, rhs| lhs.service_id().as_str().cmp(rhs.service_id().as_str()));
+            let Some(id) = candidates
+                .into_iter()
+                .find(|iox_service_config| tunnels[idlest].accepts_handover(iox_service_config))
+                .map(|iox_service_config| iox_service_config.service_id().clone())
+            else {
+                break;
+            };
+
+            let Some((iox_service_config, source)) = tunnels[busiest].detach_service(&id) else {
+                break;
+            };
+            let moved = tunnels[idlest].attach_service(&iox_service_config, source) == Ok(true);
+            if !moved {
+                // Keep the service connected by the tunnel it was taken from
+                let _ = tunnels[busiest].attach_service(&iox_service_config, source);
+                tunnels[busiest].apply_directions();
+                break;
+            }
+
+            tunnels[idlest].apply_directions();
+            info!(
+                "{}REBALANCED {} [{}]: moved from tunnel {} to tunnel {}",
+                tunnels[idlest].log_context,
+                id.as_str(),
+                iox_service_config.name(),
+                busiest,
+                idlest
+            );
+            report.moved.push(RebalancedService {
+                service_id: id,
+                from: busiest,
+                to: idlest,
+            });
+        }
+
+        report
+    }
+
+    /// Moves the connections of another tunnel into this tunnel and consumes it, e.g. when a
+    /// tunnel was created to connect to a newly discovered zenoh router.
+    ///
+    /// The zenoh sessions of the other tunnel are kept open as additional sessions of this
+    /// tunnel, see [`TunnelConfig::zenoh_sessions`]. The moved connections are created anew
+    /// with the iceoryx2 node of this tunnel and are attached to all of its zenoh sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tunnel whose connections are moved
+    /// * `policy` - Determines how services tunneled by both tunnels are handled
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of services whose connections were moved
+    /// * `Err(CreationError)` - If the zenoh sessions of the other tunnel could not be adopted,
+    ///   no connection was moved
+    pub fn merge_from(
+        &mut self,
+        mut other: Tunnel<'a, Service>,
+        policy: MergePolicy,
+    ) -> Result<usize, CreationError> {
+        let z_key_expression = self.z_discovery.key_expression();
+        let z_discovery_mode = self.z_discovery.mode();
+        let labels = self.zenoh_session_labels();
+        let mut z_adopted_sessions = Vec::new();
+        for (label, z_session) in core::iter::once((other.zenoh_session_id(), &other.z_session))
+            .chain(
+                other
+                    .z_additional_sessions
+                    .iter()
+                    .map(|(label, z_session)| (label.clone(), z_session)),
+            )
+        {
+            if labels.contains(&label) {
+                continue;
+            }
+            let z_discovery =
+                ZenohDiscovery::create(z_session, &z_key_expression, z_discovery_mode).map_err(
+                    |e| CreationError::ZenohDiscoveryCreationFailed {
+                        reason: e.to_string(),
+                    },
+                )?;
+            z_adopted_sessions.push((label, z_session.clone(), z_discovery));
+        }
+        // The adopted sessions must stay open when the other tunnel is dropped
+        other.close_timeout = None;
+
+        for (label, z_session, z_discovery) in z_adopted_sessions {
+            attach_zenoh_session(
+                &mut self.publish_subscribe_connectons,
+                &mut self.event_connections,
+                &label,
+                &z_session,
+                self.z_allowed_destination,
+            );
+            self.z_additional_sessions.push((label, z_session));
+            self.z_additional_discoveries.push(z_discovery);
+        }
+
+        let mut ids: Vec<IceoryxServiceId> = other
+            .service_configs()
+            .into_iter()
+            .map(|iox_service_config| iox_service_config.service_id().clone())
+            .collect();
+        ids.sort_by(|lhs, rhs| lhs.as_str().cmp(rhs.as_str()));
+
+        let mut merged = 0;
+        for id in ids {
+            let Some((iox_service_config, source)) = other.detach_service(&id) else {
+                continue;
+            };
+            let is_tunneled = self.publish_subscribe_connectons.contains_key(&id)
+                || self.event_connections.contains_key(&id)
+                || self.request_response_connections.contains_key(&id);
+            if is_tunneled {
+                match policy {
+                    MergePolicy::Skip => continue,
+                    MergePolicy::Replace => {
+                        self.detach_service(&id);
+                    }
+                }
+            }
+
+            match self.attach_service(&iox_service_config, source) {
+                Ok(true) => {
+                    info!(
+                        "{}MERGED {} [{}]",
+                        self.log_context,
+                        id.as_str(),
+                        iox_service_config.name()
+                    );
+                    merged += 1;
+                }
+                Ok(false) => (),
+                Err(e) => warn!(
+                    "{}Failed to merge {} [{}]: {:?}",
+                    self.log_context,
+                    id.as_str(),
+                    iox_service_config.name(),
+                    e
+                ),
+            }
+        }
+        self.apply_directions();
+
+        Ok(merged)
+    }
+
+    fn num_connections(&self) -> usize {
+        self.publish_subscribe_connectons.len()
+            + self.event_connections.len()
+            + self.request_response_connections.len()
+    }
+
+    fn service_configs(&self) -> Vec<&IceoryxServiceConfig> {
+        self.publish_subscribe_connectons
+            .values()
+            .map(|connection| connection.service_config())
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| connection.service_config()),
+            )
+            .chain(
+                self.request_response_connections
+                    .values()
+                    .map(|connection| connection.service_config()),
+            )
+            .collect()
+    }
+
+    /// Returns `true` if a connection for the service handed over by another tunnel can be
+    /// created.
+    fn accepts_handover(&self, iox_service_config: &IceoryxServiceConfig) -> bool {
+        let id = iox_service_config.service_id();
+        self.service_filter.permits(iox_service_config)
+            && !self.failed_services.contains_key(id)
+            && !self.publish_subscribe_connectons.contains_key(id)
+            && !self.event_connections.contains_key(id)
+            && !self.request_response_connections.contains_key(id)
+    }
+
+    /// Creates the connection of a service outside of discovery.
+    ///
+    /// # Arguments
+    ///
+    /// * `iox_service_config` - The static config of the service to connect
+    /// * `source` - The scope in which the service was discovered
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If the connection was created
+    /// * `Ok(false)` - If the service is connected already or was skipped
+    /// * `Err(DiscoveryError)` - If the connection could not be created
+    fn attach_service(
+        &mut self,
+        iox_service_config: &IceoryxServiceConfig,
+        source: Scope,
+    ) -> Result<bool, DiscoveryError> {
+        self.service_filter
+            .handed_over
+            .remove(iox_service_config.service_id());
+
+        let context = ConnectionContext {
+            iox_node: &self.iox_node,
+            z_session: &self.z_session,
+            key_expr_mapper: self.key_expr_mapper.as_deref(),
+            schema_registry: self.schema_registry.as_deref(),
+            reject_unknown_services: self.reject_unknown_services,
+            connection_direction: self.connection_direction,
+            qos_mapping: self.qos_mapping,
+            backpressure_policy: self.connection_defaults.backpressure_policy,
+            z_allowed_destination: self.z_allowed_destination,
+            iox_service_limits: self.iox_service_limits,
+        };
+        let result = match on_discovery(
+            DiscoverySource {
+                scope: source,
+                log_context: &self.log_context,
+                callbacks: &self.connection_callbacks,
+            },
+            iox_service_config,
+            &context,
+            &mut self.publish_subscribe_connectons,
+            &mut self.event_connections,
+            &self.request_response_connections,
+            &mut self.failed_services,
+        ) {
+            Ok(false) => on_request_response_discovery(
+                DiscoverySource {
+                    scope: source,
+                    log_context: &self.log_context,
+                    callbacks: &self.connection_callbacks,
+                },
+                iox_service_config,
+                &context,
+                &self.publish_subscribe_connectons,
+                &self.event_connections,
+                &mut self.request_response_connections,
+                &mut self.failed_services,
+            ),
+            result => result,
+        };
+
+        if result == Ok(true) {
+            self.total_services_discovered += 1;
+            index_service_name(&mut self.service_ids_by_name, iox_service_config);
+            self.service_sources
+                .insert(iox_service_config.service_id().clone(), source);
+            apply_connection_defaults(
+                &mut self.publish_subscribe_connectons,
+                &mut self.event_connections,
+                &mut self.request_response_connections,
+                iox_service_config,
+                &self.connection_defaults,
+                &self.z_additional_sessions,
+                self.z_allowed_destination,
+            );
+        }
+
+        result
+    }
+
+    /// Removes the connection of a service, releasing its iceoryx2 ports and zenoh entities,
+    /// e.g. when a remote node announced its shutdown via a side channel. Calls
+    /// [`TunnelConfig::on_connection_removed`] if configured.
+    ///
+    /// The service is connected again when it is discovered anew, see
+    /// [`TunnelConfig::service_blocklist`] to prevent that.
+    ///
+    /// # Arguments
+    ///
+    /// * `service_id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the connection of the service was removed
+    /// * `false` - If no service with the provided id is being tunneled
+    pub fn remove_connection(&mut self, service_id: &IceoryxServiceId) -> bool {
+        let removed = self
+            .publish_subscribe_connectons
+            .remove(service_id)
+            .is_some()
+            || self.event_connections.remove(service_id).is_some()
+            || self
+                .request_response_connections
+                .remove(service_id)
+                .is_some();
+        if !removed {
+            return false;
+        }
+
+        info!(
+            "{}REMOVED {}: removed manually",
+            self.log_context,
+            service_id.as_str()
+        );
+        self.service_ids_by_name.retain(|_, id| *id != *service_id);
+        self.service_sources.remove(service_id);
+        self.remove_stale_metadata();
+        self.connection_callbacks.removed(service_id);
+        self.connect_pending_services();
+
+        true
+    }
+
+    /// Returns the number of discovered services that are waiting to be connected until
+    /// there is room within [`TunnelConfig::max_connections`].
+    pub fn num_pending_connections(&self) -> usize {
+        self.connection_limit.pending.len()
+    }
+
+    /// Connects the services skipped due to [`TunnelConfig::max_connections`], oldest first,
+    /// while there is room for them.
+    fn connect_pending_services(&mut self) {
+        let Some(max_connections) = self.connection_limit.max_connections else {
+            return;
+        };
+
+        while self.publish_subscribe_connectons.len() + self.event_connections.len()
+            < max_connections
+        {
+            let Some((source, iox_service_config)) = self.connection_limit.pending.pop_front()
+            else {
+                break;
+            };
+            if self
+                .service_sources
+                .contains_key(iox_service_config.service_id())
+            {
+                continue;
+            }
+
+            info!(
+                "{}CONNECTING pending {} [{}]",
+                self.log_context,
+                iox_service_config.service_id().as_str(),
+                iox_service_config.name()
+            );
+            // Failures were logged and, with the `strict-errors` feature, recorded
+            let _ = self.attach_service(&iox_service_config, source);
+        }
+
+        self.apply_directions();
+    }
+
+    /// Removes the connection of a service and prevents it from being connected again when
+    /// it is discovered anew, so that it can be handed over to another tunnel.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((IceoryxServiceConfig, Scope))` - The static config of the service and the
+    ///   scope in which it was discovered
+    /// * `None` - If no service with the provided id is being tunneled
+    fn detach_service(&mut self, id: &IceoryxServiceId) -> Option<(IceoryxServiceConfig, Scope)> {
+        let iox_service_config = self
+            .publish_subscribe_connectons
+            .remove(id)
+            .map(|connection| connection.service_config().clone())
+            .or_else(|| {
+                self.event_connections
+                    .remove(id)
+                    .map(|connection| connection.service_config().clone())
+            })
+            .or_else(|| {
+                self.request_response_connections
+                    .remove(id)
+                    .map(|connection| connection.service_config().clone())
+            })?;
+
+        self.service_ids_by_name
+            .retain(|_, service_id| *service_id != *id);
+        self.service_filter.handed_over.insert(id.clone());
+        let source = self.service_sources.remove(id).unwrap_or(Scope::Iceoryx);
+        self.connection_callbacks.removed(id);
+
+        Some((iox_service_config, source))
+    }
+
+    /// Returns the discovery scope in which a tunneled service was found when its connection
+    /// was created, e.g. to tell whether a service discovered in both scopes originates from
+    /// the local host.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Scope)` - Either [`Scope::Iceoryx`] or [`Scope::Zenoh`], or [`Scope::None`]
+    ///   if the connection was added manually
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn service_discovery_source(&self, id: &IceoryxServiceId) -> Option<Scope> {
+        self.service_sources.get(id).copied()
+    }
+
+    /// Returns the zenoh key expression on which the payloads or events of a tunneled service
+    /// are published.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The zenoh key expression of the service
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn zenoh_key_for_service_id(&self, id: &IceoryxServiceId) -> Option<String> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.z_key_expression())
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| connection.z_key_expression())
+            })
+            .or_else(|| {
+                self.request_response_connections
+                    .get(id)
+                    .map(|connection| connection.z_key_expression())
+            })
+    }
+
+    /// Returns all pairs of tunneled services whose zenoh key expressions are equal or one
+    /// includes the other, in which case the payloads of one service are received by the
+    /// connection of the other service.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(IceoryxServiceId, IceoryxServiceId, String)>` - The ids of the colliding
+    ///   services along with the key expression they share, i.e. the more specific of both
+    pub fn service_zenoh_key_collision_map(
+        &self,
+    ) -> Vec<(IceoryxServiceId, IceoryxServiceId, String)> {
+        let mut key_expressions: Vec<(&IceoryxServiceId, KeyExpr<'static>)> = self
+            .publish_subscribe_connectons
+            .iter()
+            .map(|(id, connection)| (id, connection.z_key_expression()))
+            .chain(
+                self.event_connections
+                    .iter()
+                    .map(|(id, connection)| (id, connection.z_key_expression())),
+            )
+            .chain(
+                self.request_response_connections
+                    .iter()
+                    .map(|(id, connection)| (id, connection.z_key_expression())),
+            )
+            .filter_map(|(id, key)| KeyExpr::try_from(key).ok().map(|key| (id, key)))
+            .collect();
+        key_expressions.sort_by(|(lhs, _), (rhs, _)| lhs.as_str().cmp(rhs.as_str()));
+
+        let mut collisions = Vec::new();
+        for (i, (id, key)) in key_expressions.iter().enumerate() {
+            for (other_id, other_key) in &key_expressions[i + 1..] {
+                let shared = if key.includes(other_key) {
+                    other_key
+                } else if other_key.includes(key) {
+                    key
+                } else {
+                    continue;
+                };
+
+                collisions.push(((*id).clone(), (*other_id).clone(), shared.to_string()));
+            }
+        }
+
+        collisions
+    }
+
+    /// Returns a compact, URI-like representation of the connection of a tunneled service, e.g.
+    /// for logging or generating configurations.
+    ///
+    /// The representation has the format `iox2://<service id>/<service name><->zenoh://<key>`,
+    /// where `<key>` is the zenoh key expression of the service.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The representation of the connection
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn connection_string(&self, id: &IceoryxServiceId) -> Option<String> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| (connection.service_config(), connection.z_key_expression()))
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| (connection.service_config(), connection.z_key_expression()))
+            })
+            .map(|(iox_service_config, z_key)| {
+                format!(
+                    "iox2://{}/{}<->zenoh://{}",
+                    iox_service_config.service_id().as_str(),
+                    iox_service_config.name(),
+                    z_key
+                )
+            })
+    }
+
+    /// Returns the zenoh key expression on which the payloads or events of a tunneled service
+    /// are published.
+    ///
+    /// If multiple services with the same name are tunneled, e.g. a publish-subscribe and an
+    /// event service, the key expression of the service tunneled first is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The zenoh key expression of the service
+    /// * `None` - If no service with the provided name is being tunneled
+    pub fn zenoh_key_for_service_name(&self, name: &str) -> Option<String> {
+        self.service_ids_by_name
+            .get(name)
+            .and_then(|id| self.zenoh_key_for_service_id(id))
+    }
+
+    /// Returns the ids of all tunneled services whose name starts with the provided prefix,
+    /// e.g. all services in the `/sensors/` naming hierarchy.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of the names of the services
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<&IceoryxServiceId>` - The ids of the matching services
+    pub fn services_by_name_prefix(&self, prefix: &str) -> Vec<&IceoryxServiceId> {
+        let publish_subscribe_services = self
+            .publish_subscribe_connectons
+            .iter()
+            .map(|(id, connection)| (id, connection.service_config()));
+        let event_services = self
+            .event_connections
+            .iter()
+            .map(|(id, connection)| (id, connection.service_config()));
+
+        publish_subscribe_services
+            .chain(event_services)
+            .filter(|(_, iox_service_config)| {
+                iox_service_config.name().as_str().starts_with(prefix)
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Returns whether the connection of a service exceeded the failure threshold of the
+    /// configured [`PropagationPolicy`] and was disabled as a consequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(true)` - If the connection was disabled
+    /// * `Some(false)` - If the connection is still being propagated
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn service_error_threshold_exceeded(&self, id: &IceoryxServiceId) -> Option<bool> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.failures().is_disabled())
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| connection.failures().is_disabled())
+            })
+    }
+
+    /// Returns whether the connection of a service has forwarded at least one payload or
+    /// event in either direction, e.g. for readiness probes.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(true)` - If the connection has forwarded anything
+    /// * `Some(false)` - If the connection has not forwarded anything yet
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn service_is_active(&self, id: &IceoryxServiceId) -> Option<bool> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.statistics())
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| connection.statistics())
+            })
+            .or_else(|| {
+                self.request_response_connections
+                    .get(id)
+                    .map(|connection| connection.statistics())
+            })
+            .map(|statistics| {
+                statistics.forwarded_iceoryx_to_zenoh() > 0
+                    || statistics.forwarded_zenoh_to_iceoryx() > 0
+            })
+    }
+
+    /// Returns the number of services a connection was created for since the tunnel was
+    /// created, e.g. for analyzing the churn of the tunneled services.
+    ///
+    /// Services that are connected again after their connection was removed, e.g. when
+    /// reconnecting to zenoh, are counted again.
+    pub fn total_services_ever_discovered(&self) -> u64 {
+        self.total_services_discovered
+    }
+
+    /// Returns the number of services whose connection was removed since the tunnel was
+    /// created, e.g. because the service no longer exists or was evicted.
+    pub fn total_services_ever_removed(&self) -> u64 {
+        self.connection_callbacks.removals.load(Ordering::Relaxed)
+    }
+
+    /// Returns the liveness of the connections of all tunneled services, e.g. for polling the
+    /// health of the tunnel without parsing its log output.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<IceoryxServiceId, ConnectionHealth>` - The liveness of the connection of
+    ///   every service
+    pub fn health_check(&self) -> HashMap<IceoryxServiceId, ConnectionHealth> {
+        self.publish_subscribe_connectons
+            .iter()
+            .map(|(id, connection)| (id.clone(), connection.health()))
+            .chain(
+                self.event_connections
+                    .iter()
+                    .map(|(id, connection)| (id.clone(), connection.health())),
+            )
+            .chain(
+                self.request_response_connections
+                    .iter()
+                    .map(|(id, connection)| (id.clone(), connection.health())),
+            )
+            .collect()
+    }
+
+    /// Returns `true` if the connections of all tunneled services are
+    /// [`ConnectionHealth::Healthy`], e.g. for readiness probes.
+    pub fn is_healthy(&self) -> bool {
+        self.publish_subscribe_connectons
+            .values()
+            .map(|connection| connection.health())
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| connection.health()),
+            )
+            .chain(
+                self.request_response_connections
+                    .values()
+                    .map(|connection| connection.health()),
+            )
+            .all(|health| health == ConnectionHealth::Healthy)
+    }
+
+    /// Validates every connection of the tunnel, i.e. that its iceoryx2 ports and zenoh
+    /// entities are still usable and that its propagations do not fail excessively. Unlike
+    /// [`Tunnel::health_check()`], the usability of the ports is checked as well.
+    ///
+    /// # Returns
+    ///
+    /// * `ValidationReport` - The healthy, degraded and failed connections
+    pub fn validate_all_connections(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        for (id, connection) in &self.publish_subscribe_connectons {
+            report.record(id, connection.validate(&self.z_session));
+        }
+        for (id, connection) in &self.event_connections {
+            report.record(id, connection.validate(&self.z_session));
+        }
+        for (id, connection) in &self.request_response_connections {
+            report.record(id, connection.validate(&self.z_session));
+        }
+
+        report
+    }
+
+    /// Returns whether the connection of a service is saturated, i.e. the most recent
+    /// propagation found one of its receive buffers at capacity, indicating that the sender
+    /// is faster than the tunnel. See [`Tunnel::service_dropped_payloads()`] for the payloads
+    /// that were actually dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(true)` - If the connection was saturated during the most recent propagation
+    /// * `Some(false)` - If the connection was not saturated during the most recent propagation
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn connection_is_saturated(&self, id: &IceoryxServiceId) -> Option<bool> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.is_saturated())
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| connection.is_saturated())
+            })
+    }
+
+    /// Returns the ids of all tunneled services whose connection was saturated during the
+    /// most recent propagation.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<&IceoryxServiceId>` - The ids of the services with saturated connections
+    pub fn saturated_services(&self) -> Vec<&IceoryxServiceId> {
+        self.publish_subscribe_connectons
+            .iter()
+            .filter(|(_, connection)| connection.is_saturated())
+            .map(|(id, _)| id)
+            .chain(
+                self.event_connections
+                    .iter()
+                    .filter(|(_, connection)| connection.is_saturated())
+                    .map(|(id, _)| id),
+            )
+            .collect()
+    }
+
+    /// Returns the number of local payloads of a tunneled service that the tunnel dropped due
+    /// to backpressure, i.e. since they exceeded the rate limit set with
+    /// [`Tunnel::set_rate_limit()`] or the pending payloads.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The number of dropped payloads, always `0` for event services
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn service_dropped_payloads(&self, id: &IceoryxServiceId) -> Option<u64> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.dropped_payloads())
+            .or_else(|| self.event_connections.get(id).map(|_| 0))
+    }
+
+    /// Limits the rate at which local payloads of a tunneled publish-subscribe service are
+    /// propagated to remote hosts. Payloads exceeding the limit are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled publish-subscribe service
+    /// * `messages_per_second` - The maximum rate, `None` removes the limit
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the limit was applied to the connection of the service
+    /// * `false` - If no publish-subscribe service with the provided id is being tunneled
+    pub fn set_rate_limit(
+        &mut self,
+        id: &IceoryxServiceId,
+        messages_per_second: Option<u32>,
+    ) -> bool {
+        match self.publish_subscribe_connectons.get_mut(id) {
+            Some(connection) => {
+                connection.set_rate_limit(messages_per_second);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Limits the time local payloads of a tunneled publish-subscribe service may take from
+    /// being received from iceoryx2 until being propagated to remote hosts, including the
+    /// time they are kept pending due to backpressure. Payloads exceeding the budget are
+    /// dropped, see [`Tunnel::service_expired_messages_count()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled publish-subscribe service
+    /// * `budget` - The maximum latency of a payload within the tunnel
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the budget was applied to the connection of the service
+    /// * `false` - If no publish-subscribe service with the provided id is being tunneled
+    pub fn set_service_latency_budget(&mut self, id: &IceoryxServiceId, budget: Duration) -> bool {
+        match self.publish_subscribe_connectons.get_mut(id) {
+            Some(connection) => {
+                connection.set_latency_budget(Some(budget));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the latency budget of a tunneled publish-subscribe service set with
+    /// [`Tunnel::set_service_latency_budget()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled publish-subscribe service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Duration)` - The latency budget of the service
+    /// * `None` - If no budget was set or no publish-subscribe service with the provided id is
+    ///   being tunneled
+    pub fn service_propagation_latency_budget(&self, id: &IceoryxServiceId) -> Option<Duration> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .and_then(|connection| connection.latency_budget())
+    }
+
+    /// Attaches user data to the connection of a tunneled service, e.g. the calibration
+    /// parameters of a sensor, replacing the value previously set for the key. The metadata
+    /// is removed together with the connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    /// * `key` - The key under which the value is stored
+    /// * `value` - The value to store
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the value was attached to the connection of the service
+    /// * `false` - If no service with the provided id is being tunneled
+    pub fn set_connection_metadata(
+        &mut self,
+        id: &IceoryxServiceId,
+        key: &str,
+        value: String,
+    ) -> bool {
+        if !self.service_sources.contains_key(id) {
+            return false;
+        }
+
+        self.connection_metadata
+            .entry(id.clone())
+            .or_default()
+            .insert(key.to_string(), value);
+        true
+    }
+
+    /// Returns the user data attached to the connection of a tunneled service with
+    /// [`Tunnel::set_connection_metadata()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    /// * `key` - The key under which the value is stored
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` - The value stored under the key
+    /// * `None` - If no value is stored under the key or the service is not tunneled
+    pub fn get_connection_metadata(&self, id: &IceoryxServiceId, key: &str) -> Option<&str> {
+        self.connection_metadata
+            .get(id)
+            .and_then(|metadata| metadata.get(key))
+            .map(String::as_str)
+    }
+
+    /// Sets the maximum duration each connection spends receiving local payloads per
+    /// propagation, for all connections including the ones discovered later on. See
+    /// [`TunnelConfig::iceoryx_receive_timeout`].
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum receive duration, `None` removes the limit
+    pub fn set_iceoryx_receive_timeout(&mut self, timeout: Option<Duration>) {
+        self.connection_defaults.iceoryx_receive_timeout = timeout;
+        for connection in self.publish_subscribe_connectons.values_mut() {
+            connection.set_iceoryx_receive_timeout(timeout);
+        }
+        for connection in self.event_connections.values_mut() {
+            connection.set_iceoryx_receive_timeout(timeout);
+        }
+        for connection in self.request_response_connections.values_mut() {
+            connection.set_iceoryx_receive_timeout(timeout);
+        }
+    }
+
+    /// Sets how the local payloads of publish-subscribe services are handled while their rate
+    /// limit is exhausted, for all connections including the ones discovered later on. See
+    /// [`TunnelConfig::backpressure_policy`].
+    ///
+    /// The zenoh publishers of existing connections keep their congestion control until the
+    /// connections are recreated, e.g. with [`Tunnel::reconnect()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The backpressure policy
+    pub fn set_backpressure_policy(&mut self, policy: BackpressurePolicy) {
+        self.connection_defaults.backpressure_policy = Some(policy);
+        for connection in self.publish_subscribe_connectons.values_mut() {
+            connection.set_backpressure_policy(policy);
+        }
+    }
+
+    /// Replays the history of publish-subscribe services to the remote tunnels subscribing to
+    /// them later on, for all connections including the ones discovered later on. See
+    /// [`TunnelConfig::replay_history_on_connect`].
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the history is replayed
+    pub fn set_replay_history_on_connect(&mut self, enabled: bool) {
+        self.connection_defaults.replay_history_on_connect = enabled;
+        for (id, connection) in self.publish_subscribe_connectons.iter_mut() {
+            if connection.set_history_replay(enabled).is_err() {
+                warn!(
+                    "{}Failed to change the history replay of {} [{}]",
+                    self.log_context,
+                    id.as_str(),
+                    connection.service_config().name()
+                );
+            }
+        }
+    }
+
+    /// Publishes keep-alives on publish-subscribe and event connections that stayed idle for
+    /// the provided interval, for all connections including the ones discovered later on.
+    /// `None` disables the keep-alives. See [`TunnelConfig::keep_alive_interval`].
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The maximum duration a connection stays idle on zenoh
+    pub fn enable_connection_keep_alive(&mut self, interval: Option<Duration>) {
+        self.connection_defaults.keep_alive_interval = interval;
+        for connection in self.publish_subscribe_connectons.values_mut() {
+            connection.set_keep_alive_interval(interval);
+        }
+        for connection in self.event_connections.values_mut() {
+            connection.set_keep_alive_interval(interval);
+        }
+    }
+
+    /// Restricts the zenoh instances the local payloads and events are published to, for the
+    /// connections created from now on. Existing connections keep their destination until
+    /// they are recreated, e.g. with [`Tunnel::reconnect()`]. See
+    /// [`TunnelConfig::zenoh_allowed_destination`].
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The zenoh instances the publications are allowed to reach
+    pub fn set_zenoh_publisher_allowed_destination(&mut self, destination: Locality) {
+        self.z_allowed_destination = destination;
+    }
+
+    /// Returns the zenoh instances the local payloads and events of newly created
+    /// connections are published to.
+    pub fn zenoh_publisher_allowed_destination(&self) -> Locality {
+        self.z_allowed_destination
+    }
+
+    /// Limits the number of publishers of the publish-subscribe services created by the
+    /// tunnel from now on. Existing services keep their limit. See
+    /// [`TunnelConfig::iceoryx_max_publishers`].
+    ///
+    /// # Arguments
+    ///
+    /// * `max_publishers` - The maximum number of publishers, `None` uses the limit of the
+    ///   discovered service
+    pub fn set_iceoryx_service_max_publishers(&mut self, max_publishers: Option<u64>) {
+        self.iox_service_limits.max_publishers = max_publishers;
+    }
+
+    /// Limits the number of subscribers of the publish-subscribe services created by the
+    /// tunnel from now on. Existing services keep their limit. See
+    /// [`TunnelConfig::iceoryx_max_subscribers`].
+    ///
+    /// # Arguments
+    ///
+    /// * `max_subscribers` - The maximum number of subscribers, `None` uses the limit of the
+    ///   discovered service
+    pub fn set_iceoryx_service_max_subscribers(&mut self, max_subscribers: Option<u64>) {
+        self.iox_service_limits.max_subscribers = max_subscribers;
+    }
+
+    /// Returns the algorithm the checksums of the publish-subscribe payloads are computed with,
+    /// see [`TunnelConfig::checksum_verification`].
+    #[cfg(feature = "checksum")]
+    pub fn connection_checksum_verification(&self) -> Option<ChecksumAlgorithm> {
+        self.connection_defaults.checksum_verification
+    }
+
+    /// Enables or disables tracking the ids of the publish-subscribe payloads for all
+    /// connections, including the ones discovered later on. See
+    /// [`TunnelConfig::track_message_ids`].
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the payloads are prefixed with their message id
+    pub fn with_message_id_tracking(&mut self, enabled: bool) {
+        self.connection_defaults.track_message_ids = enabled;
+        for connection in self.publish_subscribe_connectons.values_mut() {
+            connection.set_message_id_tracking(enabled);
+        }
+    }
+
+    /// Returns the message id of the most recent remote payload of a tunneled
+    /// publish-subscribe service, see [`TunnelConfig::track_message_ids`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The message id of the most recent remote payload
+    /// * `None` - If the service is not tunneled, message ids are not tracked or no remote
+    ///   payload was received yet
+    pub fn last_received_message_id(&self, id: &IceoryxServiceId) -> Option<u64> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .and_then(|connection| connection.statistics().last_received_message_id())
+    }
+
+    /// Compresses the local payloads of a tunneled publish-subscribe service that are larger
+    /// than the provided size before propagating them to remote hosts. Smaller payloads are
+    /// propagated as-is, as compressing them costs more than it saves.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled publish-subscribe service
+    /// * `min_size_bytes` - The payload size in bytes above which payloads are compressed
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the threshold was applied to the connection of the service
+    /// * `false` - If no publish-subscribe service with the provided id is being tunneled
+    #[cfg(feature = "compression")]
+    pub fn set_compression_threshold(
+        &mut self,
+        id: &IceoryxServiceId,
+        min_size_bytes: usize,
+    ) -> bool {
+        match self.publish_subscribe_connectons.get_mut(id) {
+            Some(connection) => {
+                connection.set_compression_threshold(min_size_bytes);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the codec the local payloads of a publish-subscribe service are compressed with
+    /// once they exceed the compression threshold of the service, see
+    /// [`Tunnel::set_compression_threshold()`]. Payloads are compressed with
+    /// [`CompressionCodec::Lz4`] unless another codec is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled publish-subscribe service
+    /// * `codec` - The codec the payloads are compressed with
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the codec was applied to the connection of the service
+    /// * `false` - If no publish-subscribe service with the provided id is being tunneled
+    #[cfg(feature = "compression")]
+    pub fn set_compression_codec(
+        &mut self,
+        id: &IceoryxServiceId,
+        codec: CompressionCodec,
+    ) -> bool {
+        match self.publish_subscribe_connectons.get_mut(id) {
+            Some(connection) => {
+                connection.set_compression_codec(codec);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Compresses the local payloads of all tunneled publish-subscribe services with the
+    /// provided compression, like [`Tunnel::set_compression_codec()`] and
+    /// [`Tunnel::set_compression_threshold()`] for each service.
+    /// Connections created later on are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `compression` - The compression applied to the connections
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of connections the compression was applied to
+    /// * `Err(CompressionError::UnsupportedConnections)` - If some connections do not support
+    ///   the compression, the other connections are updated regardless
+    #[cfg(feature = "compression")]
+    pub fn compress_all_connections(
+        &mut self,
+        compression: CompressionConfig,
+    ) -> Result<usize, CompressionError> {
+        let mut updated = 0;
+        let mut unsupported = 0;
+        for connection in self.publish_subscribe_connectons.values_mut() {
+            if connection.is_iceoryx_to_zenoh() {
+                connection.set_compression_codec(compression.codec);
+                connection.set_compression_threshold(compression.min_size_bytes);
+                updated += 1;
+            } else {
+                warn!(
+                    "{}Compression {:?} is not supported by PublishSubscribe {} [{}]",
+                    self.log_context,
+                    compression.codec,
+                    connection.service_config().service_id().as_str(),
+                    connection.service_config().name()
+                );
+                unsupported += 1;
+            }
+        }
+
+        if unsupported > 0 {
+            return Err(CompressionError::UnsupportedConnections {
+                updated,
+                unsupported,
+            });
+        }
+
+        Ok(updated)
+    }
+
+    /// Returns whether the rate limit of a service is exhausted, i.e. the next local payload
+    /// of the service would be dropped, e.g. to slow down the publisher before payloads are
+    /// actually lost.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(true)` - If the rate limit of the connection is exhausted
+    /// * `Some(false)` - If the rate limit is not exhausted or no rate limit is set
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn connection_throughput_limit_exceeded(&self, id: &IceoryxServiceId) -> Option<bool> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.is_rate_limit_exceeded())
+            .or_else(|| self.event_connections.get(id).map(|_| false))
+    }
+
+    /// Returns the number of local payloads of a service that are kept pending until its rate
+    /// limit permits propagating them, see [`BackpressurePolicy::DropOldest`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The number of pending local payloads
+    /// * `None` - If no publish-subscribe service with the provided id is being tunneled
+    pub fn num_pending_payloads(&self, id: &IceoryxServiceId) -> Option<usize> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.num_pending_payloads())
+    }
+
+    /// Returns the number of local events of a service that are collected in a batch and not
+    /// yet propagated to remote hosts, see [`EventBatching`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The number of pending local events
+    /// * `None` - If no event service with the provided id is being tunneled
+    pub fn pending_events(&self, id: &IceoryxServiceId) -> Option<usize> {
+        self.event_connections
+            .get(id)
+            .map(|connection| connection.pending_events())
+    }
+
+    /// Returns the total number of local events across all event connections that are
+    /// collected in a batch and not yet propagated to remote hosts.
+    pub fn num_pending_events(&self) -> usize {
+        self.event_connections
+            .values()
+            .map(|connection| connection.pending_events())
+            .sum()
+    }
+
+    /// Returns the connection of a service, e.g. to inspect a single service without
+    /// collecting the [`Tunnel::health_check()`] or [`Tunnel::statistics()`] of all services.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ConnectionRef)` - The connection of the service
+    /// * `None` - If no publish-subscribe or event service with the provided id is being
+    ///   tunneled
+    pub fn connection_for_service(
+        &self,
+        id: &IceoryxServiceId,
+    ) -> Option<ConnectionRef<'_, 'a, Service>> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(BorrowedConnection::PublishSubscribe)
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(BorrowedConnection::Event)
+            })
+            .map(|connection| ConnectionRef { connection })
+    }
+
+    /// Returns whether the zenoh subscribers of a service are still connected to their zenoh
+    /// sessions, e.g. to detect connections that silently stopped forwarding from zenoh to
+    /// iceoryx2. The check does not involve any network traffic.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(true)` - If all zenoh subscribers of the connection are alive
+    /// * `Some(false)` - If a zenoh subscriber was dropped, or if the connection does not
+    ///   receive from zenoh due to its [`ConnectionDirection`]
+    /// * `None` - If no publish-subscribe or event service with the provided id is being
+    ///   tunneled
+    pub fn service_zenoh_subscriber_is_alive(&self, id: &IceoryxServiceId) -> Option<bool> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.is_z_subscriber_alive())
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| connection.is_z_listener_alive())
+            })
+    }
+
+    /// Returns the quality of service currently applied to the connection of a service, e.g.
+    /// to audit the connections after their settings were changed at runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(EffectiveQoS)` - The current quality of service of the connection
+    /// * `None` - If no publish-subscribe or event service with the provided id is being
+    ///   tunneled
+    pub fn service_effective_qos(&self, id: &IceoryxServiceId) -> Option<EffectiveQoS> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| (connection.publisher_options(), connection.rate_limit()))
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| (connection.publisher_options(), None))
+            })
+            .map(|(options, rate_limit)| EffectiveQoS {
+                priority: options.priority,
+                reliability: options.reliability,
+                rate_limit,
+                congestion_control: options.congestion_control,
+            })
+    }
+
+    /// Returns the number of local samples of a service that the tunnel may have missed
+    /// because the local publishers were faster than the propagation.
+    ///
+    /// The count is an estimate, as iceoryx2 does not report how many samples were
+    /// overwritten in a full subscriber buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The number of missed samples, always `0` for event services
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn service_missed_samples(&self, id: &IceoryxServiceId) -> Option<u64> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.missed_iceoryx_samples())
+            .or_else(|| self.event_connections.get(id).map(|_| 0))
+    }
+
+    /// Returns the number of local payloads of a tunneled service that were dropped since they
+    /// exceeded the latency budget set with [`Tunnel::set_service_latency_budget()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The number of expired payloads, always `0` for event services
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn service_expired_messages_count(&self, id: &IceoryxServiceId) -> Option<u64> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.expired_payloads())
+            .or_else(|| self.event_connections.get(id).map(|_| 0))
+    }
+
+    /// Summarizes the health of a tunneled service in a single score between `0.0` and
+    /// `1.0`, where `1.0` is perfect, e.g. to monitor many services at a glance.
+    ///
+    /// The score is the weighted average of the following ratings, each between `0.0` and
+    /// `1.0`, with the weights of [`TunnelConfig::quality_score_config`]:
+    ///
+    /// * error rate - the share of successful propagations, see [`TunnelStatistics`]
+    /// * latency - the share of local payloads that did not exceed the latency budget, see
+    ///   [`Tunnel::service_expired_messages_count()`]. With the `latency-tracking` feature,
+    ///   the rating is lowered further when the 95th percentile of the latency of remote
+    ///   payloads exceeds the latency budget.
+    /// * throughput - the average number of messages forwarded per second since the
+    ///   connection was created, compared to the expected number
+    /// * missed samples - the share of local samples that were not missed, see
+    ///   [`Tunnel::service_missed_samples()`]
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The quality score of the service
+    /// * `None` - If no publish-subscribe or event service with the provided id is being
+    ///   tunneled
+    pub fn service_quality_score(&self, id: &IceoryxServiceId) -> Option<f64> {
+        let config = &self.quality_score_config;
+        let (statistics, expired, missed) = match self.publish_subscribe_connectons.get(id) {
+            Some(connection) => (
+                connection.statistics(),
+                connection.expired_payloads(),
+                connection.missed_iceoryx_samples(),
+            ),
+            None => (self.event_connections.get(id)?.statistics(), 0, 0),
+        };
+        let forwarded_iceoryx_to_zenoh = statistics.forwarded_iceoryx_to_zenoh();
+
+        let error_rating = 1.0 - statistics.error_rate();
+
+        let latency_rating = success_ratio(forwarded_iceoryx_to_zenoh, expired);
+        #[cfg(feature = "latency-tracking")]
+        let latency_rating = match statistics.latency_stats() {
+            Some(latency_stats) => {
+                let latency_budget = self
+                    .service_propagation_latency_budget(id)
+                    .unwrap_or(config.latency_budget);
+                latency_rating.min(capped_ratio(
+                    latency_budget.as_secs_f64(),
+                    Duration::from_micros(latency_stats.p95_us).as_secs_f64(),
+                ))
+            }
+            None => latency_rating,
+        };
+
+        let throughput_rating = match config.expected_messages_per_second {
+            Some(expected_messages_per_second) => {
+                let elapsed = statistics
+                    .created_at()
+                    .elapsed()
+                    .unwrap_or_default()
+                    .as_secs_f64();
+                let forwarded =
+                    forwarded_iceoryx_to_zenoh + statistics.forwarded_zenoh_to_iceoryx();
+                let messages_per_second = if elapsed > 0.0 {
+                    forwarded as f64 / elapsed
+                } else {
+                    0.0
+                };
+                capped_ratio(messages_per_second, expected_messages_per_second)
+            }
+            None => 1.0,
+        };
+
+        let missed_samples_rating = success_ratio(forwarded_iceoryx_to_zenoh, missed);
+
+        let ratings = [
+            (config.error_rate_weight, error_rating),
+            (config.latency_weight, latency_rating),
+            (config.throughput_weight, throughput_rating),
+            (config.missed_samples_weight, missed_samples_rating),
+        ];
+        let total_weight: f64 = ratings.iter().map(|(weight, _)| weight).sum();
+        if total_weight <= 0.0 {
+            return Some(1.0);
+        }
+        let score = ratings
+            .iter()
+            .map(|(weight, rating)| weight * rating)
+            .sum::<f64>()
+            / total_weight;
+
+        Some(score.clamp(0.0, 1.0))
+    }
+
+    /// Returns the maximum number of unread samples the tunnel found in the buffer of its
+    /// iceoryx2 subscriber of a publish-subscribe service across all propagations, e.g. to
+    /// tell whether the subscriber buffer size of the service is sufficient.
+    ///
+    /// A depth equal to the subscriber buffer size indicates that samples may have been
+    /// missed, see [`Tunnel::service_missed_samples()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The maximum observed number of unread samples
+    /// * `None` - If no publish-subscribe service with the provided id is being tunneled
+    pub fn service_max_queue_depth_observed(&self, id: &IceoryxServiceId) -> Option<usize> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.max_iceoryx_queue_depth())
+    }
+
+    /// Returns the histogram of the payload sizes forwarded in both directions for a service.
+    /// Events carry no payload and are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(SizeHistogram)` - The payload sizes forwarded for the service
+    /// * `None` - If no service with the provided id is being tunneled
+    pub fn message_size_histogram(&self, id: &IceoryxServiceId) -> Option<SizeHistogram> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.statistics().size_histogram())
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| connection.statistics().size_histogram())
+            })
+    }
+
+    /// Returns the histogram of the payload sizes forwarded in both directions, aggregated
+    /// over all tunneled services.
+    pub fn overall_message_size_histogram(&self) -> SizeHistogram {
+        let mut histogram = SizeHistogram::default();
+        for connection in self.publish_subscribe_connectons.values() {
+            histogram.merge(&connection.statistics().size_histogram());
+        }
+        for connection in self.event_connections.values() {
+            histogram.merge(&connection.statistics().size_histogram());
+        }
+
+        histogram
+    }
+
+    /// Returns the distribution of the ages of all tunneled connections, e.g. to tell
+    /// whether connections are long-lived or churning.
+    pub fn connection_age_histogram(&self) -> AgeHistogram {
+        let now = SystemTime::now();
+        let mut histogram = AgeHistogram::default();
+
+        let created_at = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| connection.statistics().created_at())
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| connection.statistics().created_at()),
+            );
+        for created_at in created_at {
+            let age = now.duration_since(created_at).unwrap_or_default();
+            histogram.counts[AgeBucket::of(age) as usize] += 1;
+        }
+
+        histogram
+    }
+
+    /// Returns the rate of events per second propagated for an event service within the
+    /// provided window.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled event service
+    /// * `window` - The duration, ending now, over which the frequency is determined
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The events per second propagated in both directions
+    /// * `None` - If no event service with the provided id is being tunneled
+    pub fn event_frequency(&self, id: &IceoryxServiceId, window: Duration) -> Option<f64> {
+        self.event_connections
+            .get(id)
+            .map(|connection| connection.event_frequency(window))
+    }
+
+    /// Installs a callback that is called with the raw payload of every message propagated
+    /// over the connection of a publish-subscribe service, e.g. to debug a specific service
+    /// without recording all messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled publish-subscribe service
+    /// * `callback` - The callback receiving the direction and payload of each message
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(WatchHandle)` - The handle that removes the callback when dropped
+    /// * `Err(WatchError)` - If no publish-subscribe service with the provided id is tunneled
+    pub fn watch_service<F: Fn(Direction, &[u8]) + Send + 'static>(
+        &mut self,
+        id: &IceoryxServiceId,
+        callback: F,
+    ) -> Result<WatchHandle, WatchError> {
+        let connection = self
+            .publish_subscribe_connectons
+            .get(id)
+            .ok_or(WatchError::Error)?;
+
+        let taps = connection.taps();
+        let tap_id = taps.install(Box::new(move |direction, payload| {
+            callback(direction, payload);
+            true
+        }));
+
+        Ok(WatchHandle {
+            taps: Arc::downgrade(taps),
+            id: tap_id,
+        })
+    }
+
+    /// Returns a channel that receives the raw payload of every message propagated over the
+    /// connection of a publish-subscribe service, e.g. to inspect the traffic of a service
+    /// from another thread.
+    ///
+    /// Messages are dropped from the observation while the channel is full, the propagation
+    /// is never blocked. Dropping the receiver ends the observation with the next propagated
+    /// message.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled publish-subscribe service
+    /// * `buffer` - The number of messages the channel buffers
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Receiver)` - The receiver of the direction and payload of each message
+    /// * `Err(ObserveError::ServiceNotFound)` - If no publish-subscribe service with the
+    ///   provided id is tunneled
+    #[cfg(feature = "crossbeam")]
+    pub fn observe_service_traffic(
+        &mut self,
+        id: &IceoryxServiceId,
+        buffer: usize,
+    ) -> Result<crossbeam_channel::Receiver<(Direction, Vec<u8>)>, ObserveError> {
+        let connection = self
+            .publish_subscribe_connectons
+            .get(id)
+            .ok_or(ObserveError::ServiceNotFound)?;
+
+        let (sender, receiver) = crossbeam_channel::bounded(buffer);
+        connection
+            .taps()
+            .install(Box::new(move |direction, payload| {
+                match sender.try_send((direction, payload.to_vec())) {
+                    Ok(()) | Err(crossbeam_channel::TrySendError::Full(_)) => true,
+                    Err(crossbeam_channel::TrySendError::Disconnected(_)) => false,
+                }
+            }));
+
+        Ok(receiver)
+    }
+
+    /// Returns the names that are shared by multiple tunneled services of the same messaging
+    /// pattern, e.g. services created by different processes.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The names of the services with duplicate names
+    pub fn services_with_duplicate_names(&self) -> Vec<String> {
+        let mut publish_subscribe_names = HashSet::new();
+        let mut event_names = HashSet::new();
+        let mut duplicates = HashSet::new();
+
+        for connection in self.publish_subscribe_connectons.values() {
+            let name = connection.service_config().name().to_string();
+            if !publish_subscribe_names.insert(name.clone()) {
+                duplicates.insert(name);
+            }
+        }
+
+        for connection in self.event_connections.values() {
+            let name = connection.service_config().name().to_string();
+            if !event_names.insert(name.clone()) {
+                duplicates.insert(name);
+            }
+        }
+
+        duplicates.into_iter().collect()
+    }
+
+    /// Forwards all pending local payloads of a publish-subscribe service to remote hosts on
+    /// the zenoh topic of another tunneled publish-subscribe service instead of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The id of the service whose local payloads are forwarded
+    /// * `sink` - The id of the service on whose zenoh topic the payloads are published
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of forwarded payloads
+    /// * `Err(SpliceError)` - If either service is not a distinct tunneled publish-subscribe
+    ///   service or forwarding failed
+    pub fn splice(
+        &mut self,
+        source: &IceoryxServiceId,
+        sink: &IceoryxServiceId,
+    ) -> Result<u64, SpliceError> {
+        let mut sink_connection = self
+            .publish_subscribe_connectons
+            .remove(sink)
+            .ok_or(SpliceError::Error)?;
+
+        let result = match self.publish_subscribe_connectons.get(source) {
+            Some(source_connection) => BidirectionalPublishSubscribeConnection::splice(
+                source_connection,
+                &mut sink_connection,
+            ),
+            None => Err(SpliceError::Error),
+        };
+
+        self.publish_subscribe_connectons
+            .insert(sink.clone(), sink_connection);
+
+        result
+    }
+
+    /// Estimates the heap memory used by the tunnel from the capacities of its maps, the
+    /// buffers of its connections and the stored service configurations.
+    ///
+    /// Allocations within iceoryx2 and zenoh, e.g. for shared memory or the session, are not
+    /// included.
+    ///
+    /// # Returns
+    ///
+    /// * `MemoryUsageEstimate` - The approximate heap memory used by the tunnel
+    pub fn estimate_memory_usage(&self) -> MemoryUsageEstimate {
+        let publish_subscribe_entry_size = core::mem::size_of::<(
+            IceoryxServiceId,
+            BidirectionalPublishSubscribeConnection<'a, Service>,
+        )>();
+        let event_entry_size =
+            core::mem::size_of::<(IceoryxServiceId, BidirectionalEventConnection<'a, Service>)>();
+        let connection_map_bytes = self.publish_subscribe_connectons.capacity()
+            * publish_subscribe_entry_size
+            + self.event_connections.capacity() * event_entry_size;
+
+        let payload_buffer_bytes = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| connection.payload_buffer_bytes())
+            .sum();
+
+        let history_bytes: usize = self
+            .publish_subscribe_connectons
+            .values()
+            .map(|connection| connection.history_bytes())
+            .chain(
+                self.event_connections
+                    .values()
+                    .map(|connection| connection.history_bytes()),
+            )
+            .sum();
+        let failed_services_bytes = self.failed_services.capacity()
+            * core::mem::size_of::<(IceoryxServiceId, (Scope, IceoryxServiceConfig))>();
+        let service_sources_bytes =
+            self.service_sources.capacity() * core::mem::size_of::<(IceoryxServiceId, Scope)>();
+        let service_names_bytes = self.service_ids_by_name.capacity()
+            * core::mem::size_of::<(String, IceoryxServiceId)>()
+            + self
+                .service_ids_by_name
+                .keys()
+                .map(|name| name.capacity())
+                .sum::<usize>();
+        let connection_metadata_bytes = self.connection_metadata.capacity()
+            * core::mem::size_of::<(IceoryxServiceId, HashMap<String, String>)>()
+            + self
+                .connection_metadata
+                .values()
+                .flat_map(|metadata| metadata.iter())
+                .map(|(key, value)| {
+                    core::mem::size_of::<(String, String)>() + key.capacity() + value.capacity()
+                })
+                .sum::<usize>();
+        let metadata_bytes = history_bytes
+            + failed_services_bytes
+            + service_sources_bytes
+            + service_names_bytes
+            + connection_metadata_bytes;
+
+        MemoryUsageEstimate {
+            connection_map_bytes,
+            payload_buffer_bytes,
+            metadata_bytes,
+            total_bytes: connection_map_bytes + payload_buffer_bytes + metadata_bytes,
+        }
+    }
+
+    /// Returns the connection establishment latency of a service, i.e. the duration from its
+    /// discovery until the first successful propagation over its connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the tunneled service
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Duration)` - The connection establishment latency
+    /// * `None` - If no service with the provided id is being tunneled or nothing was
+    ///   propagated successfully over its connection yet
+    pub fn service_connection_latency(&self, id: &IceoryxServiceId) -> Option<Duration> {
+        self.publish_subscribe_connectons
+            .get(id)
+            .map(|connection| connection.statistics())
+            .or_else(|| {
+                self.event_connections
+                    .get(id)
+                    .map(|connection| connection.statistics())
+            })
+            .and_then(|statistics| statistics.connection_latency())
+    }
+
+    /// Returns the jitter of the propagation over the connection of a service, i.e. the
+    /// standard deviation of the intervals between the payloads forwarded within the provided
+    /// window.
+    ///
+    /// # Arguments
+    ///
+    /// * `service_id` - The id of the tunneled service
+    /// * `window` - The duration, ending now, over which the jitter is determined
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Duration)` - The jitter of the propagation in both directions
+    /// * `None` - If no service with the provided id is being tunneled or less than two
+    ///   payloads were forwarded within the window
+    pub fn propagation_jitter(
+        &self,
+        service_id: &IceoryxServiceId,
+        window: Duration,
+    ) -> Option<Duration> {
+        self.publish_subscribe_connectons
+            .get(service_id)
+            .map(|connection| connection.statistics())
+            .or_else(|| {
+                self.event_connections
+                    .get(service_id)
+                    .map(|connection| connection.statistics())
+            })
+            .and_then(|statistics| statistics.propagation_jitter(window))
+    }
+}
+
+/// An entry of the service list exported by [`Tunnel::export_service_list_json()`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct ServiceListEntry {
+    id: String,
+    name: String,
+    pattern: String,
+    connected_at_iso8601: String,
+    messages_forwarded_iox_to_zenoh: u64,
+    messages_forwarded_zenoh_to_iox: u64,
+    last_error: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl ServiceListEntry {
+    fn new(iox_service_config: &IceoryxServiceConfig, statistics: &ConnectionStatistics) -> Self {
+        Self {
+            id: iox_service_config.service_id().as_str().to_string(),
+            name: iox_service_config.name().to_string(),
+            pattern: iox_service_config.messaging_pattern().to_string(),
+            connected_at_iso8601: humantime::format_rfc3339_seconds(statistics.created_at())
+                .to_string(),
+            messages_forwarded_iox_to_zenoh: statistics.forwarded_iceoryx_to_zenoh(),
+            messages_forwarded_zenoh_to_iox: statistics.forwarded_zenoh_to_iceoryx(),
+            last_error: statistics.last_error().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// Appends the provided value to the JSON document as a quoted and escaped JSON string.
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Returns the connection of the service with the provided id, regardless of its messaging
+/// pattern.
+fn find_connection<'t, ServiceType: iceoryx2::service::Service>(
+    id: &IceoryxServiceId,
+    publish_subscribe_connections: &'t HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &'t HashMap<IceoryxServiceId, BidirectionalEventConnection<'_, ServiceType>>,
+    request_response_connections: &'t HashMap<
+        IceoryxServiceId,
+        BidirectionalRequestResponseConnection<'_, ServiceType>,
+    >,
+) -> Option<&'t dyn TunneledConnection> {
+    if let Some(connection) = publish_subscribe_connections.get(id) {
+        return Some(connection as &dyn TunneledConnection);
+    }
+    if let Some(connection) = event_connections.get(id) {
+        return Some(connection as &dyn TunneledConnection);
+    }
+    request_response_connections
+        .get(id)
+        .map(|connection| connection as &dyn TunneledConnection)
+}
+
+/// Returns the number of payloads forwarded over a connection so far, as a tuple of the numbers
+/// forwarded from iceoryx to zenoh and from zenoh to iceoryx.
+fn forwarded(statistics: &ConnectionStatistics) -> (usize, usize) {
+    (
+        statistics.forwarded_iceoryx_to_zenoh() as usize,
+        statistics.forwarded_zenoh_to_iceoryx() as usize,
+    )
+}
+
+/// Formats the statistics of a service as a CSV row of [`Tunnel::service_stats_to_csv()`].
+fn csv_row(iox_service_config: &IceoryxServiceConfig, statistics: &ConnectionStatistics) -> String {
+    let uptime = SystemTime::now()
+        .duration_since(statistics.created_at())
+        .unwrap_or_default();
+
+    format!(
+        "{},{},{},{},{},{},{},{},{}\n",
+        iox_service_config.service_id().as_str(),
+        csv_field(iox_service_config.name().as_str()),
+        iox_service_config.messaging_pattern(),
+        statistics.forwarded_iceoryx_to_zenoh(),
+        statistics.forwarded_zenoh_to_iceoryx(),
+        statistics.bytes_iceoryx_to_zenoh(),
+        statistics.bytes_zenoh_to_iceoryx(),
+        statistics.errors(),
+        uptime.as_secs()
+    )
+}
+
+/// Returns the share of `succeeded` among `succeeded` and `failed`, `1.0` if there were none.
+fn success_ratio(succeeded: u64, failed: u64) -> f64 {
+    let total = succeeded + failed;
+    if total == 0 {
+        return 1.0;
+    }
+
+    succeeded as f64 / total as f64
+}
+
+/// Returns the ratio of `actual` to `expected`, capped at `1.0`, or `1.0` if nothing is
+/// expected.
+fn capped_ratio(actual: f64, expected: f64) -> f64 {
+    if expected <= 0.0 {
+        return 1.0;
+    }
+
+    (actual / expected).min(1.0)
+}
+
+/// Colors are used unless disabled via the `NO_COLOR` environment variable or when the output
+/// is not a terminal.
+fn use_color_by_default() -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+
+    !no_color && std::io::stdout().is_terminal() && std::io::stderr().is_terminal()
+}
+
+/// Publishes a raw payload consisting of multiple elements of the provided size on iceoryx.
+fn iox_send_payload<ServiceType: iceoryx2::service::Service>(
+    iox_publisher: &IceoryxPublisher<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
+    payload: &[u8],
+    iox_payload_size: usize,
+) -> Result<(), RoundtripError> {
+    unsafe {
+        let mut iox_sample = iox_publisher
+            .loan_custom_payload(payload.len() / iox_payload_size)
+            .map_err(|_e| RoundtripError::Error)?;
+        core::ptr::copy_nonoverlapping(
+            payload.as_ptr(),
+            iox_sample.payload_mut().as_mut_ptr() as *mut u8,
+            payload.len(),
+        );
+        iox_sample
+            .assume_init()
+            .send()
+            .map_err(|_e| RoundtripError::Error)?;
+    }
+
+    Ok(())
+}
+
+/// Receives the next raw payload on iceoryx, if any.
+fn iox_receive_payload<ServiceType: iceoryx2::service::Service>(
+    iox_subscriber: &IceoryxSubscriber<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
+) -> Result<Option<Vec<u8>>, RoundtripError> {
+    match unsafe { iox_subscriber.receive_custom_payload() } {
+        Ok(Some(sample)) => {
+            let ptr = sample.payload().as_ptr() as *const u8;
+            let bytes = unsafe { core::slice::from_raw_parts(ptr, sample.len()) };
+            Ok(Some(bytes.to_vec()))
+        }
+        Ok(None) => Ok(None),
+        Err(_e) => Err(RoundtripError::Error),
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Propagate over a single connection, tracking its failures according to the propagation policy.
+///
+/// # Arguments
+///
+/// * `iox_service_config` - The static config of the service of the connection
+/// * `connection` - The connection to propagate over
+/// * `failures` - The failure tracker of the connection
+/// * `policy` - The policy determining when to disable a failing connection
+/// * `log_context` - The logging context of the tunnel
+/// * `report` - The report to record the outcome of the propagation in
+// Recorded here rather than in the connections, as the spans of the connections are not
+// nested in the span of the tunnel when propagating in parallel.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(log_context = log_context.as_fields()))
+)]
+fn propagate_connection<C: Connection + ?Sized>(
+    iox_service_config: &IceoryxServiceConfig,
+    connection: &C,
+    failures: &FailureTracker,
+    policy: PropagationPolicy,
+    log_context: &LogContext,
+    report: &mut PropagationReport,
+) {
+    if failures.is_disabled() {
+        return;
+    }
+
+    let id = iox_service_config.service_id();
+    match connection.propagate() {
+        Ok(propagated) => {
+            failures.record_success();
+            report.propagated += propagated;
+        }
+        Err(e) if e.is_busy() => {
+            warn!("{}Propagation interrupted ({:?}): {}", log_context, id, e);
+            report
+                .failures
+                .push(ServicePropagationError::new(iox_service_config, e));
+        }
+        Err(e) => {
+            error!("{}Failed to propagate ({:?}): {}", log_context, id, e);
+            let consecutive_failures = failures.record_failure(e);
+            if let PropagationPolicy::DisableAfter(max_failures) = policy {
+                if consecutive_failures >= max_failures {
+                    error!(
+                        "{}DISABLED propagation ({:?}) after {} consecutive failures",
+                        log_context, id, consecutive_failures
+                    );
+                    failures.disable();
+                }
+            }
+            report
+                .failures
+                .push(ServicePropagationError::new(iox_service_config, e));
+        }
+    }
+}
+
+/// Returns control to the async runtime once before completing, the runtime-agnostic
+/// equivalent of `tokio::task::yield_now()`.
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct YieldNow {
+    yielded: bool,
+}
+
+#[cfg(feature = "async")]
+impl core::future::Future for YieldNow {
+    type Output = ();
+
+    fn poll(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        if self.yielded {
+            return core::task::Poll::Ready(());
+        }
+
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        core::task::Poll::Pending
+    }
+}
+
+/// Process a discovered service and create appropriate connections.
+///
+/// # Arguments
+///
+/// * `source` - The scope from which the service was discovered (Iceoryx, Zenoh, or Both),
+///   along with the logging context and the callbacks of the tunnel
+/// * `iox_service_config` - Configuration of the discovered Iceoryx service
+/// * `context` - The Iceoryx node, Zenoh session and key expressions to use for creating
+///   connections
+/// * `publish_subscribe_connections` - Map to store created publish-subscribe connections
+/// * `event_connections` - Map to store created event connections
+/// * `request_response_connections` - The request-response connections whose key
+///   expressions must not collide with the one of the service
+/// * `failed_services` - Map to store services for which no connection could be created, along
+///   with the scope they were discovered in
+///
+/// # Returns
+///
+/// * `Ok(true)` - If a new connection was created for the service
+/// * `Ok(false)` - If the service is already connected, failed before or is not supported
+/// * `Err(DiscoveryError::ServiceConnectionFailed)` - If the connection could not be created
+///   and failures are not recorded with the `strict-errors` feature
+fn on_discovery<'a, ServiceType: iceoryx2::service::Service>(
+    source: DiscoverySource<'_>,
+    iox_service_config: &IceoryxServiceConfig,
+    context: &ConnectionContext<'_, ServiceType>,
+    publish_subscribe_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'a, ServiceType>,
+    >,
+    event_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalEventConnection<'a, ServiceType>,
+    >,
+    request_response_connections: &HashMap<
+        IceoryxServiceId,
+        BidirectionalRequestResponseConnection<'_, ServiceType>,
+    >,
+    failed_services: &mut HashMap<IceoryxServiceId, (Scope, IceoryxServiceConfig)>,
+) -> Result<bool, DiscoveryError> {
+    let discovered_at = Instant::now();
+    let iox_service_id = iox_service_config.service_id();
+    if failed_services.contains_key(iox_service_id) {
+        return Ok(false);
+    }
+
+    // A service re-created with a different messaging pattern has a different id, so it is
+    // connected like a new service while the connection of the destroyed service is removed
+    // as stale, see remove_stale_connections()
+
+    // Messages of colliding services would be mixed on zenoh
+    if let Err(e) = check_key_expression_collision(
+        iox_service_config,
+        context.key_expr_mapper,
+        publish_subscribe_connections,
+        event_connections,
+        request_response_connections,
+    ) {
+        error!(
+            "{}COLLISION({}): {} [{}]: {:?}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            e
+        );
+        return Ok(false);
+    }
+
+    if let Err(e) = context.validate_z_key_expression(iox_service_config) {
+        error!(
+            "{}INVALID KEY EXPRESSION({}): {} [{}]: {:?}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            e
+        );
+        failed_services.insert(
+            iox_service_id.clone(),
+            (source.scope, iox_service_config.clone()),
+        );
+        return Ok(false);
+    }
+
+    if let Err(e) = context.validate_schema(iox_service_config) {
+        error!(
+            "{}SCHEMA VIOLATION({}): {} [{}]: {:?}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            e
+        );
+        failed_services.insert(
+            iox_service_id.clone(),
+            (source.scope, iox_service_config.clone()),
+        );
+        return Ok(false);
+    }
+
+    // Both services are tunneled, but remote subscribers may confuse them
+    if let Some(duplicate_service_id) = find_duplicate_name(
+        iox_service_config,
+        publish_subscribe_connections,
+        event_connections,
+    ) {
+        warn!(
+            "{}DUPLICATE NAME({}): {} [{}] has the same name as {}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            duplicate_service_id.as_str()
+        );
+    }
+
+    match iox_service_config.messaging_pattern() {
+        MessagingPattern::PublishSubscribe(_)
+            if !publish_subscribe_connections.contains_key(iox_service_id) =>
+        {
+            info!(
+                "{}DISCOVERED({}): PublishSubscribe {} [{}]",
+                source.log_context,
+                source.scope,
+                iox_service_id.as_str(),
+                iox_service_config.name()
+            );
+
+            let connection = BidirectionalPublishSubscribeConnection::create(
+                context.iox_node,
+                context.z_session,
+                iox_service_config,
+                &context.z_key_expression(iox_service_config),
+                context.connection_direction,
+                context.z_publisher_config(iox_service_config),
+                context.iox_service_limits,
+            );
+
+            #[cfg(feature = "strict-errors")]
+            let connection = match connection {
+                Ok(connection) => connection,
+                Err(e) => {
+                    error!(
+                        "{}FAILED({}): PublishSubscribe {} [{}]: {:?}",
+                        source.log_context,
+                        source.scope,
+                        iox_service_id.as_str(),
+                        iox_service_config.name(),
+                        e
+                    );
+                    failed_services.insert(
+                        iox_service_id.clone(),
+                        (source.scope, iox_service_config.clone()),
+                    );
+                    return Ok(false);
+                }
+            };
+            #[cfg(not(feature = "strict-errors"))]
+            let connection = connection.map_err(|e| {
+                error!(
+                    "{}FAILED({}): PublishSubscribe {} [{}]: {:?}",
+                    source.log_context,
+                    source.scope,
+                    iox_service_id.as_str(),
+                    iox_service_config.name(),
+                    e
+                );
+                DiscoveryError::service_connection_failed(iox_service_config, e)
+            })?;
+
+            connection.statistics().record_discovered_at(discovered_at);
+            publish_subscribe_connections.insert(iox_service_id.clone(), connection);
+            source.callbacks.created(
+                iox_service_config,
+                MessagingPatternKind::PublishSubscribe,
+                source.scope,
+            );
+            Ok(true)
+        }
+        MessagingPattern::Event(_) if !event_connections.contains_key(iox_service_id) => {
+            info!(
+                "{}DISCOVERED({}): Event {} [{}]",
+                source.log_context,
+                source.scope,
+                iox_service_id.as_str(),
+                iox_service_config.name()
+            );
 
-#[derive(Default)]
-pub struct TunnelConfig {
-    pub discovery_service: Option<String>,
+            let connection = BidirectionalEventConnection::create(
+                context.iox_node,
+                context.z_session,
+                iox_service_config,
+                &context.z_key_expression(iox_service_config),
+                context.z_allowed_destination,
+            );
+
+            #[cfg(feature = "strict-errors")]
+            let connection = match connection {
+                Ok(connection) => connection,
+                Err(e) => {
+                    error!(
+                        "{}FAILED({}): Event {} [{}]: {:?}",
+                        source.log_context,
+                        source.scope,
+                        iox_service_id.as_str(),
+                        iox_service_config.name(),
+                        e
+                    );
+                    failed_services.insert(
+                        iox_service_id.clone(),
+                        (source.scope, iox_service_config.clone()),
+                    );
+                    return Ok(false);
+                }
+            };
+            #[cfg(not(feature = "strict-errors"))]
+            let connection = connection.map_err(|e| {
+                error!(
+                    "{}FAILED({}): Event {} [{}]: {:?}",
+                    source.log_context,
+                    source.scope,
+                    iox_service_id.as_str(),
+                    iox_service_config.name(),
+                    e
+                );
+                DiscoveryError::service_connection_failed(iox_service_config, e)
+            })?;
+
+            connection.statistics().record_discovered_at(discovered_at);
+            event_connections.insert(iox_service_id.clone(), connection);
+            source.callbacks.created(
+                iox_service_config,
+                MessagingPatternKind::Event,
+                source.scope,
+            );
+            Ok(true)
+        }
+        _ => {
+            /* Not supported. Nothing to do. */
+            Ok(false)
+        }
+    }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub enum CreationError {
-    Error,
+/// Process a discovered request-response service and create the connection for it.
+///
+/// # Arguments
+///
+/// * `source` - The scope from which the service was discovered (Iceoryx, Zenoh, or Both),
+///   along with the logging context and the callbacks of the tunnel
+/// * `iox_service_config` - Configuration of the discovered Iceoryx service
+/// * `context` - The Iceoryx node, Zenoh session and key expressions to use for creating
+///   connections
+/// * `publish_subscribe_connections` - The publish-subscribe connections whose key
+///   expressions must not collide with the one of the service
+/// * `event_connections` - The event connections whose key expressions must not collide with
+///   the one of the service
+/// * `request_response_connections` - Map to store created request-response connections
+/// * `failed_services` - Map to store services for which no connection could be created, along
+///   with the scope they were discovered in
+///
+/// # Returns
+///
+/// * `Ok(true)` - If a new connection was created for the service
+/// * `Ok(false)` - If the service is already connected, failed before or is no
+///   request-response service
+/// * `Err(DiscoveryError::ServiceConnectionFailed)` - If the connection could not be created
+///   and failures are not recorded with the `strict-errors` feature
+fn on_request_response_discovery<'a, ServiceType: iceoryx2::service::Service>(
+    source: DiscoverySource<'_>,
+    iox_service_config: &IceoryxServiceConfig,
+    context: &ConnectionContext<'_, ServiceType>,
+    publish_subscribe_connections: &HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &HashMap<IceoryxServiceId, BidirectionalEventConnection<'_, ServiceType>>,
+    request_response_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalRequestResponseConnection<'a, ServiceType>,
+    >,
+    failed_services: &mut HashMap<IceoryxServiceId, (Scope, IceoryxServiceConfig)>,
+) -> Result<bool, DiscoveryError> {
+    let discovered_at = Instant::now();
+    let iox_service_id = iox_service_config.service_id();
+    if !matches!(
+        iox_service_config.messaging_pattern(),
+        MessagingPattern::RequestResponse(_)
+    ) || request_response_connections.contains_key(iox_service_id)
+        || failed_services.contains_key(iox_service_id)
+    {
+        return Ok(false);
+    }
+
+    // Messages of colliding services would be mixed on zenoh
+    if let Err(e) = check_key_expression_collision(
+        iox_service_config,
+        context.key_expr_mapper,
+        publish_subscribe_connections,
+        event_connections,
+        request_response_connections,
+    ) {
+        error!(
+            "{}COLLISION({}): {} [{}]: {:?}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            e
+        );
+        return Ok(false);
+    }
+
+    if let Err(e) = context.validate_z_key_expression(iox_service_config) {
+        error!(
+            "{}INVALID KEY EXPRESSION({}): {} [{}]: {:?}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            e
+        );
+        failed_services.insert(
+            iox_service_id.clone(),
+            (source.scope, iox_service_config.clone()),
+        );
+        return Ok(false);
+    }
+
+    if let Err(e) = context.validate_schema(iox_service_config) {
+        error!(
+            "{}SCHEMA VIOLATION({}): {} [{}]: {:?}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            e
+        );
+        failed_services.insert(
+            iox_service_id.clone(),
+            (source.scope, iox_service_config.clone()),
+        );
+        return Ok(false);
+    }
+
+    info!(
+        "{}DISCOVERED({}): RequestResponse {} [{}]",
+        source.log_context,
+        source.scope,
+        iox_service_id.as_str(),
+        iox_service_config.name()
+    );
+
+    let connection = BidirectionalRequestResponseConnection::create(
+        context.iox_node,
+        context.z_session,
+        iox_service_config,
+        &context.z_key_expression(iox_service_config),
+    );
+
+    #[cfg(feature = "strict-errors")]
+    let connection = match connection {
+        Ok(connection) => connection,
+        Err(e) => {
+            error!(
+                "{}FAILED({}): RequestResponse {} [{}]: {:?}",
+                source.log_context,
+                source.scope,
+                iox_service_id.as_str(),
+                iox_service_config.name(),
+                e
+            );
+            failed_services.insert(
+                iox_service_id.clone(),
+                (source.scope, iox_service_config.clone()),
+            );
+            return Ok(false);
+        }
+    };
+    #[cfg(not(feature = "strict-errors"))]
+    let connection = connection.map_err(|e| {
+        error!(
+            "{}FAILED({}): RequestResponse {} [{}]: {:?}",
+            source.log_context,
+            source.scope,
+            iox_service_id.as_str(),
+            iox_service_config.name(),
+            e
+        );
+        DiscoveryError::service_connection_failed(iox_service_config, e)
+    })?;
+
+    connection.statistics().record_discovered_at(discovered_at);
+    request_response_connections.insert(iox_service_id.clone(), connection);
+    source.callbacks.created(
+        iox_service_config,
+        MessagingPatternKind::RequestResponse,
+        source.scope,
+    );
+    Ok(true)
 }
 
-impl core::fmt::Display for CreationError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
-        core::write!(f, "CreationError::{self:?}")
+/// Returns the zenoh key expression on which the payloads, events or requests of the provided
+/// service are exchanged, as determined by the user-defined mapper if any.
+fn z_key_expression(
+    iox_service_config: &IceoryxServiceConfig,
+    key_expr_mapper: Option<&KeyExprMapper>,
+) -> String {
+    if let Some(key_expr_mapper) = key_expr_mapper {
+        return key_expr_mapper(iox_service_config);
+    }
+
+    let iox_service_id = iox_service_config.service_id();
+    match iox_service_config.messaging_pattern() {
+        MessagingPattern::Event(_) => keys::event(iox_service_id),
+        MessagingPattern::RequestResponse(_) => keys::request_response(iox_service_id),
+        _ => keys::publish_subscribe(iox_service_id),
     }
 }
 
-impl core::error::Error for CreationError {}
+/// Checks whether the zenoh key expression of the provided service is already used by the
+/// connection of a different service.
+///
+/// A collision occurs when the key expression of an existing connection equals the one of
+/// the service, e.g. because the [`TunnelConfig::key_expr_mapper`] maps two service names
+/// onto the same key expression, or when an existing connection with the same service id
+/// belongs to a service with a different name, i.e. the service name hashes collide.
+fn check_key_expression_collision<ServiceType: iceoryx2::service::Service>(
+    iox_service_config: &IceoryxServiceConfig,
+    key_expr_mapper: Option<&KeyExprMapper>,
+    publish_subscribe_connections: &HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &HashMap<IceoryxServiceId, BidirectionalEventConnection<'_, ServiceType>>,
+    request_response_connections: &HashMap<
+        IceoryxServiceId,
+        BidirectionalRequestResponseConnection<'_, ServiceType>,
+    >,
+) -> Result<(), CreationError> {
+    let iox_service_id = iox_service_config.service_id();
+    let connected_service_config = publish_subscribe_connections
+        .get(iox_service_id)
+        .map(|connection| connection.service_config())
+        .or_else(|| {
+            event_connections
+                .get(iox_service_id)
+                .map(|connection| connection.service_config())
+        })
+        .or_else(|| {
+            request_response_connections
+                .get(iox_service_id)
+                .map(|connection| connection.service_config())
+        });
+    match connected_service_config {
+        // The service itself is connected already
+        Some(existing) if existing.name() == iox_service_config.name() => return Ok(()),
+        Some(existing) => {
+            return Err(CreationError::KeyExpressionCollision {
+                conflicting_service: existing.service_id().clone(),
+            })
+        }
+        None => (),
+    }
+
+    let existing_connections = publish_subscribe_connections
+        .values()
+        .map(|connection| (connection.service_config(), connection.z_key_expression()))
+        .chain(
+            event_connections
+                .values()
+                .map(|connection| (connection.service_config(), connection.z_key_expression())),
+        )
+        .chain(
+            request_response_connections
+                .values()
+                .map(|connection| (connection.service_config(), connection.z_key_expression())),
+        );
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub enum DiscoveryError {
-    Error,
+    let z_key = z_key_expression(iox_service_config, key_expr_mapper);
+    for (existing, existing_z_key) in existing_connections {
+        if existing_z_key == z_key {
+            return Err(CreationError::KeyExpressionCollision {
+                conflicting_service: existing.service_id().clone(),
+            });
+        }
+    }
+
+    Ok(())
 }
 
-impl core::fmt::Display for DiscoveryError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
-        core::write!(f, "DiscoveryError::{self:?}")
+/// Checks the message types of a service against the schema registry, services are accepted
+/// if no registry is enforced.
+fn validate_schema(
+    iox_service_config: &IceoryxServiceConfig,
+    schema_registry: Option<&(dyn SchemaRegistry + Send + Sync)>,
+    reject_unknown_services: bool,
+) -> Result<(), SchemaViolation> {
+    let Some(schema_registry) = schema_registry else {
+        return Ok(());
+    };
+
+    match schema_registry.lookup(iox_service_config.name().as_str()) {
+        Some(expected) if Some(&expected) == TypeDescriptor::of(iox_service_config).as_ref() => {
+            Ok(())
+        }
+        Some(_) => Err(SchemaViolation::TypeMismatch),
+        None if reject_unknown_services => Err(SchemaViolation::UnknownService),
+        None => Ok(()),
     }
 }
 
-impl core::error::Error for DiscoveryError {}
+/// Checks that the zenoh key expression of the provided service is a valid key expression,
+/// which is not guaranteed when it is determined by a user-defined mapper.
+fn validate_z_key_expression(
+    iox_service_config: &IceoryxServiceConfig,
+    key_expr_mapper: Option<&KeyExprMapper>,
+) -> Result<(), CreationError> {
+    let z_key = z_key_expression(iox_service_config, key_expr_mapper);
+    let reason = match KeyExpr::try_from(z_key.as_str()) {
+        Ok(_) => return Ok(()),
+        Err(e) => e.to_string(),
+    };
 
-/// Defines the operational scope for tunnel services.
-///
-/// This enum specifies which environment to use for tunnel operations:
-/// - `Iceoryx`: Only operate within the local Iceoryx environment
-/// - `Zenoh`: Only operate through the Zenoh network
-/// - `Both`: Operate in both Iceoryx and Zenoh environments
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub enum Scope {
-    Iceoryx,
-    Zenoh,
-    Both,
+    Err(CreationError::InvalidKeyExpression {
+        service_name: iox_service_config.name().to_string(),
+        key_expr: z_key,
+        reason,
+    })
 }
 
-impl core::fmt::Display for Scope {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Scope::Iceoryx => write!(f, "iceoryx"),
-            Scope::Zenoh => write!(f, "zenoh"),
-            Scope::Both => write!(f, "both"),
+/// Returns the id of a tunneled service with the same name and messaging pattern as the provided,
+/// not yet tunneled service.
+fn find_duplicate_name<'c, ServiceType: iceoryx2::service::Service>(
+    iox_service_config: &IceoryxServiceConfig,
+    publish_subscribe_connections: &'c HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &'c HashMap<IceoryxServiceId, BidirectionalEventConnection<'_, ServiceType>>,
+) -> Option<&'c IceoryxServiceId> {
+    let iox_service_id = iox_service_config.service_id();
+    let has_same_name = |existing: &IceoryxServiceConfig| {
+        existing.name() == iox_service_config.name() && existing.service_id() != iox_service_id
+    };
+
+    match iox_service_config.messaging_pattern() {
+        MessagingPattern::PublishSubscribe(_)
+            if !publish_subscribe_connections.contains_key(iox_service_id) =>
+        {
+            publish_subscribe_connections
+                .iter()
+                .find(|(_, connection)| has_same_name(connection.service_config()))
+                .map(|(id, _)| id)
+        }
+        MessagingPattern::Event(_) if !event_connections.contains_key(iox_service_id) => {
+            event_connections
+                .iter()
+                .find(|(_, connection)| has_same_name(connection.service_config()))
+                .map(|(id, _)| id)
         }
+        _ => None,
     }
 }
 
-/// A tunnel for propagating iceoryx2 payloads across hosts via the Zenoh network middleware.
-pub struct Tunnel<'a, ServiceType: iceoryx2::service::Service> {
-    z_session: ZenohSession,
-    z_discovery: ZenohDiscovery<'a, ServiceType>,
-    iox_node: IceoryxNode<ServiceType>,
-    iox_discovery: IceoryxDiscovery<ServiceType>,
-    publish_subscribe_connectons:
-        HashMap<IceoryxServiceId, BidirectionalPublishSubscribeConnection<'a, ServiceType>>,
-    event_connections: HashMap<IceoryxServiceId, BidirectionalEventConnection<'a, ServiceType>>,
+/// Index the id of a connected service by its name, keeping the first service if multiple
+/// services share the same name.
+fn index_service_name(
+    service_ids_by_name: &mut HashMap<String, IceoryxServiceId>,
+    iox_service_config: &IceoryxServiceConfig,
+) {
+    service_ids_by_name
+        .entry(iox_service_config.name().to_string())
+        .or_insert_with(|| iox_service_config.service_id().clone());
 }
 
-impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
-    /// Creates a new tunnel with the provided configuration.
-    ///
-    /// # Arguments
-    ///
-    /// * `tunnel_config` - Tunnel configuration
-    /// * `iox_config` - Iceoryx configuration to be used
-    /// * `z_config` - Zenoh configuration to be used
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(Self)` - A new tunnel instance if creation was successful
-    /// * `Err(CreationError)` - If any part of the tunnel creation failed
-    pub fn create(
-        tunnel_config: &TunnelConfig,
-        iox_config: &IceoryxConfig,
-        z_config: &ZenohConfig,
-    ) -> Result<Self, CreationError> {
-        info!("STARTING Zenoh Tunnel");
+/// Apply the connection settings configured for the tunnel to a newly connected service and
+/// attach the additional zenoh sessions of the tunnel to it.
+fn apply_connection_defaults<ServiceType: iceoryx2::service::Service>(
+    publish_subscribe_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalEventConnection<'_, ServiceType>,
+    >,
+    request_response_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalRequestResponseConnection<'_, ServiceType>,
+    >,
+    iox_service_config: &IceoryxServiceConfig,
+    connection_defaults: &ConnectionDefaults,
+    z_additional_sessions: &[(String, ZenohSession)],
+    z_allowed_destination: Locality,
+) {
+    if let Some(connection) = publish_subscribe_connections.get_mut(iox_service_config.service_id())
+    {
+        connection.set_iceoryx_receive_timeout(connection_defaults.iceoryx_receive_timeout);
+        connection
+            .set_backpressure_policy(connection_defaults.backpressure_policy.unwrap_or_default());
+        connection.set_keep_alive_interval(connection_defaults.keep_alive_interval);
+        if connection
+            .set_history_replay(connection_defaults.replay_history_on_connect)
+            .is_err()
+        {
+            warn!(
+                "Failed to enable the history replay of {} [{}]",
+                iox_service_config.service_id().as_str(),
+                iox_service_config.name()
+            );
+        }
+        #[cfg(feature = "checksum")]
+        connection.set_checksum_verification(connection_defaults.checksum_verification);
+        connection.set_message_id_tracking(connection_defaults.track_message_ids);
+        #[cfg(feature = "compression")]
+        if let Some(compression_threshold) = connection_defaults.compression_threshold {
+            connection.set_compression_codec(connection_defaults.compression_codec);
+            connection.set_compression_threshold(compression_threshold);
+        }
+    }
 
-        let z_session = zenoh::open(z_config.clone())
-            .wait()
-            .map_err(|_e| CreationError::Error)?;
-        let z_discovery = ZenohDiscovery::create(&z_session).map_err(|_e| CreationError::Error)?;
+    if let Some(connection) = event_connections.get_mut(iox_service_config.service_id()) {
+        connection.set_iceoryx_receive_timeout(connection_defaults.iceoryx_receive_timeout);
+        connection.set_event_batching(connection_defaults.event_batching);
+        connection.set_keep_alive_interval(connection_defaults.keep_alive_interval);
+    }
 
-        let iox_node = NodeBuilder::new()
-            .config(iox_config)
-            .create::<Service>()
-            .map_err(|_e| CreationError::Error)?;
-        let iox_discovery =
-            IceoryxDiscovery::create(iox_config, &iox_node, &tunnel_config.discovery_service)
-                .map_err(|_e| CreationError::Error)?;
+    if let Some(connection) = request_response_connections.get_mut(iox_service_config.service_id())
+    {
+        connection.set_iceoryx_receive_timeout(connection_defaults.iceoryx_receive_timeout);
+    }
 
-        let publish_subscribe_connectons: HashMap<
-            IceoryxServiceId,
-            BidirectionalPublishSubscribeConnection<Service>,
-        > = HashMap::new();
-        let event_connections: HashMap<IceoryxServiceId, BidirectionalEventConnection<Service>> =
-            HashMap::new();
+    for (label, z_session) in z_additional_sessions {
+        let attached = match (
+            publish_subscribe_connections.get_mut(iox_service_config.service_id()),
+            event_connections.get_mut(iox_service_config.service_id()),
+        ) {
+            (Some(connection), _) => connection
+                .attach_zenoh_session(z_session, z_allowed_destination)
+                .is_ok(),
+            (_, Some(connection)) => connection
+                .attach_zenoh_session(z_session, z_allowed_destination)
+                .is_ok(),
+            (None, None) => true,
+        };
+        if !attached {
+            warn!(
+                "Failed to attach zenoh session {} to {} [{}]",
+                label,
+                iox_service_config.service_id().as_str(),
+                iox_service_config.name()
+            );
+        }
+    }
+}
 
-        Ok(Self {
-            z_session,
-            z_discovery,
-            iox_node,
-            iox_discovery,
-            publish_subscribe_connectons,
-            event_connections,
-        })
+/// Panics with the names of the services if their number differs from the expected one.
+#[track_caller]
+fn assert_service_names_len(kind: &str, expected: usize, mut names: Vec<String>) {
+    if names.len() == expected {
+        return;
     }
 
-    /// Discover iceoryx services across all connected hosts.
-    ///
-    /// # Arguments
-    ///
-    /// * `scope` - Determines the discovery scope
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - If discovery was successful
-    /// * `Err(DiscoveryError)` - If discovery failed
-    pub fn discover(&mut self, scope: Scope) -> Result<(), DiscoveryError> {
-        if scope == Scope::Iceoryx || scope == Scope::Both {
-            self.iox_discovery
-                .discover(&mut |iox_service_config| {
-                    on_discovery(
-                        Scope::Iceoryx,
-                        iox_service_config,
-                        &self.iox_node,
-                        &self.z_session,
-                        &mut self.publish_subscribe_connectons,
-                        &mut self.event_connections,
-                    )
-                })
-                .map_err(|_e| DiscoveryError::Error)?;
-        }
-
-        if scope == Scope::Zenoh || scope == Scope::Both {
-            self.z_discovery
-                .discover(&mut |iox_service_config| {
-                    on_discovery(
-                        Scope::Zenoh,
-                        iox_service_config,
-                        &self.iox_node,
-                        &self.z_session,
-                        &mut self.publish_subscribe_connectons,
-                        &mut self.event_connections,
-                    )
-                })
-                .map_err(|_e| DiscoveryError::Error)?;
+    names.sort_unstable();
+    panic!(
+        "expected {} {} services but found {}: [{}]",
+        expected,
+        kind,
+        names.len(),
+        names.join(", ")
+    );
+}
+
+/// Attaches a zenoh session to all publish-subscribe and event connections.
+fn attach_zenoh_session<ServiceType: iceoryx2::service::Service>(
+    publish_subscribe_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalEventConnection<'_, ServiceType>,
+    >,
+    label: &str,
+    z_session: &ZenohSession,
+    z_allowed_destination: Locality,
+) {
+    for (id, connection) in publish_subscribe_connections.iter_mut() {
+        if connection
+            .attach_zenoh_session(z_session, z_allowed_destination)
+            .is_err()
+        {
+            warn!(
+                "Failed to attach zenoh session {} to {}",
+                label,
+                id.as_str()
+            );
+        }
+    }
+    for (id, connection) in event_connections.iter_mut() {
+        if connection
+            .attach_zenoh_session(z_session, z_allowed_destination)
+            .is_err()
+        {
+            warn!(
+                "Failed to attach zenoh session {} to {}",
+                label,
+                id.as_str()
+            );
         }
+    }
+}
 
-        Ok(())
+/// Remove the connections of services discovered in the provided scope that are no longer
+/// alive, releasing the iceoryx and zenoh resources held by them.
+///
+/// # Arguments
+///
+/// * `source` - The scope whose connections are checked, along with the logging context and
+///   the callbacks of the tunnel
+/// * `live_services` - The ids of the services that are still alive in the scope
+/// * `publish_subscribe_connections` - Map of publish-subscribe connections to clean up
+/// * `event_connections` - Map of event connections to clean up
+/// * `request_response_connections` - Map of request-response connections to clean up
+/// * `service_ids_by_name` - Index of the service ids by name to clean up
+/// * `service_sources` - Map of the discovery scope of each service to clean up
+fn remove_stale_connections<ServiceType: iceoryx2::service::Service>(
+    source: DiscoverySource<'_>,
+    live_services: &HashSet<IceoryxServiceId>,
+    publish_subscribe_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalEventConnection<'_, ServiceType>,
+    >,
+    request_response_connections: &mut HashMap<
+        IceoryxServiceId,
+        BidirectionalRequestResponseConnection<'_, ServiceType>,
+    >,
+    service_ids_by_name: &mut HashMap<String, IceoryxServiceId>,
+    service_sources: &mut HashMap<IceoryxServiceId, Scope>,
+) {
+    let stale_services: Vec<IceoryxServiceId> = service_sources
+        .iter()
+        .filter(|(id, scope)| **scope == source.scope && !live_services.contains(*id))
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    for id in stale_services {
+        info!(
+            "{}REMOVED {}: service no longer exists",
+            source.log_context,
+            id.as_str()
+        );
+        publish_subscribe_connections.remove(&id);
+        event_connections.remove(&id);
+        request_response_connections.remove(&id);
+        service_ids_by_name.retain(|_, service_id| *service_id != id);
+        service_sources.remove(&id);
+        source.callbacks.removed(&id);
     }
+}
 
-    /// Propagates payloads between all connected hosts.
-    pub fn propagate(&self) {
-        // TODO(correctioness): consolidate and forward errors
-        for (id, connection) in &self.publish_subscribe_connectons {
-            if let Err(e) = connection.propagate() {
-                error!("Failed to propagate ({:?}): {}", id, e);
-            }
-        }
+/// Returns `true` if a discovered service passes the service filter and, when deduplicating
+/// discoveries, was not processed by the current discovery yet.
+fn is_newly_discovered(
+    service_filter: &ServiceFilter,
+    discovered_in_run: &mut Option<HashSet<IceoryxServiceId>>,
+    iox_service_config: &IceoryxServiceConfig,
+) -> bool {
+    if !service_filter.allows(iox_service_config) {
+        return false;
+    }
 
-        for (id, connection) in &self.event_connections {
-            if let Err(e) = connection.propagate() {
-                error!("Failed to propagate ({:?}): {}", id, e);
-            }
+    match discovered_in_run {
+        Some(discovered_in_run) => {
+            discovered_in_run.insert(iox_service_config.service_id().clone())
         }
+        None => true,
     }
+}
 
-    /// Returns a list of all service IDs that are currently being tunneled.
-    ///
-    /// # Returns
-    ///
-    /// * `Vec<String>` - A vector containing the string representation of all service IDs
-    ///   that are currently being tunneled through this tunnel instance.
-    pub fn tunneled_services(&self) -> Vec<String> {
-        self.publish_subscribe_connectons
-            .keys()
-            .chain(self.event_connections.keys())
-            .map(|id| id.as_str().to_string())
-            .collect()
-    }
+/// Returns `true` if [`on_discovery()`] creates a connection counting towards the maximum
+/// number of connections for a discovered service, i.e. if it is a publish-subscribe or
+/// event service that did not fail before and passes the checks of [`on_discovery()`].
+fn is_limited_connection<ServiceType: iceoryx2::service::Service>(
+    iox_service_config: &IceoryxServiceConfig,
+    context: &ConnectionContext<'_, ServiceType>,
+    publish_subscribe_connections: &HashMap<
+        IceoryxServiceId,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
+    >,
+    event_connections: &HashMap<IceoryxServiceId, BidirectionalEventConnection<'_, ServiceType>>,
+    request_response_connections: &HashMap<
+        IceoryxServiceId,
+        BidirectionalRequestResponseConnection<'_, ServiceType>,
+    >,
+    failed_services: &HashMap<IceoryxServiceId, (Scope, IceoryxServiceConfig)>,
+) -> bool {
+    matches!(
+        iox_service_config.messaging_pattern(),
+        MessagingPattern::PublishSubscribe(_) | MessagingPattern::Event(_)
+    ) && !failed_services.contains_key(iox_service_config.service_id())
+        && check_key_expression_collision(
+            iox_service_config,
+            context.key_expr_mapper,
+            publish_subscribe_connections,
+            event_connections,
+            request_response_connections,
+        )
+        .is_ok()
+        && context
+            .validate_z_key_expression(iox_service_config)
+            .is_ok()
+        && context.validate_schema(iox_service_config).is_ok()
 }
 
-/// Process a discovered service and create appropriate connections.
+/// Ensure that a connection can be created for a discovered service without exceeding the
+/// configured maximum number of connections, evicting the connection that was active least
+/// recently if the policy requires to.
 ///
 /// # Arguments
 ///
-/// * `source` - The scope from which the service was discovered (Iceoryx, Zenoh, or Both)
-/// * `iox_service_config` - Configuration of the discovered Iceoryx service
-/// * `iox_node` - The Iceoryx node instance to use for creating connections
-/// * `z_session` - The Zenoh session to use for creating connections
-/// * `publish_subscribe_connections` - Map to store created publish-subscribe connections
-/// * `event_connections` - Map to store created event connections
+/// * `source` - The scope in which the service was discovered, along with the logging
+///   context and the callbacks of the tunnel
 ///
 /// # Returns
 ///
-/// This function doesn't return a value. It updates the connection maps in-place.
-fn on_discovery<'a, ServiceType: iceoryx2::service::Service>(
-    source: Scope,
+/// * `true` - If the service is already connected or a connection can be created
+/// * `false` - If the maximum number of connections is reached and the service is skipped
+fn make_room_for_service<ServiceType: iceoryx2::service::Service>(
     iox_service_config: &IceoryxServiceConfig,
-    iox_node: &IceoryxNode<ServiceType>,
-    z_session: &ZenohSession,
+    connection_limit: &mut ConnectionLimit,
     publish_subscribe_connections: &mut HashMap<
         IceoryxServiceId,
-        BidirectionalPublishSubscribeConnection<'a, ServiceType>,
+        BidirectionalPublishSubscribeConnection<'_, ServiceType>,
     >,
     event_connections: &mut HashMap<
         IceoryxServiceId,
-        BidirectionalEventConnection<'a, ServiceType>,
+        BidirectionalEventConnection<'_, ServiceType>,
     >,
-) {
+    service_ids_by_name: &mut HashMap<String, IceoryxServiceId>,
+    service_sources: &mut HashMap<IceoryxServiceId, Scope>,
+    source: DiscoverySource<'_>,
+) -> bool {
+    let Some(max_connections) = connection_limit.max_connections else {
+        return true;
+    };
+
     let iox_service_id = iox_service_config.service_id();
-    match iox_service_config.messaging_pattern() {
-        MessagingPattern::PublishSubscribe(_) => {
-            if !publish_subscribe_connections.contains_key(iox_service_id) {
-                info!(
-                    "DISCOVERED({}): PublishSubscribe {} [{}]",
-                    source,
+    if publish_subscribe_connections.contains_key(iox_service_id)
+        || event_connections.contains_key(iox_service_id)
+    {
+        return true;
+    }
+
+    while publish_subscribe_connections.len() + event_connections.len() >= max_connections {
+        if connection_limit.policy == MaxConnectionsPolicy::SkipNew {
+            if connection_limit
+                .pending
+                .iter()
+                .all(|(_, pending)| pending.service_id() != iox_service_id)
+            {
+                warn!(
+                    "{}SKIPPED {} [{}]: maximum of {} connections reached, pending",
+                    source.log_context,
                     iox_service_id.as_str(),
-                    iox_service_config.name()
+                    iox_service_config.name(),
+                    max_connections
                 );
-
-                let connection = BidirectionalPublishSubscribeConnection::create(
-                    iox_node,
-                    z_session,
-                    iox_service_config,
-                )
-                .unwrap();
-
-                publish_subscribe_connections.insert(iox_service_id.clone(), connection);
+                connection_limit
+                    .pending
+                    .push_back((source.scope, iox_service_config.clone()));
             }
+            return false;
         }
-        MessagingPattern::Event(_) => {
-            if !event_connections.contains_key(iox_service_id) {
-                info!(
-                    "DISCOVERED({}): Event {} [{}]",
-                    source,
-                    iox_service_id.as_str(),
-                    iox_service_config.name()
-                );
 
-                let connection =
-                    BidirectionalEventConnection::create(iox_node, z_session, iox_service_config)
-                        .unwrap();
+        let least_recently_used = publish_subscribe_connections
+            .iter()
+            .map(|(id, connection)| (id, connection.statistics().last_active()))
+            .chain(
+                event_connections
+                    .iter()
+                    .map(|(id, connection)| (id, connection.statistics().last_active())),
+            )
+            .min_by_key(|(_, last_active)| *last_active)
+            .map(|(id, _)| id.clone());
+        let Some(least_recently_used) = least_recently_used else {
+            // A maximum of zero connections
+            return false;
+        };
 
-                event_connections.insert(iox_service_id.clone(), connection);
-            }
-        }
-        _ => { /* Not supported. Nothing to do. */ }
+        info!(
+            "{}EVICTED {} to connect {} [{}]",
+            source.log_context,
+            least_recently_used.as_str(),
+            iox_service_id.as_str(),
+            iox_service_config.name()
+        );
+        publish_subscribe_connections.remove(&least_recently_used);
+        event_connections.remove(&least_recently_used);
+        service_ids_by_name.retain(|_, id| *id != least_recently_used);
+        service_sources.remove(&least_recently_used);
+        source.callbacks.removed(&least_recently_used);
     }
+
+    true
 }
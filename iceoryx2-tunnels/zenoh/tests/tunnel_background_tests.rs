@@ -0,0 +1,109 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod zenoh_tunnel_background {
+
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_tunnel_background_zenoh_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn tunnel_is_send_and_sync_for_thread_safe_service_types() {
+        fn assert_send_and_sync<T: Send + Sync>() {}
+
+        assert_send_and_sync::<Tunnel<'static, iceoryx2::service::ipc_threadsafe::Service>>();
+        assert_send_and_sync::<Tunnel<'static, iceoryx2::service::local_threadsafe::Service>>();
+    }
+
+    #[test]
+    fn tunnel_can_be_created_in_background() {
+        // the tunnel can only be moved across threads for thread-safe service types
+        type S = iceoryx2::service::ipc_threadsafe::Service;
+
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let tunnel =
+            Tunnel::<S>::create_in_background(&TunnelConfig::default(), &iox_config, &z_config);
+        let mut tunnel = tunnel.wait(Duration::from_secs(10)).unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+
+    #[test]
+    fn tunnel_discovers_and_propagates_in_thread_until_stopped() {
+        // the tunnel can only be moved across threads for thread-safe service types
+        type S = iceoryx2::service::ipc_threadsafe::Service;
+
+        const TIMEOUT: Duration = Duration::from_secs(10);
+
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let tunnel = Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let handle = tunnel.propagate_in_thread(Scope::Iceoryx, Duration::from_millis(10));
+
+        let start = std::time::Instant::now();
+        while handle.tunneled_services().is_empty() && start.elapsed() < TIMEOUT {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_that!(handle.tunneled_services(), len 1);
+        assert_that!(
+            handle.tunneled_services()[0],
+            eq iox_service.service_id().as_str()
+        );
+
+        let tunnel = handle.stop();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+}
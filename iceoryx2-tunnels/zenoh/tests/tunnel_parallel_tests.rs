@@ -0,0 +1,76 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "rayon")]
+
+mod zenoh_tunnel_parallel {
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_tunnel_parallel_zenoh_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn propagates_all_connections_in_parallel() {
+        // the connections are only `Sync` for thread-safe service types
+        type S = iceoryx2::service::ipc_threadsafe::Service;
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier = iox_event_service.notifier_builder().create().unwrap();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_publish_subscribe_service
+            .publisher_builder()
+            .create()
+            .unwrap();
+
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 2);
+
+        // ==================== TEST =====================
+
+        iox_notifier.notify().unwrap();
+        iox_publisher.send_copy(42).unwrap();
+
+        let report = tunnel.propagate_parallel();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 2);
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod zenoh_tunnel_router {
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_tunnel_router_zenoh_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn dispatches_discovery_and_propagation_to_all_tunnels<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // [[ HOST B ]]
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_b =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_b, &z_config_b).unwrap();
+
+        // Notifier on host a only
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        let mut router = TunnelRouter::<S>::new();
+        assert_that!(router.is_empty(), eq true);
+        router.add_tunnel("a", tunnel_a);
+        router.add_tunnel("b", tunnel_b);
+        assert_that!(router.len(), eq 2);
+
+        // ==================== TEST =====================
+
+        router.discover_all(Scope::Iceoryx).unwrap();
+        assert_that!(router.tunnel("a").unwrap().tunneled_service_infos().len(), eq 1);
+        assert_that!(router.tunnel("b").unwrap().tunneled_service_infos().len(), eq 0);
+        assert_that!(router.tunnel("c").is_none(), eq true);
+
+        iox_notifier_a.notify().unwrap();
+        let reports = router.propagate_all();
+        assert_that!(reports.len(), eq 2);
+        assert_that!(reports[0].0, eq "a");
+        assert_that!(reports[0].1.is_success(), eq true);
+        assert_that!(reports[0].1.propagated(), eq 1);
+        assert_that!(reports[1].0, eq "b");
+        assert_that!(reports[1].1.propagated(), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
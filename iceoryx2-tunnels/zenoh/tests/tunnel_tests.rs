@@ -13,6 +13,7 @@
 #[generic_tests::define]
 mod zenoh_tunnel {
 
+    use std::collections::HashMap;
     use std::time::Duration;
 
     use iceoryx2::prelude::*;
@@ -21,6 +22,7 @@ mod zenoh_tunnel {
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::{assert_that, test_fail};
     use iceoryx2_services_discovery::service_discovery::Config as DiscoveryConfig;
+    use iceoryx2_services_discovery::service_discovery::Discovery as DiscoveryUpdate;
     use iceoryx2_services_discovery::service_discovery::Service as DiscoveryService;
     use iceoryx2_tunnels_zenoh::*;
 
@@ -125,10 +127,11 @@ mod zenoh_tunnel {
         let z_config_a = zenoh::Config::default();
         let tunnel_config = TunnelConfig {
             discovery_service: Some("iox2://discovery/services/".into()),
+            ..Default::default()
         };
 
         let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config_a).unwrap();
-        assert_that!(tunnel.tunneled_services().len(), eq 0);
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 0);
 
         // Service
         let iox_node = NodeBuilder::new()
@@ -152,10 +155,10 @@ mod zenoh_tunnel {
         // [[ HOST A ]]
         // Respond to discovered services
         tunnel.discover(Scope::Iceoryx).unwrap();
-        assert_that!(tunnel.tunneled_services().len(), eq 1);
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 1);
         assert_that!(tunnel
-            .tunneled_services()
-            .contains(&String::from(iox_service.service_id().as_str())), eq true);
+            .tunneled_service_infos()
+            .iter().any(|info| info.service_id == *iox_service.service_id()), eq true);
     }
 
     #[test]
@@ -171,7 +174,7 @@ mod zenoh_tunnel {
         let iox_config = generate_isolated_config();
         let tunnel_config = TunnelConfig::default();
         let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
-        assert_that!(tunnel.tunneled_services().len(), eq 0);
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 0);
 
         // Service
         let iox_node = NodeBuilder::new()
@@ -191,10 +194,10 @@ mod zenoh_tunnel {
         // [[ HOST A ]]
         // Discover
         tunnel.discover(Scope::Iceoryx).unwrap();
-        assert_that!(tunnel.tunneled_services().len(), eq 1);
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 1);
         assert_that!(tunnel
-            .tunneled_services()
-            .contains(&String::from(iox_service.service_id().as_str())), eq true);
+            .tunneled_service_infos()
+            .iter().any(|info| info.service_id == *iox_service.service_id()), eq true);
     }
 
     #[test]
@@ -214,7 +217,7 @@ mod zenoh_tunnel {
         let tunnel_config_a = TunnelConfig::default();
         let mut tunnel_a =
             Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
-        assert_that!(tunnel_a.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
 
         // [[ HOST B ]]
         // Tunnel
@@ -223,7 +226,7 @@ mod zenoh_tunnel {
         let tunnel_config_b = TunnelConfig::default();
         let mut tunnel_b =
             Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
-        assert_that!(tunnel_b.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 0);
 
         // Service
         let iox_node_b = NodeBuilder::new()
@@ -243,15 +246,15 @@ mod zenoh_tunnel {
         // [[ HOST A ]]
         // Discover - nothing should be discovered
         tunnel_a.discover(Scope::Zenoh).unwrap();
-        assert_that!(tunnel_a.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
 
         // [[ HOST B ]]
         // Discover - service should be announced
         tunnel_b.discover(Scope::Iceoryx).unwrap();
-        assert_that!(tunnel_b.tunneled_services().len(), eq 1);
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 1);
         assert_that!(tunnel_b
-            .tunneled_services()
-            .contains(&String::from(iox_service_b.service_id().as_str())), eq true);
+            .tunneled_service_infos()
+            .iter().any(|info| info.service_id == *iox_service_b.service_id()), eq true);
 
         // [[ HOST A ]]
         // Discover - announced service should be discovered via Zenoh
@@ -259,9 +262,10 @@ mod zenoh_tunnel {
             || {
                 tunnel_a.discover(Scope::Zenoh).unwrap();
 
-                let tunneled_services = tunnel_a.tunneled_services();
-                let success =
-                    tunneled_services.contains(&String::from(iox_service_b.service_id().as_str()));
+                let tunneled_services = tunnel_a.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_b.service_id());
 
                 if success {
                     return Ok(());
@@ -297,7 +301,7 @@ mod zenoh_tunnel {
         let tunnel_config_a = TunnelConfig::default();
         let mut tunnel_a =
             Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
-        assert_that!(tunnel_a.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
 
         // Publisher
         let iox_node_a = NodeBuilder::new()
@@ -313,10 +317,10 @@ mod zenoh_tunnel {
 
         // Discover
         tunnel_a.discover(Scope::Iceoryx).unwrap();
-        let tunneled_services_a = tunnel_a.tunneled_services();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
         assert_that!(tunneled_services_a.len(), eq 1);
         assert_that!(tunneled_services_a
-            .contains(&String::from(iox_service_a.service_id().as_str())), eq true);
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
 
         // [[ HOST B ]]
         // Tunnel
@@ -325,16 +329,17 @@ mod zenoh_tunnel {
         let tunnel_config_b = TunnelConfig::default();
         let mut tunnel_b =
             Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
-        assert_that!(tunnel_b.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 0);
 
         // Discover
         retry(
             || {
                 tunnel_b.discover(Scope::Zenoh).unwrap();
 
-                let tunneled_services = tunnel_b.tunneled_services();
-                let success =
-                    tunneled_services.contains(&String::from(iox_service_a.service_id().as_str()));
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
 
                 if success {
                     return Ok(());
@@ -441,7 +446,7 @@ mod zenoh_tunnel {
         let tunnel_config_a = TunnelConfig::default();
         let mut tunnel_a =
             Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
-        assert_that!(tunnel_a.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
 
         // Publisher
         let iox_node_a = NodeBuilder::new()
@@ -461,10 +466,10 @@ mod zenoh_tunnel {
 
         // Discover
         tunnel_a.discover(Scope::Iceoryx).unwrap();
-        let tunneled_services_a = tunnel_a.tunneled_services();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
         assert_that!(tunneled_services_a.len(), eq 1);
         assert_that!(tunneled_services_a
-            .contains(&String::from(iox_service_a.service_id().as_str())), eq true);
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
 
         // [[ HOST B ]]
         // Tunnel
@@ -473,16 +478,17 @@ mod zenoh_tunnel {
         let tunnel_config_b = TunnelConfig::default();
         let mut tunnel_b =
             Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
-        assert_that!(tunnel_b.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 0);
 
         // Discover
         retry(
             || {
                 tunnel_b.discover(Scope::Zenoh).unwrap();
 
-                let tunneled_services = tunnel_b.tunneled_services();
-                let success =
-                    tunneled_services.contains(&String::from(iox_service_a.service_id().as_str()));
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
 
                 if success {
                     return Ok(());
@@ -613,10 +619,10 @@ mod zenoh_tunnel {
 
         // Discover
         tunnel_a.discover(Scope::Iceoryx).unwrap();
-        let tunneled_services_a = tunnel_a.tunneled_services();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
         assert_that!(tunneled_services_a.len(), eq 1);
         assert_that!(tunneled_services_a
-            .contains(&String::from(iox_service_a.service_id().as_str())), eq true);
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
 
         // ==================== TEST =====================
 
@@ -673,10 +679,10 @@ mod zenoh_tunnel {
 
         // Discover
         tunnel_a.discover(Scope::Iceoryx).unwrap();
-        let tunneled_services_a = tunnel_a.tunneled_services();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
         assert_that!(tunneled_services_a.len(), eq 1);
         assert_that!(tunneled_services_a
-            .contains(&String::from(iox_service_a.service_id().as_str())), eq true);
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
 
         // Query Zenoh for Services
         let z_config_b = zenoh::config::Config::default();
@@ -716,7 +722,7 @@ mod zenoh_tunnel {
         let tunnel_config_a = TunnelConfig::default();
         let mut tunnel_a =
             Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
-        assert_that!(tunnel_a.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
 
         // Notifier
         let iox_node_a = NodeBuilder::new()
@@ -732,10 +738,10 @@ mod zenoh_tunnel {
 
         // Discover
         tunnel_a.discover(Scope::Iceoryx).unwrap();
-        let tunneled_services_a = tunnel_a.tunneled_services();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
         assert_that!(tunneled_services_a.len(), eq 1);
         assert_that!(tunneled_services_a
-            .contains(&String::from(iox_service_a.service_id().as_str())), eq true);
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
 
         // [[ HOST B ]]
         // Tunnel
@@ -744,16 +750,17 @@ mod zenoh_tunnel {
         let tunnel_config_b = TunnelConfig::default();
         let mut tunnel_b =
             Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
-        assert_that!(tunnel_b.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 0);
 
         // Discover
         retry(
             || {
                 tunnel_b.discover(Scope::Zenoh).unwrap();
 
-                let tunneled_services = tunnel_b.tunneled_services();
-                let success =
-                    tunneled_services.contains(&String::from(iox_service_a.service_id().as_str()));
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
 
                 if success {
                     return Ok(());
@@ -821,7 +828,7 @@ mod zenoh_tunnel {
         let tunnel_config_a = TunnelConfig::default();
         let mut tunnel_a =
             Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
-        assert_that!(tunnel_a.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
 
         // Notifier
         let iox_node_a = NodeBuilder::new()
@@ -840,10 +847,10 @@ mod zenoh_tunnel {
 
         // Discover
         tunnel_a.discover(Scope::Iceoryx).unwrap();
-        let tunneled_services_a = tunnel_a.tunneled_services();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
         assert_that!(tunneled_services_a.len(), eq 1);
         assert_that!(tunneled_services_a
-            .contains(&String::from(iox_service_a.service_id().as_str())), eq true);
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
 
         // [[ HOST B ]]
         // Tunnel
@@ -852,16 +859,17 @@ mod zenoh_tunnel {
         let tunnel_config_b = TunnelConfig::default();
         let mut tunnel_b =
             Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
-        assert_that!(tunnel_b.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 0);
 
         // Discover
         retry(
             || {
                 tunnel_b.discover(Scope::Zenoh).unwrap();
 
-                let tunneled_services = tunnel_b.tunneled_services();
-                let success =
-                    tunneled_services.contains(&String::from(iox_service_a.service_id().as_str()));
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
 
                 if success {
                     return Ok(());
@@ -946,7 +954,7 @@ mod zenoh_tunnel {
         let tunnel_config_a = TunnelConfig::default();
         let mut tunnel_a =
             Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
-        assert_that!(tunnel_a.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
 
         // Notifier
         let iox_node_a = NodeBuilder::new()
@@ -962,10 +970,10 @@ mod zenoh_tunnel {
 
         // Discover
         tunnel_a.discover(Scope::Iceoryx).unwrap();
-        let tunneled_services_a = tunnel_a.tunneled_services();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
         assert_that!(tunneled_services_a.len(), eq 1);
         assert_that!(tunneled_services_a
-            .contains(&String::from(iox_service_a.service_id().as_str())), eq true);
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
 
         // [[ HOST B ]]
         // Tunnel
@@ -974,16 +982,17 @@ mod zenoh_tunnel {
         let tunnel_config_b = TunnelConfig::default();
         let mut tunnel_b =
             Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
-        assert_that!(tunnel_b.tunneled_services().len(), eq 0);
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 0);
 
         // Discover
         retry(
             || {
                 tunnel_b.discover(Scope::Zenoh).unwrap();
 
-                let tunneled_services = tunnel_b.tunneled_services();
-                let success =
-                    tunneled_services.contains(&String::from(iox_service_a.service_id().as_str()));
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
 
                 if success {
                     return Ok(());
@@ -1067,6 +1076,7397 @@ mod zenoh_tunnel {
         assert_that!(num_notifications_c, eq 1);
     }
 
+    #[test]
+    fn event_frequency_reflects_propagated_events<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Notifier
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 1);
+
+        // ==================== TEST =====================
+
+        let window = Duration::from_secs(10);
+        assert_that!(tunnel_a.event_frequency(iox_service_a.service_id(), window), eq Some(0.0));
+
+        iox_notifier_a
+            .notify_with_custom_event_id(EventId::new(1))
+            .unwrap();
+        iox_notifier_a
+            .notify_with_custom_event_id(EventId::new(2))
+            .unwrap();
+        tunnel_a.propagate();
+
+        let frequency = tunnel_a
+            .event_frequency(iox_service_a.service_id(), window)
+            .unwrap();
+        assert_that!(frequency, eq 2.0 / window.as_secs_f64());
+    }
+
+    #[test]
+    fn healthy_connections_do_not_exceed_error_threshold<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            propagation_policy: PropagationPolicy::DisableAfter(1),
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Service
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+
+        // [[ HOST B ]]
+        // Service that is never discovered by the tunnel
+        let iox_config_b = generate_isolated_config();
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+
+        assert_that!(tunnel_a.service_error_threshold_exceeded(iox_service_a.service_id()), eq Some(false));
+        assert_that!(tunnel_a.service_error_threshold_exceeded(iox_service_b.service_id()), eq None);
+    }
+
+    #[test]
+    fn saturated_connections_are_detected<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.connection_is_saturated(iox_service_a.service_id()), eq Some(false));
+
+        // ==================== TEST =====================
+
+        for value in 0..3u64 {
+            iox_publisher_a.send_copy(value).unwrap();
+        }
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+
+        assert_that!(tunnel_a.connection_is_saturated(iox_service_a.service_id()), eq Some(true));
+        assert_that!(tunnel_a.connection_is_saturated(iox_service_a.service_id()), eq Some(true));
+        assert_that!(tunnel_a.saturated_services(), len 1);
+
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+
+        assert_that!(tunnel_a.connection_is_saturated(iox_service_a.service_id()), eq Some(false));
+        assert_that!(tunnel_a.saturated_services(), len 0);
+    }
+
+    #[test]
+    fn forced_discovery_bypasses_discovery_interval<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            min_discovery_interval: Some(Duration::from_secs(3600)),
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // Service created after the first discovery
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let _iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.tunneled_service_infos(), len 0);
+
+        tunnel_a.force_immediate_discovery(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.tunneled_service_infos(), len 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn exports_tunneled_services_as_json<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Notifier
+        let iox_service_name = mock_service_name();
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_notifier_a.notify().unwrap();
+        tunnel_a.propagate();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&tunnel_a.export_service_list_json()).unwrap();
+        let services = json.as_array().unwrap();
+        assert_that!(services, len 1);
+        assert_that!(services[0]["id"], eq iox_service_a.service_id().as_str());
+        assert_that!(services[0]["name"], eq iox_service_name.as_str());
+        assert_that!(services[0]["pattern"], eq "Event");
+        assert_that!(services[0]["messages_forwarded_iox_to_zenoh"], eq 1);
+        assert_that!(services[0]["messages_forwarded_zenoh_to_iox"], eq 0);
+        assert_that!(services[0]["last_error"].is_null(), eq true);
+        assert_that!(services[0]["connected_at_iso8601"].as_str().unwrap().ends_with('Z'), eq true);
+    }
+
+    #[test]
+    fn zenoh_key_can_be_retrieved_by_service_name_and_id<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Service
+        let iox_service_name = mock_service_name();
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel_a.zenoh_key_for_service_id(iox_service_a.service_id()), eq None);
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        let expected_key = keys::publish_subscribe(iox_service_a.service_id());
+        assert_that!(tunnel_a.zenoh_key_for_service_id(iox_service_a.service_id()), eq Some(expected_key.clone()));
+        assert_that!(tunnel_a.zenoh_key_for_service_name(iox_service_name.as_str()), eq Some(expected_key));
+        assert_that!(tunnel_a.zenoh_key_for_service_name("unknown"), eq None);
+    }
+
+    #[test]
+    fn propagation_can_be_disabled_per_direction<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Notifier
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        // Disabled before the service is discovered
+        tunnel_a.disable_iceoryx_to_zenoh();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_notifier_a.notify().unwrap();
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 0);
+
+        // The pending event is propagated once re-enabled
+        tunnel_a.enable_iceoryx_to_zenoh();
+        tunnel_a.disable_zenoh_to_iceoryx();
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+    }
+
+    #[test]
+    fn discovery_key_expression_can_be_customized<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        // ==================== TEST =====================
+
+        let invalid_tunnel_config = TunnelConfig {
+            discovery_key_expression: Some("fleet//services/*".into()),
+            ..Default::default()
+        };
+        let tunnel = Tunnel::<S>::create(&invalid_tunnel_config, &iox_config, &z_config);
+        assert_that!(tunnel.is_err(), eq true);
+
+        let tunnel_config = TunnelConfig {
+            discovery_key_expression: Some("fleet/services/*".into()),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+        tunnel.discover(Scope::Zenoh).unwrap();
+
+        assert_that!(
+            matches!(
+                tunnel.set_discovery_key_expression("fleet//services/*"),
+                Err(DiscoveryError::InvalidKeyExpression { .. })
+            ),
+            eq true
+        );
+        assert_that!(tunnel.set_discovery_key_expression(&keys::discovery()), eq Ok(()));
+        tunnel.discover(Scope::Zenoh).unwrap();
+    }
+
+    #[test]
+    fn discovers_local_services_with_iox_monitoring_disabled<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            disable_iox_monitoring: true,
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+
+    #[test]
+    fn exports_service_statistics_as_csv<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_service_name =
+            ServiceName::new(&format!("{},with\"separators\"", mock_service_name())).unwrap();
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher_a.send_copy(42).unwrap();
+        tunnel_a.propagate();
+
+        let csv = tunnel_a.service_stats_to_csv();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_that!(rows, len 2);
+        assert_that!(rows[0], eq "service_id,service_name,pattern,messages_iox_to_zenoh,messages_zenoh_to_iox,bytes_iox_to_zenoh,bytes_zenoh_to_iox,errors,uptime_seconds");
+
+        let expected_row_prefix = format!(
+            "{},\"{}\",PublishSubscribe,1,0,{},0,0,",
+            iox_service_a.service_id().as_str(),
+            iox_service_name.as_str().replace('"', "\"\""),
+            core::mem::size_of::<u64>()
+        );
+        assert_that!(rows[1].starts_with(&expected_row_prefix), eq true);
+    }
+
+    #[test]
+    fn propagation_stops_at_deadline<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Notifier
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        iox_notifier_a.notify().unwrap();
+
+        // ==================== TEST =====================
+
+        let report = tunnel_a.propagate_with_deadline(std::time::Instant::now());
+        assert_that!(report.propagated(), eq 0);
+        assert_that!(report.skipped(), eq 1);
+
+        let report =
+            tunnel_a.propagate_with_deadline(std::time::Instant::now() + Duration::from_secs(60));
+        assert_that!(report.propagated(), eq 1);
+        assert_that!(report.skipped(), eq 0);
+    }
+
+    #[test]
+    fn zenoh_session_id_is_unique_per_tunnel<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let tunnel_a = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        let tunnel_b = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // ==================== TEST =====================
+
+        let z_session_id_a = tunnel_a.zenoh_session_id();
+        assert_that!(z_session_id_a.is_empty(), eq false);
+        assert_that!(z_session_id_a.chars().all(|c| c.is_ascii_hexdigit()), eq true);
+        assert_that!(tunnel_a.zenoh_session_id(), eq z_session_id_a);
+        assert_that!(tunnel_b.zenoh_session_id(), ne z_session_id_a);
+    }
+
+    #[test]
+    fn cross_check_finds_announced_services_on_zenoh<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Service
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let report = tunnel_a
+            .cross_check_with_zenoh(Duration::from_secs(10))
+            .unwrap();
+
+        let iox_service_id_a = iox_service_a.service_id().as_str().to_string();
+        assert_that!(report.is_synchronized(), eq true);
+        assert_that!(report.synchronized().contains(&iox_service_id_a), eq true);
+        assert_that!(report.missing_from_zenoh(), len 0);
+        assert_that!(report.unknown_locally().contains(&iox_service_id_a), eq false);
+    }
+
+    #[test]
+    fn cross_check_queries_the_registries_in_queryable_discovery_mode<S: Service>() {
+        // ==================== SETUP ====================
+
+        let tunnel_config = TunnelConfig {
+            discovery_mode: DiscoveryMode::Queryable,
+            ..Default::default()
+        };
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let report = tunnel
+            .cross_check_with_zenoh(Duration::from_secs(10))
+            .unwrap();
+
+        let iox_service_id = iox_service.service_id().as_str().to_string();
+        assert_that!(report.is_synchronized(), eq true);
+        assert_that!(report.synchronized().contains(&iox_service_id), eq true);
+    }
+
+    #[test]
+    fn failed_services_can_be_cleared_and_retried<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.failed_service_count(), eq 0);
+        assert_that!(tunnel.retry_failed_services(), eq 0);
+
+        tunnel.clear_failed_services();
+        assert_that!(tunnel.failed_service_count(), eq 0);
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+
+    #[test]
+    fn exhausted_rate_limits_are_detected<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.connection_throughput_limit_exceeded(iox_service_a.service_id()), eq Some(false));
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel_a.set_rate_limit(iox_service_a.service_id(), Some(1)), eq true);
+        assert_that!(tunnel_a.connection_throughput_limit_exceeded(iox_service_a.service_id()), eq Some(false));
+        assert_that!(tunnel_a.service_dropped_payloads(iox_service_a.service_id()), eq Some(0));
+
+        for value in 0..2u64 {
+            iox_publisher_a.send_copy(value).unwrap();
+        }
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+        assert_that!(tunnel_a.connection_throughput_limit_exceeded(iox_service_a.service_id()), eq Some(true));
+        assert_that!(tunnel_a.service_dropped_payloads(iox_service_a.service_id()), eq Some(1));
+
+        assert_that!(tunnel_a.set_rate_limit(iox_service_a.service_id(), None), eq true);
+        assert_that!(tunnel_a.connection_throughput_limit_exceeded(iox_service_a.service_id()), eq Some(false));
+    }
+
+    #[test]
+    fn message_sizes_are_counted_per_bucket<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        for value in 0..3u64 {
+            iox_publisher_a.send_copy(value).unwrap();
+        }
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+
+        let histogram = tunnel_a
+            .message_size_histogram(iox_service_a.service_id())
+            .unwrap();
+        assert_that!(histogram.count(SizeBucket::Below64B), eq 3);
+        assert_that!(histogram.count(SizeBucket::From64KiB), eq 0);
+        assert_that!(histogram.total(), eq 3);
+        assert_that!(tunnel_a.overall_message_size_histogram(), eq histogram);
+        assert_that!(SizeBucket::of(64), eq SizeBucket::Below512B);
+        assert_that!(SizeBucket::of(65536), eq SizeBucket::From64KiB);
+    }
+
+    #[test]
+    fn discovers_local_services_via_custom_discovery<S: Service>() {
+        struct Registry {
+            services: Vec<StaticConfig>,
+        }
+
+        impl<S: Service> Discovery<S> for Registry {
+            fn discover<OnDiscovered: FnMut(&StaticConfig)>(
+                &mut self,
+                on_discovered: &mut OnDiscovered,
+            ) -> Result<(), DiscoveryError> {
+                for iox_service_config in self.services.drain(..) {
+                    on_discovered(&iox_service_config);
+                }
+                Ok(())
+            }
+        }
+
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(services, len 1);
+
+        let mut tunnel = Tunnel::<S>::create_with_custom_discovery(
+            &TunnelConfig::default(),
+            &iox_config,
+            &z_config,
+            Registry { services },
+        )
+        .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            tunnel.tunneled_service_infos()[0].service_id,
+            eq * iox_service.service_id()
+        );
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn propagates_compressed_slice_payloads<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+        const COMPRESSION_THRESHOLD: usize = 64;
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a
+            .publisher_builder()
+            .initial_max_slice_len(4 * COMPRESSION_THRESHOLD)
+            .create()
+            .unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.set_compression_threshold(iox_service_a.service_id(), COMPRESSION_THRESHOLD), eq true);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_b, &z_config_b).unwrap();
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        let matched = wait_for_zenoh_match(
+            keys::publish_subscribe(iox_service_a.service_id()),
+            Duration::from_millis(1000),
+        );
+        assert_that!(matched, eq true);
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // One payload below and one above the threshold
+        for payload_length in [COMPRESSION_THRESHOLD / 2, 4 * COMPRESSION_THRESHOLD] {
+            let payload_data = vec![b'A'; payload_length];
+            let iox_sample_sent_a = iox_publisher_a.loan_slice_uninit(payload_length).unwrap();
+            let iox_sample_sent_a = iox_sample_sent_a.write_from_slice(&payload_data);
+            iox_sample_sent_a.send().unwrap();
+
+            retry(
+                || {
+                    tunnel_a.propagate();
+                    tunnel_b.propagate();
+                    match iox_subscriber_b.receive().unwrap() {
+                        Some(iox_sample_received_b) => {
+                            if *iox_sample_received_b.payload() == *payload_data {
+                                Ok(())
+                            } else {
+                                Err("received unexpected sample")
+                            }
+                        }
+                        None => Err("failed to receive expected sample"),
+                    }
+                },
+                TIME_BETWEEN_RETRIES,
+                Some(MAX_RETRIES),
+            );
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn propagates_zstd_compressed_slice_payloads<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+        const PAYLOAD_LENGTH: usize = 4 * DEFAULT_COMPRESSION_THRESHOLD;
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let tunnel_config_a = TunnelConfig {
+            compression: true,
+            compression_codec: CompressionCodec::Zstd { level: 3 },
+            ..Default::default()
+        };
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a
+            .publisher_builder()
+            .initial_max_slice_len(PAYLOAD_LENGTH)
+            .create()
+            .unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_b, &z_config_b).unwrap();
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        let matched = wait_for_zenoh_match(
+            keys::publish_subscribe(iox_service_a.service_id()),
+            Duration::from_millis(1000),
+        );
+        assert_that!(matched, eq true);
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        let payload_data = vec![b'A'; PAYLOAD_LENGTH];
+        let iox_sample_sent_a = iox_publisher_a.loan_slice_uninit(PAYLOAD_LENGTH).unwrap();
+        let iox_sample_sent_a = iox_sample_sent_a.write_from_slice(&payload_data);
+        iox_sample_sent_a.send().unwrap();
+
+        retry(
+            || {
+                tunnel_a.propagate();
+                tunnel_b.propagate();
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) => {
+                        if *iox_sample_received_b.payload() == *payload_data {
+                            Ok(())
+                        } else {
+                            Err("received unexpected sample")
+                        }
+                    }
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn connection_ages_are_counted_per_bucket<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+        assert_that!(tunnel.connection_age_histogram().total(), eq 0);
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let histogram = tunnel.connection_age_histogram();
+        assert_that!(histogram.total(), eq 2);
+        assert_that!(histogram.count(AgeBucket::Below1s), eq 2);
+        assert_that!(AgeBucket::of(Duration::from_secs(10)), eq AgeBucket::Below1m);
+        assert_that!(AgeBucket::of(Duration::from_secs(600)), eq AgeBucket::From10m);
+    }
+
+    #[test]
+    fn propagation_report_contains_forwarded_payloads_per_service<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        for value in 0..3u64 {
+            iox_publisher_a.send_copy(value).unwrap();
+        }
+        let report = tunnel_a.propagate_once_and_report();
+        assert_that!(report.propagation().is_success(), eq true);
+        assert_that!(report.forwarded(), len 1);
+        assert_that!(report.forwarded_for(iox_service_a.service_id()), eq Some((3, 0)));
+
+        let report = tunnel_a.propagate_once_and_report();
+        assert_that!(report.forwarded_for(iox_service_a.service_id()), eq Some((0, 0)));
+    }
+
+    #[test]
+    fn receiving_local_payloads_times_out<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            iceoryx_receive_timeout: Some(Duration::ZERO),
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher_a.send_copy(1).unwrap();
+        let report = tunnel_a.propagate();
+        assert_that!(report.failures(), len 1);
+        assert_that!(report.failures()[0].error(), eq PropagationError::ReceiveTimeout);
+
+        tunnel_a.set_iceoryx_receive_timeout(None);
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+    }
+
+    #[test]
+    fn receive_timeouts_do_not_disable_connections<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            iceoryx_receive_timeout: Some(Duration::ZERO),
+            propagation_policy: PropagationPolicy::DisableAfter(1),
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Notifier
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_notifier_a.notify().unwrap();
+        for _ in 0..3 {
+            let report = tunnel_a.propagate();
+            assert_that!(report.failures(), len 1);
+            assert_that!(report.failures()[0].error(), eq PropagationError::ReceiveTimeout);
+        }
+        assert_that!(tunnel_a.service_error_threshold_exceeded(iox_service_a.service_id()), eq Some(false));
+
+        tunnel_a.set_iceoryx_receive_timeout(None);
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+    }
+
+    #[test]
+    fn only_allowed_services_are_tunneled<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let allowed_service_name = mock_service_name();
+        let allowed_service = iox_node
+            .service_builder(&allowed_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _other_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.with_service_allowed_list(vec![allowed_service_name.to_string()]);
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let tunneled_services = tunnel.tunneled_service_infos();
+        assert_that!(tunneled_services, len 1);
+        assert_that!(
+            tunneled_services[0].service_id,
+            eq * allowed_service.service_id()
+        );
+    }
+
+    #[test]
+    fn discovery_source_of_services_is_reported<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        assert_that!(tunnel.service_discovery_source(iox_service.service_id()), eq None);
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Both).unwrap();
+        assert_that!(tunnel.service_discovery_source(iox_service.service_id()), eq Some(Scope::Iceoryx));
+    }
+
+    #[test]
+    fn max_connections_skips_or_evicts_according_to_policy<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service_1 = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let skipping_tunnel_config = TunnelConfig {
+            max_connections: Some(1),
+            ..Default::default()
+        };
+        let mut skipping_tunnel =
+            Tunnel::<S>::create(&skipping_tunnel_config, &iox_config, &z_config).unwrap();
+        let evicting_tunnel_config = TunnelConfig {
+            max_connections: Some(1),
+            max_connections_policy: MaxConnectionsPolicy::EvictLeastRecentlyUsed,
+            ..Default::default()
+        };
+        let mut evicting_tunnel =
+            Tunnel::<S>::create(&evicting_tunnel_config, &iox_config, &z_config).unwrap();
+
+        skipping_tunnel.discover(Scope::Iceoryx).unwrap();
+        evicting_tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let iox_service_2 = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        skipping_tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(skipping_tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            skipping_tunnel.tunneled_service_infos()[0].service_id,
+            eq * iox_service_1.service_id()
+        );
+
+        evicting_tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(evicting_tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            evicting_tunnel.tunneled_service_infos()[0].service_id,
+            eq * iox_service_2.service_id()
+        );
+        assert_that!(evicting_tunnel.service_discovery_source(iox_service_1.service_id()), eq None);
+    }
+
+    #[test]
+    fn services_not_counting_towards_the_limit_do_not_evict_connections<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service_1 = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let tunnel_config = TunnelConfig {
+            max_connections: Some(1),
+            max_connections_policy: MaxConnectionsPolicy::EvictLeastRecentlyUsed,
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let iox_service_2 = iox_node
+            .service_builder(&mock_service_name())
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 2);
+        assert_that!(tunnel.service_discovery_source(iox_service_1.service_id()), eq Some(Scope::Iceoryx));
+        assert_that!(tunnel.service_discovery_source(iox_service_2.service_id()), eq Some(Scope::Iceoryx));
+    }
+
+    #[test]
+    fn local_services_can_be_queried_by_messaging_pattern<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel = Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let event_services = tunnel
+            .services_matching_pattern(MessagingPattern::Event)
+            .unwrap();
+        assert_that!(event_services, len 1);
+        assert_that!(event_services[0].service_id(), eq iox_event_service.service_id());
+        assert_that!(tunnel.services_matching_pattern(MessagingPattern::PublishSubscribe).unwrap(), len 1);
+        assert_that!(tunnel.services_matching_pattern(MessagingPattern::RequestResponse).unwrap(), len 0);
+        assert_that!(tunnel.tunneled_service_infos(), len 0);
+    }
+
+    #[test]
+    fn diagnostic_report_is_plain_text_without_color<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let tunnel_config = TunnelConfig {
+            use_color: Some(false),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let report = tunnel.diagnostic_report();
+        assert_that!(report.contains('\x1b'), eq false);
+        assert_that!(
+            report.contains(&format!("OK {}", iox_service.service_id().as_str())),
+            eq true
+        );
+    }
+
+    #[test]
+    fn propagation_jitter_is_determined_from_forwarded_payloads<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let window = Duration::from_secs(60);
+        iox_publisher.send_copy(1).unwrap();
+        tunnel.propagate();
+        assert_that!(
+            tunnel.propagation_jitter(iox_service.service_id(), window),
+            is_none
+        );
+
+        for payload in 2..5 {
+            std::thread::sleep(Duration::from_millis(10));
+            iox_publisher.send_copy(payload).unwrap();
+            tunnel.propagate();
+        }
+        assert_that!(
+            tunnel.propagation_jitter(iox_service.service_id(), window),
+            is_some
+        );
+    }
+
+    #[test]
+    fn merged_configs_prefer_the_settings_of_the_overlay<S: Service>() {
+        // ==================== SETUP ====================
+
+        let base = TunnelConfig {
+            discovery_service: Some("base/discovery".into()),
+            min_discovery_interval: Some(Duration::from_secs(1)),
+            propagation_policy: PropagationPolicy::DisableAfter(3),
+            ..Default::default()
+        };
+        let overlay = TunnelConfigOverlay {
+            discovery_service: Some("overlay/discovery".into()),
+            max_connections: Some(8),
+            ..Default::default()
+        };
+
+        // ==================== TEST =====================
+
+        let merged = Tunnel::<S>::merge_configs(base, overlay);
+        assert_that!(merged.discovery_service, eq Some("overlay/discovery".into()));
+        assert_that!(merged.min_discovery_interval, eq Some(Duration::from_secs(1)));
+        assert_that!(merged.propagation_policy, eq PropagationPolicy::DisableAfter(3));
+        assert_that!(merged.max_connections, eq Some(8));
+    }
+
+    #[test]
+    fn merged_configs_apply_defaults_and_disabled_flags_of_the_overlay<S: Service>() {
+        // ==================== SETUP ====================
+
+        let base = TunnelConfig {
+            propagation_policy: PropagationPolicy::DisableAfter(3),
+            track_message_ids: true,
+            scope_aliasing: true,
+            ..Default::default()
+        };
+        let overlay = TunnelConfigOverlay {
+            propagation_policy: Some(PropagationPolicy::default()),
+            track_message_ids: Some(false),
+            ..Default::default()
+        };
+
+        // ==================== TEST =====================
+
+        let merged = Tunnel::<S>::merge_configs(base, overlay);
+        assert_that!(merged.propagation_policy, eq PropagationPolicy::default());
+        assert_that!(merged.track_message_ids, eq false);
+        assert_that!(merged.scope_aliasing, eq true);
+    }
+
+    #[test]
+    fn connection_latency_is_known_after_first_propagation<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(
+            tunnel.service_connection_latency(iox_service.service_id()),
+            is_none
+        );
+
+        tunnel.propagate();
+        assert_that!(
+            tunnel.service_connection_latency(iox_service.service_id()),
+            is_some
+        );
+    }
+
+    #[test]
+    fn tunnel_can_be_created_with_zenoh_session_options<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let tunnel_config = TunnelConfig {
+            zenoh_session_options: ZenohSessionOptions {
+                multicast_scouting: Some(false),
+                gossip_scouting: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // ==================== TEST =====================
+
+        let tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config);
+        assert_that!(tunnel, is_ok);
+    }
+
+    #[test]
+    fn splicing_forwards_local_payloads_of_source_service<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_source_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_sink_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_source_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher.send_copy(42).unwrap();
+
+        let forwarded = tunnel
+            .splice(
+                iox_source_service.service_id(),
+                iox_sink_service.service_id(),
+            )
+            .unwrap();
+        assert_that!(forwarded, eq 1);
+
+        let result = tunnel.splice(
+            iox_source_service.service_id(),
+            iox_source_service.service_id(),
+        );
+        assert_that!(result, eq Err(SpliceError::Error));
+    }
+
+    #[test]
+    fn splicing_respects_the_rate_limit_of_the_sink_service<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_source_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_sink_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_source_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.set_rate_limit(iox_sink_service.service_id(), Some(1)), eq true);
+        for value in 0..2u64 {
+            iox_publisher.send_copy(value).unwrap();
+        }
+
+        let forwarded = tunnel
+            .splice(
+                iox_source_service.service_id(),
+                iox_sink_service.service_id(),
+            )
+            .unwrap();
+        assert_that!(forwarded, eq 1);
+
+        // The payload exceeding the rate limit is forwarded by the next splice
+        assert_that!(tunnel.set_rate_limit(iox_sink_service.service_id(), None), eq true);
+        let forwarded = tunnel
+            .splice(
+                iox_source_service.service_id(),
+                iox_sink_service.service_id(),
+            )
+            .unwrap();
+        assert_that!(forwarded, eq 1);
+        assert_that!(tunnel.service_dropped_payloads(iox_sink_service.service_id()), eq Some(0));
+    }
+
+    #[test]
+    fn services_of_different_patterns_with_same_name_are_no_duplicates<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_service_name = mock_service_name();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 2);
+        assert_that!(tunnel.services_with_duplicate_names(), len 0);
+    }
+
+    #[test]
+    fn connection_string_describes_both_ends_of_the_connection<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_service_name = mock_service_name();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let expected = format!(
+            "iox2://{}/{}<->zenoh://{}",
+            iox_service.service_id().as_str(),
+            iox_service_name,
+            keys::publish_subscribe(iox_service.service_id())
+        );
+        assert_that!(tunnel.connection_string(iox_service.service_id()), eq Some(expected));
+    }
+
+    #[test]
+    fn watched_service_reports_propagated_payloads_until_handle_is_dropped<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let watched = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let watched_in_callback = watched.clone();
+        let watch_handle = tunnel
+            .watch_service(iox_service.service_id(), move |direction, payload| {
+                watched_in_callback
+                    .lock()
+                    .unwrap()
+                    .push((direction, payload.to_vec()));
+            })
+            .unwrap();
+
+        iox_publisher.send_copy(42u64).unwrap();
+        tunnel.propagate();
+        assert_that!(
+            *watched.lock().unwrap(),
+            eq vec![(Direction::IceoryxToZenoh, 42u64.to_ne_bytes().to_vec())]
+        );
+
+        drop(watch_handle);
+        iox_publisher.send_copy(73u64).unwrap();
+        tunnel.propagate();
+        assert_that!(watched.lock().unwrap(), len 1);
+    }
+
+    #[test]
+    fn watched_service_can_be_unwatched_from_within_the_callback<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let watched = std::sync::Arc::new(std::sync::Mutex::new(0));
+        let watched_in_callback = watched.clone();
+        let watch_handle = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let watch_handle_in_callback = watch_handle.clone();
+        *watch_handle.lock().unwrap() = Some(
+            tunnel
+                .watch_service(iox_service.service_id(), move |_, _| {
+                    *watched_in_callback.lock().unwrap() += 1;
+                    // Dropping the handle removes the callback that is currently called
+                    watch_handle_in_callback.lock().unwrap().take();
+                })
+                .unwrap(),
+        );
+
+        iox_publisher.send_copy(42u64).unwrap();
+        tunnel.propagate();
+        assert_that!(*watched.lock().unwrap(), eq 1);
+        assert_that!(watch_handle.lock().unwrap().is_none(), eq true);
+
+        iox_publisher.send_copy(73u64).unwrap();
+        tunnel.propagate();
+        assert_that!(*watched.lock().unwrap(), eq 1);
+    }
+
+    #[test]
+    fn services_can_be_retrieved_by_name_prefix<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let namespace = mock_service_name();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_lidar_service = iox_node
+            .service_builder(&ServiceName::new(&format!("{namespace}/sensors/lidar")).unwrap())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_camera_service = iox_node
+            .service_builder(&ServiceName::new(&format!("{namespace}/sensors/camera")).unwrap())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _iox_motor_service = iox_node
+            .service_builder(&ServiceName::new(&format!("{namespace}/actuators/motor")).unwrap())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let sensors = tunnel.services_by_name_prefix(&format!("{namespace}/sensors/"));
+        assert_that!(sensors, len 2);
+        assert_that!(sensors, contains iox_lidar_service.service_id());
+        assert_that!(sensors, contains iox_camera_service.service_id());
+    }
+
+    #[test]
+    fn asserting_no_failed_services_succeeds_when_all_services_are_tunneled<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        tunnel.assert_no_failed_services();
+    }
+
+    #[test]
+    fn memory_usage_estimate_grows_with_tunneled_services<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let estimate_before_discovery = tunnel.estimate_memory_usage();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let estimate = tunnel.estimate_memory_usage();
+        assert_that!(estimate.payload_buffer_bytes, gt 0);
+        assert_that!(estimate.total_bytes, gt estimate_before_discovery.total_bytes);
+        assert_that!(
+            estimate.total_bytes,
+            eq estimate.connection_map_bytes + estimate.payload_buffer_bytes + estimate.metadata_bytes
+        );
+    }
+
+    #[test]
+    fn transient_tunnel_is_cleaned_up_on_drop<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let mut tunnel =
+            Tunnel::<S>::create_transient(&TunnelConfig::default(), &iox_config, &z_config)
+                .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        drop(tunnel);
+
+        // A new tunnel can be created for the same services right away
+        let mut tunnel =
+            Tunnel::<S>::create_transient(&TunnelConfig::default(), &iox_config, &z_config)
+                .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+
+    #[test]
+    fn message_roundtrip_through_tunnel_preserves_payload<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_service_name = mock_service_name();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let result = tunnel
+            .test_message_roundtrip(
+                iox_service_name.as_str(),
+                42u64.to_ne_bytes().to_vec(),
+                Duration::from_secs(10),
+            )
+            .unwrap();
+        assert_that!(result.payload_matches(), eq true);
+
+        let result = tunnel.test_message_roundtrip(
+            iox_service_name.as_str(),
+            vec![1, 2, 3],
+            Duration::from_secs(10),
+        );
+        assert_that!(result, eq Err(RoundtripError::InvalidPayload));
+    }
+
+    #[test]
+    fn message_roundtrip_through_tunnel_uses_the_configured_wire_format<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_service_name = mock_service_name();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        tunnel.with_message_id_tracking(true);
+
+        // ==================== TEST =====================
+
+        // The message ids prefixing the payloads on zenoh are not part of the payload
+        let result = tunnel
+            .test_message_roundtrip(
+                iox_service_name.as_str(),
+                42u64.to_ne_bytes().to_vec(),
+                Duration::from_secs(10),
+            )
+            .unwrap();
+        assert_that!(result.payload_matches(), eq true);
+    }
+
+    #[test]
+    fn propagation_order_visits_publish_subscribe_services_first<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_request_response_service = iox_node
+            .service_builder(&mock_service_name())
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let order = tunnel.service_propagation_order();
+        assert_that!(order, len 3);
+        assert_that!(order[0], eq iox_publish_subscribe_service.service_id().clone());
+        assert_that!(order[1], eq iox_event_service.service_id().clone());
+        assert_that!(order[2], eq iox_request_response_service.service_id().clone());
+    }
+
+    #[test]
+    fn pausing_and_draining_delivers_pending_payloads<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher.send_copy(1).unwrap();
+        iox_publisher.send_copy(2).unwrap();
+
+        let report = tunnel.pause_all_and_drain(Duration::from_secs(10)).unwrap();
+        assert_that!(report.drained_for(iox_service.service_id()), eq Some(2));
+
+        // Payloads published after the drain are not propagated while paused
+        iox_publisher.send_copy(3).unwrap();
+        assert_that!(tunnel.propagate().propagated(), eq 0);
+    }
+
+    #[test]
+    fn selective_propagation_only_propagates_matching_services<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_service_b = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+        let iox_publisher_b = iox_service_b.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher_a.send_copy(1).unwrap();
+        iox_publisher_b.send_copy(2).unwrap();
+
+        let report = tunnel.propagate_selective(|id| id == iox_service_a.service_id());
+        assert_that!(report.propagated(), eq 1);
+
+        let report = tunnel.propagate();
+        assert_that!(report.propagated(), eq 1);
+    }
+
+    #[test]
+    fn connections_can_be_sorted_by_message_count<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_quiet_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_busy_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_idle_service = iox_node
+            .service_builder(&mock_service_name())
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_quiet_publisher = iox_quiet_service.publisher_builder().create().unwrap();
+        let iox_busy_publisher = iox_busy_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_quiet_publisher.send_copy(1).unwrap();
+        iox_busy_publisher.send_copy(1).unwrap();
+        iox_busy_publisher.send_copy(2).unwrap();
+        tunnel.propagate();
+
+        let sorted = tunnel.connection_ids_sorted_by_message_count(Direction::IceoryxToZenoh);
+        assert_that!(
+            sorted,
+            eq vec![
+                (iox_busy_service.service_id().clone(), 2),
+                (iox_quiet_service.service_id().clone(), 1),
+                (iox_idle_service.service_id().clone(), 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn propagate_or_fail_fast_succeeds_when_all_connections_propagate<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher.send_copy(42).unwrap();
+
+        assert_that!(tunnel.propagate_or_fail_fast(), is_ok);
+    }
+
+    #[test]
+    fn zenoh_peer_list_contains_connected_peers<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let tunnel = Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let z_session = zenoh::open(z_config.clone()).wait().unwrap();
+        let z_session_id = z_session.info().zid().wait().to_string();
+
+        // ==================== TEST =====================
+
+        let mut peers = tunnel.zenoh_peer_list();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while !peers.contains(&z_session_id) && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(50));
+            peers = tunnel.zenoh_peer_list();
+        }
+
+        assert_that!(peers, contains z_session_id);
+        assert_that!(tunnel.zenoh_router_list(), len 0);
+    }
+
+    #[test]
+    fn connections_of_removed_services_are_removed<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let tunnel_config = TunnelConfig {
+            discovery_service: Some("iox2://test/discovery/services/".into()),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_discovery_publisher = iox_node
+            .service_builder(&ServiceName::new("iox2://test/discovery/services/").unwrap())
+            .publish_subscribe::<DiscoveryUpdate>()
+            .open_or_create()
+            .unwrap()
+            .publisher_builder()
+            .create()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let iox_sample = iox_discovery_publisher.loan_uninit().unwrap();
+        iox_sample
+            .write_payload(DiscoveryUpdate::Added(iox_service.static_config().clone()))
+            .send()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+
+        // ==================== TEST =====================
+
+        let iox_sample = iox_discovery_publisher.loan_uninit().unwrap();
+        iox_sample
+            .write_payload(DiscoveryUpdate::Removed(
+                iox_service.static_config().clone(),
+            ))
+            .send()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        assert_that!(tunnel.tunneled_service_infos(), len 0);
+        assert_that!(
+            tunnel.zenoh_key_for_service_id(iox_service.service_id()),
+            is_none
+        );
+    }
+
+    #[test]
+    fn propagates_request_and_response<S: Service>() {
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+        const MAX_RETRIES: usize = 25;
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Client
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_client_a = iox_service_a.client_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.tunneled_service_infos(), len 1);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_b, &z_config_b).unwrap();
+
+        // Discover
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
+
+                if success {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Server
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_server_b = iox_service_b.server_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // Send request
+        let mut iox_pending_response_a = iox_client_a.send_copy(21).unwrap();
+
+        // Respond and receive with retry
+        let mut received_response = 0;
+        retry(
+            || {
+                tunnel_a.propagate();
+                tunnel_b.propagate();
+
+                if let Some(iox_active_request) = iox_server_b.receive().unwrap() {
+                    let request = *iox_active_request.payload();
+                    iox_active_request.send_copy(request * 2).unwrap();
+                }
+
+                tunnel_b.propagate();
+                tunnel_a.propagate();
+
+                match iox_pending_response_a.receive().unwrap() {
+                    Some(iox_response) => {
+                        received_response = *iox_response.payload();
+                        Ok(())
+                    }
+                    None => {
+                        if !iox_pending_response_a.is_connected() {
+                            // The request was finalized before it reached the remote server
+                            iox_pending_response_a = iox_client_a.send_copy(21).unwrap();
+                        }
+                        Err("failed to receive expected response")
+                    }
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        assert_that!(received_response, eq 42);
+    }
+
+    #[test]
+    fn zenoh_session_of_created_tunnel_is_alive<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+
+        // ==================== TEST =====================
+
+        let tunnel = Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        assert_that!(tunnel.is_zenoh_session_alive(), eq true);
+    }
+
+    #[test]
+    fn service_list_diff_contains_added_and_removed_services<S: Service>() {
+        // ==================== SETUP ====================
+
+        let old = vec![String::from("a"), String::from("b"), String::from("c")];
+        let new = vec![String::from("c"), String::from("d"), String::from("a")];
+
+        // ==================== TEST =====================
+
+        let diff = Tunnel::<S>::service_list_diff(&old, &new);
+
+        assert_that!(diff.added().to_vec(), eq vec![String::from("d")]);
+        assert_that!(diff.removed().to_vec(), eq vec![String::from("b")]);
+        assert_that!(Tunnel::<S>::service_list_diff(&old, &old).is_empty(), eq true);
+    }
+
+    #[test]
+    fn creation_reports_cause_when_zenoh_session_cannot_be_opened<S: Service>() {
+        // ==================== SETUP ====================
+        let iox_config = generate_isolated_config();
+        let mut z_config = zenoh::Config::default();
+        z_config.insert_json5("mode", "\"client\"").unwrap();
+        z_config
+            .insert_json5("connect/endpoints", "[\"tcp/127.0.0.1:1\"]")
+            .unwrap();
+
+        // ==================== TEST =====================
+        let result = Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config);
+
+        let Some(CreationError::ZenohSessionOpenFailed { reason }) = result.err() else {
+            test_fail!("the zenoh session was expected to fail to open");
+        };
+        assert_that!(reason.is_empty(), eq false);
+    }
+
+    #[test]
+    fn discovers_cached_local_services_after_refreshing_the_cache<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig::default();
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // Service
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // The service is not cached before the cache is refreshed
+        assert_that!(tunnel.discover_cached(), eq Ok(0));
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 0);
+
+        // Refreshing the cache does not create connections
+        tunnel.refresh_discovery_cache().unwrap();
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 0);
+
+        assert_that!(tunnel.discover_cached(), eq Ok(1));
+        assert_that!(tunnel.tunneled_service_infos().len(), eq 1);
+        assert_that!(tunnel
+            .tunneled_service_infos()
+            .iter().any(|info| info.service_id == *iox_service.service_id()), eq true);
+    }
+
+    #[test]
+    fn attaches_metadata_to_tunneled_connections<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_untunneled_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+        let untunneled_id = iox_untunneled_service.service_id().clone();
+        drop(iox_untunneled_service);
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let id = iox_service.service_id();
+        assert_that!(tunnel.get_connection_metadata(id, "joint"), is_none);
+
+        assert_that!(tunnel.set_connection_metadata(id, "joint", "3".to_string()), eq true);
+        assert_that!(tunnel.get_connection_metadata(id, "joint"), eq Some("3"));
+
+        assert_that!(tunnel.set_connection_metadata(id, "joint", "4".to_string()), eq true);
+        assert_that!(tunnel.get_connection_metadata(id, "joint"), eq Some("4"));
+        assert_that!(tunnel.get_connection_metadata(id, "calibration"), is_none);
+
+        assert_that!(
+            tunnel.set_connection_metadata(&untunneled_id, "joint", "3".to_string()),
+            eq false
+        );
+        assert_that!(
+            tunnel.get_connection_metadata(&untunneled_id, "joint"),
+            is_none
+        );
+    }
+
+    #[test]
+    fn reports_and_resets_statistics_per_service<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher_a.send_copy(42).unwrap();
+        iox_publisher_a.send_copy(43).unwrap();
+        tunnel_a.propagate();
+
+        let statistics = tunnel_a.statistics();
+        assert_that!(statistics, len 1);
+        assert_that!(
+            statistics.get(iox_service_a.service_id()).copied(),
+            eq Some(TunnelStatistics {
+                messages_forwarded: 2,
+                bytes_forwarded: 2 * core::mem::size_of::<u64>() as u64,
+                propagation_errors: 0,
+                corrupted_messages_count: 0,
+            })
+        );
+
+        tunnel_a.reset_statistics();
+
+        let statistics = tunnel_a.statistics();
+        assert_that!(
+            statistics.get(iox_service_a.service_id()).copied(),
+            eq Some(TunnelStatistics::default())
+        );
+    }
+
+    #[test]
+    fn propagation_returns_forwarded_messages_up_to_the_limit<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher_a.send_copy(42).unwrap();
+        iox_publisher_a.send_copy(43).unwrap();
+        iox_publisher_a.send_copy(44).unwrap();
+
+        let messages = tunnel_a.propagate_and_return_new_messages(2);
+        assert_that!(messages, len 1);
+        assert_that!(
+            messages.get(iox_service_a.service_id()).cloned(),
+            eq Some(vec![
+                (Direction::IceoryxToZenoh, 42u64.to_ne_bytes().to_vec()),
+                (Direction::IceoryxToZenoh, 43u64.to_ne_bytes().to_vec()),
+            ])
+        );
+
+        // Only the messages forwarded during the call are returned
+        let messages = tunnel_a.propagate_and_return_new_messages(2);
+        assert_that!(messages, is_empty);
+    }
+
+    #[test]
+    fn only_services_in_the_configured_allowlist_are_tunneled<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let allowed_service_name = mock_service_name();
+        let allowed_service = iox_node
+            .service_builder(&allowed_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _other_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let tunnel_config = TunnelConfig {
+            service_name_allowlist: Some(vec![allowed_service_name.to_string()]),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Both).unwrap();
+
+        let tunneled_services = tunnel.tunneled_service_infos();
+        assert_that!(tunneled_services, len 1);
+        assert_that!(
+            tunneled_services[0].service_id,
+            eq * allowed_service.service_id()
+        );
+    }
+
+    #[test]
+    fn counts_connections_per_messaging_pattern<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _iox_publish_subscribe_service_a = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_publish_subscribe_service_b = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 0);
+        assert_that!(tunnel.num_total_event_connections(), eq 0);
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 2);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+    }
+
+    #[cfg(not(feature = "strict-errors"))]
+    #[test]
+    fn discovery_fails_when_connection_of_malformed_service_cannot_be_created<S: Service>() {
+        struct Registry {
+            services: Vec<StaticConfig>,
+        }
+
+        impl<S: Service> Discovery<S> for Registry {
+            fn discover<OnDiscovered: FnMut(&StaticConfig)>(
+                &mut self,
+                on_discovered: &mut OnDiscovered,
+            ) -> Result<(), DiscoveryError> {
+                for iox_service_config in self.services.drain(..) {
+                    on_discovered(&iox_service_config);
+                }
+                Ok(())
+            }
+        }
+
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let iox_service_name = mock_service_name();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+
+        // The configuration of a service whose payload type no longer matches the service
+        let iox_outdated_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        let iox_outdated_service_id = iox_outdated_service.service_id().clone();
+        drop(iox_outdated_service);
+        let _iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u32>()
+            .open_or_create()
+            .unwrap();
+
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        S::list(&iox_config, |iox_service_details| {
+            if iox_service_details.static_details.service_id() == iox_event_service.service_id() {
+                services.push(iox_service_details.static_details);
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(services, len 2);
+
+        let mut tunnel = Tunnel::<S>::create_with_custom_discovery(
+            &TunnelConfig::default(),
+            &iox_config,
+            &z_config,
+            Registry { services },
+        )
+        .unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(
+            tunnel.discover(Scope::Iceoryx),
+            eq Err(DiscoveryError::ServiceConnectionFailed {
+                service_id: iox_outdated_service_id.as_str().to_string(),
+                service_name: iox_service_name.as_str().to_string(),
+                reason: String::from("Error"),
+            })
+        );
+
+        // The other discovered services are connected regardless
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            tunnel.tunneled_service_infos()[0].service_id,
+            eq * iox_event_service.service_id()
+        );
+    }
+
+    #[test]
+    fn reconnecting_recreates_connections_on_a_new_zenoh_session<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        let previous_session_id = tunnel.zenoh_session_id();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.reconnect(&z_config), is_ok);
+
+        assert_that!(tunnel.zenoh_session_id(), ne previous_session_id);
+        assert_that!(tunnel.is_zenoh_session_alive(), eq true);
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            tunnel.tunneled_service_infos()[0].service_id,
+            eq * iox_service.service_id()
+        );
+
+        iox_publisher.send_copy(42).unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+    }
+
+    #[test]
+    fn tunnel_with_logging_context_discovers_and_propagates<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.with_logging_context(HashMap::from([
+            ("tunnel", String::from("primary")),
+            ("host", String::from("localhost")),
+        ]));
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+
+        iox_publisher.send_copy(42).unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+    }
+
+    #[test]
+    fn missed_samples_accumulate_when_subscriber_buffer_overflows<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.service_missed_samples(iox_service.service_id()), eq Some(0));
+
+        // ==================== TEST =====================
+
+        for _ in 0..2 {
+            for value in 0..3u64 {
+                iox_publisher.send_copy(value).unwrap();
+            }
+            tunnel.propagate();
+        }
+
+        assert_that!(tunnel.service_missed_samples(iox_service.service_id()), eq Some(2));
+
+        iox_publisher.send_copy(42).unwrap();
+        tunnel.propagate();
+
+        assert_that!(tunnel.service_missed_samples(iox_service.service_id()), eq Some(2));
+    }
+
+    #[test]
+    fn builder_without_iceoryx_config_fails<S: Service>() {
+        // ==================== TEST =====================
+
+        let result = TunnelBuilder::new()
+            .zenoh_config(&zenoh::Config::default())
+            .build::<S>();
+
+        let error = result.err().unwrap();
+        assert_that!(
+            matches!(error, CreationError::MissingIceoryxConfig),
+            eq true
+        );
+    }
+
+    #[test]
+    fn builder_creates_tunnel_restricted_to_service_filter<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let allowed_service_name = mock_service_name();
+        let allowed_service = iox_node
+            .service_builder(&allowed_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _other_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let mut tunnel = TunnelBuilder::new()
+            .iceoryx_config(&iox_config)
+            .service_filter(vec![allowed_service_name.to_string()])
+            .build::<S>()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let tunneled_services = tunnel.tunneled_service_infos();
+        assert_that!(tunneled_services, len 1);
+        assert_that!(
+            tunneled_services[0].service_id,
+            eq * allowed_service.service_id()
+        );
+    }
+
+    #[test]
+    fn services_with_distinct_zenoh_keys_do_not_collide<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let service_name = mock_service_name();
+        let _iox_event_service = iox_node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_other_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 3);
+
+        assert_that!(tunnel.service_zenoh_key_collision_map(), is_empty);
+    }
+
+    #[test]
+    fn tunnels_with_different_key_expr_mappers_producing_same_key_propagate<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+        let z_key = format!("plant/line_1/{}", iox_service_name.as_str());
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            key_expr_mapper: Some(std::sync::Arc::new(|iox_service_config: &StaticConfig| {
+                format!("plant/line_1/{}", iox_service_config.name())
+            })),
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(
+            tunnel_a.zenoh_key_for_service_id(iox_service_a.service_id()),
+            eq Some(z_key.clone())
+        );
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig {
+            key_expr_mapper: Some(std::sync::Arc::new(|iox_service_config: &StaticConfig| {
+                ["plant", "line_1", iox_service_config.name().as_str()].join("/")
+            })),
+            ..Default::default()
+        };
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // Discover
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
+
+                if success {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(
+            tunnel_b.zenoh_key_for_service_id(iox_service_a.service_id()),
+            eq Some(z_key.clone())
+        );
+
+        // Wait for Zenoh's backgorund thread to establish match...
+        let matched = wait_for_zenoh_match(z_key, Duration::from_millis(1000));
+        assert_that!(matched, eq true);
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        iox_publisher_a.send_copy(42).unwrap();
+
+        retry(
+            || {
+                tunnel_a.propagate();
+                tunnel_b.propagate();
+
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 42 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn health_check_reports_degraded_and_dead_connections<S: Service>() {
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+        const MAX_RETRIES: usize = 25;
+
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            propagation_policy: PropagationPolicy::DisableAfter(2),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .event_id_max_value(1)
+            .open_or_create()
+            .unwrap();
+        let iox_service_id = iox_service.service_id().clone();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // Remote events exceeding the maximum event id of the service fail to propagate
+        let z_key = tunnel.zenoh_key_for_service_id(&iox_service_id).unwrap();
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_notifier = z_session.declare_publisher(z_key).wait().unwrap();
+        let notify_invalid_event = || {
+            z_notifier
+                .put(usize::MAX.to_ne_bytes().to_vec())
+                .wait()
+                .unwrap()
+        };
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.health_check().get(&iox_service_id), eq Some(&ConnectionHealth::Healthy));
+        assert_that!(tunnel.is_healthy(), eq true);
+
+        retry(
+            || {
+                notify_invalid_event();
+                tunnel.propagate();
+                if tunnel.is_healthy() {
+                    return Err("invalid event did not reach the tunnel");
+                }
+                Ok(())
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(
+            tunnel.health_check().get(&iox_service_id),
+            eq Some(&ConnectionHealth::Degraded {
+                last_error: PropagationError::IceoryxSendFailed.to_string()
+            })
+        );
+
+        // Disabled after two consecutive failures
+        retry(
+            || {
+                notify_invalid_event();
+                tunnel.propagate();
+                match tunnel.health_check().get(&iox_service_id) {
+                    Some(ConnectionHealth::Dead) => Ok(()),
+                    _ => Err("connection was not disabled"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(tunnel.is_healthy(), eq false);
+    }
+
+    #[test]
+    fn cumulative_service_counts_include_reconnected_services<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        assert_that!(tunnel.total_services_ever_discovered(), eq 0);
+        assert_that!(tunnel.total_services_ever_removed(), eq 0);
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.total_services_ever_discovered(), eq 1);
+        assert_that!(tunnel.total_services_ever_removed(), eq 0);
+
+        tunnel.reconnect(&z_config).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        assert_that!(tunnel.total_services_ever_discovered(), eq 2);
+        assert_that!(tunnel.total_services_ever_removed(), eq 1);
+
+        assert_that!(tunnel.remove_connection(iox_service.service_id()), eq true);
+        assert_that!(tunnel.total_services_ever_discovered(), eq 2);
+        assert_that!(tunnel.total_services_ever_removed(), eq 2);
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.total_services_ever_discovered(), eq 3);
+        assert_that!(tunnel.total_services_ever_removed(), eq 2);
+    }
+
+    #[test]
+    fn discovery_in_no_scope_connects_no_services<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::None).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 0);
+        assert_that!(tunnel.propagate().is_success(), eq true);
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+
+    #[test]
+    fn scope_aliasing_discovers_remote_services_in_iceoryx_scope<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig {
+            scope_aliasing: true,
+            ..Default::default()
+        };
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // ==================== TEST =====================
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Iceoryx).unwrap();
+
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
+
+                if success {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        assert_that!(
+            tunnel_b.service_discovery_source(iox_service_a.service_id()),
+            eq Some(Scope::Zenoh)
+        );
+    }
+
+    #[test]
+    fn zenoh_to_iceoryx_connections_do_not_propagate_local_payloads<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            connection_direction: ConnectionDirection::ZenohToIceoryx,
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(
+            tunnel_a
+                .tunneled_service_infos()
+                .iter()
+                .any(|info| info.service_id == *iox_service_a.service_id()),
+            eq true
+        );
+
+        iox_publisher_a.send_copy(42).unwrap();
+        let report = tunnel_a.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 0);
+    }
+
+    #[test]
+    fn validate_all_connections_reports_degraded_and_failed_connections<S: Service>() {
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+        const MAX_RETRIES: usize = 25;
+
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            propagation_policy: PropagationPolicy::DisableAfter(2),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .event_id_max_value(1)
+            .open_or_create()
+            .unwrap();
+        let iox_service_id = iox_service.service_id().clone();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // Remote events exceeding the maximum event id of the service fail to propagate
+        let z_key = tunnel.zenoh_key_for_service_id(&iox_service_id).unwrap();
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_notifier = z_session.declare_publisher(z_key).wait().unwrap();
+        let notify_invalid_event = || {
+            z_notifier
+                .put(usize::MAX.to_ne_bytes().to_vec())
+                .wait()
+                .unwrap()
+        };
+
+        // ==================== TEST =====================
+
+        let report = tunnel.validate_all_connections();
+        assert_that!(report.healthy().to_vec(), contains iox_service_id.clone());
+        assert_that!(report.is_healthy(), eq true);
+
+        retry(
+            || {
+                notify_invalid_event();
+                tunnel.propagate();
+                if tunnel.validate_all_connections().is_healthy() {
+                    return Err("invalid event did not reach the tunnel");
+                }
+                Ok(())
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        let report = tunnel.validate_all_connections();
+        assert_that!(report.healthy(), is_empty);
+        assert_that!(report.degraded().iter().any(|(id, _)| *id == iox_service_id), eq true);
+        assert_that!(report.failed(), is_empty);
+
+        // Disabled after two consecutive failures
+        retry(
+            || {
+                notify_invalid_event();
+                tunnel.propagate();
+                if tunnel.validate_all_connections().failed().is_empty() {
+                    return Err("connection was not disabled");
+                }
+                Ok(())
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        let report = tunnel.validate_all_connections();
+        assert_that!(report.degraded(), is_empty);
+        assert_that!(report.failed().iter().any(|(id, _)| *id == iox_service_id), eq true);
+        assert_that!(report.is_healthy(), eq false);
+    }
+
+    #[test]
+    fn tunneled_service_infos_contain_pattern_and_discovery_scope<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service_name = mock_service_name();
+        let iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let infos = tunnel.tunneled_service_infos();
+        assert_that!(infos, len 1);
+        assert_that!(
+            infos[0],
+            eq TunneledServiceInfo {
+                service_id: iox_service.service_id().clone(),
+                service_name: iox_service_name.to_string(),
+                pattern: MessagingPattern::Event,
+                discovery_scope: Scope::Iceoryx,
+            }
+        );
+    }
+
+    #[test]
+    fn batched_events_are_propagated_once_the_batch_is_full<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            event_batching: Some(EventBatching {
+                batch_size: 2,
+                batch_timeout: Duration::from_secs(3600),
+            }),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier = iox_service.notifier_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_notifier
+            .notify_with_custom_event_id(EventId::new(1))
+            .unwrap();
+        iox_notifier
+            .notify_with_custom_event_id(EventId::new(1))
+            .unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 0);
+
+        iox_notifier
+            .notify_with_custom_event_id(EventId::new(2))
+            .unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 2);
+    }
+
+    #[test]
+    fn batched_events_are_propagated_to_remote_hosts<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            event_batching: Some(EventBatching {
+                batch_size: 3,
+                batch_timeout: Duration::from_secs(3600),
+            }),
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+        assert_that!(tunnel_a.tunneled_service_infos().len(), eq 0);
+
+        // Notifier
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        let tunneled_services_a = tunnel_a.tunneled_service_infos();
+        assert_that!(tunneled_services_a.len(), eq 1);
+        assert_that!(tunneled_services_a
+            .iter().any(|info| info.service_id == *iox_service_a.service_id()), eq true);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+        assert_that!(tunnel_b.tunneled_service_infos().len(), eq 0);
+
+        // Discover
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+
+                let tunneled_services = tunnel_b.tunneled_service_infos();
+                let success = tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id());
+
+                if success {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Wait for Zenoh's backgorund thread to establish match...
+        let matched = wait_for_zenoh_match(
+            keys::publish_subscribe(iox_service_a.service_id()),
+            Duration::from_millis(1000),
+        );
+        assert_that!(matched, eq true);
+
+        // Listener
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_listener_b = iox_service_b.listener_builder().create().unwrap();
+
+        // ==================== TEST =====================
+        // Send multiple notifications on different event ids, which fill one batch
+        let event_a = EventId::new(42);
+        let event_b = EventId::new(73);
+        let event_c = EventId::new(127);
+
+        const NUM_NOTIFICATIONS: usize = 10;
+        for _ in 0..NUM_NOTIFICATIONS {
+            iox_notifier_a.notify_with_custom_event_id(event_a).unwrap();
+            iox_notifier_a.notify_with_custom_event_id(event_b).unwrap();
+            iox_notifier_a.notify_with_custom_event_id(event_c).unwrap();
+        }
+
+        // Propagate over tunnels
+        tunnel_a.propagate();
+        tunnel_b.propagate();
+
+        // Receive with retry
+        let mut num_notifications_a = 0;
+        let mut num_notifications_b = 0;
+        let mut num_notifications_c = 0;
+
+        retry(
+            || {
+                iox_listener_b
+                    .try_wait_all(|id| {
+                        if id == event_a {
+                            num_notifications_a += 1;
+                        }
+                        if id == event_b {
+                            num_notifications_b += 1;
+                        }
+                        if id == event_c {
+                            num_notifications_c += 1;
+                        }
+                    })
+                    .unwrap();
+                if num_notifications_a == 0 || num_notifications_b == 0 || num_notifications_c == 0
+                {
+                    tunnel_a.propagate();
+                    tunnel_b.propagate();
+                    return Err("expected notifications did not arrive");
+                }
+                Ok(())
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        assert_that!(num_notifications_a, eq 1);
+        assert_that!(num_notifications_b, eq 1);
+        assert_that!(num_notifications_c, eq 1);
+    }
+
+    #[test]
+    fn rebalance_moves_connections_to_tunnels_with_fewer_connections<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnels = vec![
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap(),
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap(),
+        ];
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service_a = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_service_b = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnels[0].discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnels[0].num_total_publish_subscribe_connections(), eq 2);
+        assert_that!(tunnels[1].num_total_publish_subscribe_connections(), eq 0);
+
+        // ==================== TEST =====================
+
+        let report = Tunnel::rebalance(&mut tunnels);
+
+        assert_that!(report.moved(), len 1);
+        assert_that!(report.moved()[0].from, eq 0);
+        assert_that!(report.moved()[0].to, eq 1);
+        assert_that!(tunnels[0].num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(tunnels[1].num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(
+            tunnels[1].tunneled_service_infos()[0].service_id,
+            eq report.moved()[0].service_id
+        );
+
+        // The moved service is not connected again by the tunnel it was moved from
+        tunnels[0].discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnels[0].num_total_publish_subscribe_connections(), eq 1);
+
+        let report = Tunnel::rebalance(&mut tunnels);
+        assert_that!(report.is_empty(), eq true);
+    }
+
+    #[test]
+    fn add_event_connection_manually_connects_event_services_without_discovery<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(services, len 2);
+        let (event_services, publish_subscribe_services): (Vec<_>, Vec<_>) = services
+            .into_iter()
+            .partition(|service| service.service_id() == iox_event_service.service_id());
+
+        // ==================== TEST =====================
+
+        let result = tunnel.add_event_connection_manually(event_services[0].clone());
+        assert_that!(result, is_ok);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+        assert_that!(
+            tunnel.service_discovery_source(iox_event_service.service_id()),
+            eq Some(Scope::None)
+        );
+
+        let result = tunnel.add_event_connection_manually(event_services[0].clone());
+        assert_that!(result, is_ok);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+
+        let result = tunnel.add_event_connection_manually(publish_subscribe_services[0].clone());
+        assert_that!(
+            matches!(result, Err(CreationError::UnexpectedMessagingPattern)),
+            eq true
+        );
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 0);
+    }
+
+    #[test]
+    fn publisher_options_reflect_the_message_loss_tolerance_of_the_service<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_best_effort_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .open_or_create()
+            .unwrap();
+        let iox_reliable_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .history_size(0)
+            .subscriber_max_buffer_size(1)
+            .open_or_create()
+            .unwrap();
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        let static_config_of = |id: &iceoryx2::service::service_id::ServiceId| {
+            services
+                .iter()
+                .find(|service| service.service_id() == id)
+                .unwrap()
+                .clone()
+        };
+
+        // ==================== TEST =====================
+
+        let options = iox_qos_to_zenoh_publisher_options(&static_config_of(
+            iox_best_effort_service.service_id(),
+        ));
+        assert_that!(options.reliability, eq zenoh::qos::Reliability::BestEffort);
+        assert_that!(options.congestion_control, eq zenoh::qos::CongestionControl::Drop);
+        assert_that!(options.priority, eq zenoh::qos::Priority::Data);
+
+        let options = iox_qos_to_zenoh_publisher_options(&static_config_of(
+            iox_reliable_service.service_id(),
+        ));
+        assert_that!(options.reliability, eq zenoh::qos::Reliability::Reliable);
+        assert_that!(options.congestion_control, eq zenoh::qos::CongestionControl::Block);
+        assert_that!(options.priority, eq zenoh::qos::Priority::DataHigh);
+    }
+
+    #[test]
+    fn blocklisted_services_are_never_tunneled<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let blocked_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let internal_service_name = ServiceName::new(&format!(
+            "internal/{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let internal_service = iox_node
+            .service_builder(&internal_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let other_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let tunnel_config = TunnelConfig {
+            service_blocklist: Some(ServiceBlocklist {
+                service_ids: vec![blocked_service.service_id().clone()],
+                name_patterns: vec![String::from("internal/*")],
+            }),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // ==================== TEST =====================
+
+        for _ in 0..3 {
+            tunnel.discover(Scope::Both).unwrap();
+
+            let tunneled_services = tunnel.tunneled_service_infos();
+            assert_that!(tunneled_services, len 1);
+            assert_that!(
+                tunneled_services[0].service_id,
+                eq * other_service.service_id()
+            );
+            assert_that!(
+                tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *internal_service.service_id()),
+                eq false
+            );
+        }
+    }
+
+    #[test]
+    fn iceoryx_node_of_tunnel_is_alive_before_and_after_reconnecting_all<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.check_iceoryx_node_health(), eq IceoryxNodeHealth::Alive);
+
+        assert_that!(tunnel.reconnect_all(), is_ok);
+
+        assert_that!(tunnel.check_iceoryx_node_health(), eq IceoryxNodeHealth::Alive);
+        let tunneled_services = tunnel.tunneled_service_infos();
+        assert_that!(tunneled_services, len 1);
+        assert_that!(
+            tunneled_services[0].service_id,
+            eq * iox_service.service_id()
+        );
+    }
+
+    #[test]
+    fn global_propagation_can_be_halted_and_resumed<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier = iox_service.notifier_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.is_global_propagation_enabled(), eq true);
+
+        tunnel.set_global_propagation_enabled(false);
+        assert_that!(tunnel.is_global_propagation_enabled(), eq false);
+
+        iox_notifier.notify().unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 0);
+        assert_that!(tunnel.propagate_once_and_report().forwarded(), is_empty);
+
+        // The pending event is propagated once resumed
+        tunnel.set_global_propagation_enabled(true);
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+    }
+
+    #[test]
+    fn connection_lifecycle_callbacks_are_called_on_creation_and_removal<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        let created = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let removed = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let created_sink = created.clone();
+        let removed_sink = removed.clone();
+        let tunnel_config = TunnelConfig {
+            max_connections: Some(1),
+            max_connections_policy: MaxConnectionsPolicy::EvictLeastRecentlyUsed,
+            on_connection_created: Some(std::sync::Arc::new(move |info: &TunneledServiceInfo| {
+                created_sink.lock().unwrap().push(info.clone())
+            })),
+            on_connection_removed: Some(std::sync::Arc::new(
+                move |id: &iceoryx2::service::service_id::ServiceId| {
+                    removed_sink.lock().unwrap().push(id.clone())
+                },
+            )),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_service_b = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // The connection created first was evicted for the second one
+        let created = created.lock().unwrap().clone();
+        let removed = removed.lock().unwrap().clone();
+        assert_that!(created, len 2);
+        assert_that!(removed, len 1);
+        assert_that!(removed[0], eq created[0].service_id);
+        assert_that!(created[0].pattern, eq MessagingPattern::Event);
+        assert_that!(created[0].discovery_scope, eq Scope::Iceoryx);
+        assert_that!(
+            [iox_service_a.service_id().clone(), iox_service_b.service_id().clone()],
+            contains created[1].service_id.clone()
+        );
+    }
+
+    #[test]
+    fn add_connection_for_service_connects_known_services_idempotently<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(services, len 1);
+
+        // ==================== TEST =====================
+
+        let result = tunnel.add_connection_for_service(&services[0], Scope::Zenoh);
+        assert_that!(result, eq Ok(()));
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(
+            tunnel.service_discovery_source(iox_service.service_id()),
+            eq Some(Scope::Zenoh)
+        );
+
+        let result = tunnel.add_connection_for_service(&services[0], Scope::Iceoryx);
+        assert_that!(result, eq Ok(()));
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(
+            tunnel.service_discovery_source(iox_service.service_id()),
+            eq Some(Scope::Zenoh)
+        );
+    }
+
+    #[test]
+    fn max_queue_depth_observed_is_the_maximum_number_of_unread_samples<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+        let iox_service_id = iox_service.service_id().clone();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.service_max_queue_depth_observed(&iox_service_id), eq Some(0));
+
+        for n in 0..3 {
+            iox_publisher.send_copy(n).unwrap();
+        }
+        tunnel.propagate();
+        assert_that!(tunnel.service_max_queue_depth_observed(&iox_service_id), eq Some(3));
+
+        iox_publisher.send_copy(3).unwrap();
+        tunnel.propagate();
+        assert_that!(tunnel.service_max_queue_depth_observed(&iox_service_id), eq Some(3));
+
+        let unknown_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        assert_that!(
+            tunnel.service_max_queue_depth_observed(unknown_service.service_id()),
+            eq None
+        );
+    }
+
+    #[test]
+    fn announced_tunnel_capabilities_can_be_queried_remotely<S: Service>() {
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        let key_prefix = format!(
+            "iox2/test/capabilities/{}",
+            UniqueSystemId::new().unwrap().value()
+        );
+
+        // === TEST ===
+        let handle = tunnel.announce_tunnel_capabilities(&key_prefix).unwrap();
+        assert_that!(handle.key(), eq format!("{}/{}", key_prefix, tunnel.zenoh_session_id()));
+        assert_that!(handle.capabilities(), eq TunnelCapabilities::default());
+        assert_that!(handle.capabilities().version, eq TUNNEL_CAPABILITIES_VERSION);
+
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let announced = loop {
+            let z_replies = z_session
+                .get(format!("{}/*", key_prefix))
+                .timeout(Duration::from_millis(200))
+                .wait()
+                .unwrap();
+            if let Ok(z_reply) = z_replies.recv() {
+                if let Ok(z_sample) = z_reply.result() {
+                    break TunnelCapabilities::from_json(&z_sample.payload().to_bytes());
+                }
+            }
+            if std::time::Instant::now() >= deadline {
+                test_fail!("no reply to capabilities query");
+            }
+        };
+        assert_that!(announced, eq Some(handle.capabilities()));
+
+        let invalid = tunnel.announce_tunnel_capabilities("iox2/**/#invalid");
+        assert_that!(invalid.err(), eq Some(AnnounceError::InvalidKeyExpression));
+    }
+
+    #[cfg(feature = "toml-config")]
+    #[test]
+    fn tunnel_config_round_trips_through_toml_file<S: Service>() {
+        // === SETUP ===
+        let config = TunnelConfig {
+            discovery_service: Some(String::from("iox2://discovery")),
+            propagation_policy: PropagationPolicy::DisableAfter(3),
+            min_discovery_interval: Some(Duration::from_millis(250)),
+            max_connections: Some(8),
+            max_connections_policy: MaxConnectionsPolicy::EvictLeastRecentlyUsed,
+            zenoh_session_options: ZenohSessionOptions {
+                multicast_scouting: Some(false),
+                ..Default::default()
+            },
+            service_name_allowlist: Some(vec![String::from("camera/front")]),
+            service_blocklist: Some(ServiceBlocklist {
+                service_ids: vec![],
+                name_patterns: vec![String::from("internal/*")],
+            }),
+            reconnect_policy: ReconnectPolicy::ExponentialBackoff {
+                initial_delay: Duration::from_millis(100),
+                max_delay: Duration::from_secs(5),
+                max_attempts: 10,
+            },
+            connection_direction: ConnectionDirection::IceoryxToZenoh,
+            event_batching: Some(EventBatching {
+                batch_size: 16,
+                batch_timeout: Duration::from_micros(500),
+            }),
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join(format!(
+            "iox2_tunnel_config_{}.toml",
+            UniqueSystemId::new().unwrap().value()
+        ));
+        std::fs::write(&path, toml::to_string(&config).unwrap()).unwrap();
+
+        // === TEST ===
+        let loaded = TunnelConfig::from_toml_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        assert_that!(loaded.discovery_service, eq config.discovery_service);
+        assert_that!(loaded.propagation_policy, eq config.propagation_policy);
+        assert_that!(loaded.min_discovery_interval, eq config.min_discovery_interval);
+        assert_that!(loaded.max_connections, eq config.max_connections);
+        assert_that!(loaded.max_connections_policy, eq config.max_connections_policy);
+        assert_that!(loaded.zenoh_session_options, eq config.zenoh_session_options);
+        assert_that!(loaded.service_name_allowlist, eq config.service_name_allowlist);
+        assert_that!(loaded.service_blocklist, eq config.service_blocklist);
+        assert_that!(loaded.reconnect_policy, eq config.reconnect_policy);
+        assert_that!(loaded.connection_direction, eq config.connection_direction);
+        assert_that!(loaded.event_batching, eq config.event_batching);
+
+        let missing = TunnelConfig::from_toml_file(&path.with_extension("missing"));
+        assert_that!(missing.err(), eq Some(ConfigLoadError::FailedToReadConfigFileContents));
+    }
+
+    #[test]
+    fn payloads_are_only_published_to_the_allowed_zenoh_destination<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            zenoh_allowed_destination: Some(zenoh::sample::Locality::SessionLocal),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+        assert_that!(tunnel.zenoh_publisher_allowed_destination(), eq zenoh::sample::Locality::SessionLocal);
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_subscriber = z_session
+            .declare_subscriber(keys::publish_subscribe(iox_service.service_id()))
+            .wait()
+            .unwrap();
+        let publish_and_receive = |tunnel: &mut Tunnel<S>, attempts: usize| {
+            for _ in 0..attempts {
+                iox_publisher.send_copy(42).unwrap();
+                tunnel.propagate();
+                if let Ok(Some(_)) = z_subscriber.recv_timeout(TIME_BETWEEN_RETRIES) {
+                    return true;
+                }
+            }
+            false
+        };
+
+        // === TEST ===
+        assert_that!(publish_and_receive(&mut tunnel, 10), eq false);
+
+        tunnel.set_zenoh_publisher_allowed_destination(zenoh::sample::Locality::Remote);
+        assert_that!(tunnel.zenoh_publisher_allowed_destination(), eq zenoh::sample::Locality::Remote);
+        tunnel.reconnect(&zenoh::Config::default()).unwrap();
+        assert_that!(publish_and_receive(&mut tunnel, MAX_RETRIES), eq true);
+    }
+
+    #[test]
+    fn run_until_cancelled_discovers_and_stops_when_cancelled<S: Service>() {
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // === TEST ===
+        let token = CancellationToken::new();
+        let canceller = {
+            let token = token.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(300));
+                token.cancel();
+            })
+        };
+
+        let start = std::time::Instant::now();
+        let result = tunnel.run_until_cancelled(Scope::Iceoryx, Duration::from_millis(10), &token);
+        canceller.join().unwrap();
+
+        assert_that!(result, eq Ok(()));
+        assert_that!(token.is_cancelled(), eq true);
+        assert_that!(start.elapsed(), ge Duration::from_millis(300));
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            tunnel.tunneled_service_infos()[0].service_id,
+            eq * iox_service.service_id()
+        );
+    }
+
+    #[test]
+    fn wait_for_service_returns_once_service_appears<S: Service>() {
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        let iox_service_name = mock_service_name();
+
+        let (release, released) = std::sync::mpsc::channel::<()>();
+        let (created, creation) = std::sync::mpsc::channel();
+        let launcher = {
+            let iox_config = iox_config.clone();
+            let iox_service_name = iox_service_name.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                let iox_node = NodeBuilder::new()
+                    .config(&iox_config)
+                    .create::<S>()
+                    .unwrap();
+                let iox_service = iox_node
+                    .service_builder(&iox_service_name)
+                    .event()
+                    .open_or_create()
+                    .unwrap();
+                created.send(iox_service.service_id().clone()).unwrap();
+                let _ = released.recv();
+            })
+        };
+
+        // === TEST ===
+        let timeout = Duration::from_secs(10);
+        let start = std::time::Instant::now();
+        let result = tunnel.wait_for_service(iox_service_name.as_str(), timeout);
+        assert_that!(start.elapsed(), lt timeout);
+        assert_that!(result, eq Ok(creation.recv().unwrap()));
+
+        let result = tunnel.wait_for_service("does/not/exist", Duration::from_millis(50));
+        assert_that!(result, eq Err(WaitError::Timeout));
+
+        release.send(()).unwrap();
+        launcher.join().unwrap();
+    }
+
+    #[test]
+    fn remote_services_are_discovered_via_scouting_endpoint<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // === SETUP ===
+        let port = 20000 + UniqueSystemId::new().unwrap().value() % 20000;
+        let endpoint = format!("tcp/127.0.0.1:{port}");
+
+        // [[ HOST A ]]
+        let mut z_config_a = zenoh::Config::default();
+        z_config_a
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        z_config_a
+            .insert_json5("listen/endpoints", &format!("[\"{endpoint}\"]"))
+            .unwrap();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let mut z_config_b = zenoh::Config::default();
+        z_config_b
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig {
+            discovery_scouting_endpoint: Some(endpoint),
+            ..Default::default()
+        };
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // === TEST ===
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service via scouting endpoint")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn propagated_payloads_carry_service_attachment<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        let iox_service_name = mock_service_name();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_subscriber = z_session
+            .declare_subscriber(keys::publish_subscribe(iox_service.service_id()))
+            .wait()
+            .unwrap();
+
+        // === TEST ===
+        let mut z_sample = None;
+        for _ in 0..MAX_RETRIES {
+            iox_publisher.send_copy(42).unwrap();
+            tunnel.propagate();
+            if let Ok(Some(sample)) = z_subscriber.recv_timeout(TIME_BETWEEN_RETRIES) {
+                z_sample = Some(sample);
+                break;
+            }
+        }
+        let Some(z_sample) = z_sample else {
+            test_fail!("no payload received on zenoh");
+        };
+
+        let z_attachment = z_sample.attachment().unwrap().to_bytes();
+        let origin = ServiceAttachment::from_json(&z_attachment).unwrap();
+        assert_that!(origin.service_name, eq iox_service_name.to_string());
+        assert_that!(origin.service_id, eq iox_service.service_id().as_str());
+        assert_that!(ServiceAttachment::from_json(b"not json"), is_none);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compress_all_connections_updates_publish_subscribe_connections<S: Service>() {
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service_a = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let _iox_service_b = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let inbound_only_config = TunnelConfig {
+            connection_direction: ConnectionDirection::ZenohToIceoryx,
+            ..Default::default()
+        };
+        let mut inbound_only_tunnel =
+            Tunnel::<S>::create(&inbound_only_config, &iox_config, &zenoh::Config::default())
+                .unwrap();
+        inbound_only_tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // === TEST ===
+        let compression = CompressionConfig {
+            codec: CompressionCodec::Lz4,
+            min_size_bytes: 128,
+        };
+        assert_that!(tunnel.compress_all_connections(compression), eq Ok(2));
+        assert_that!(
+            inbound_only_tunnel.compress_all_connections(compression),
+            eq Err(CompressionError::UnsupportedConnections {
+                updated: 0,
+                unsupported: 2
+            })
+        );
+    }
+
+    #[test]
+    fn service_ids_are_exported_as_json_array<S: Service>() {
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        assert_that!(tunnel.service_ids_as_json_array(), eq "[]");
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_pubsub_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // === TEST ===
+        let json = tunnel.service_ids_as_json_array();
+        let ids: Vec<String> = serde_json::from_str(&json).unwrap();
+        assert_that!(ids, len 2);
+        assert_that!(ids, contains iox_pubsub_service.service_id().as_str().to_string());
+        assert_that!(ids, contains iox_event_service.service_id().as_str().to_string());
+    }
+
+    #[cfg(feature = "crossbeam")]
+    #[test]
+    fn observed_service_traffic_is_received_on_channel<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let unknown_id = {
+            let iox_other_service = iox_node
+                .service_builder(&mock_service_name())
+                .event()
+                .open_or_create()
+                .unwrap();
+            iox_other_service.service_id().clone()
+        };
+        let result = tunnel.observe_service_traffic(&unknown_id, 1);
+        assert_that!(result.err(), eq Some(ObserveError::ServiceNotFound));
+
+        // === TEST ===
+        let receiver = tunnel
+            .observe_service_traffic(iox_service.service_id(), 4)
+            .unwrap();
+        let observer = std::thread::spawn(move || receiver.recv_timeout(Duration::from_secs(10)));
+
+        for _ in 0..MAX_RETRIES {
+            iox_publisher.send_copy(42u64).unwrap();
+            tunnel.propagate();
+            if observer.is_finished() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        let (direction, payload) = observer.join().unwrap().unwrap();
+        assert_that!(direction, eq Direction::IceoryxToZenoh);
+        assert_that!(payload, eq 42u64.to_ne_bytes().to_vec());
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn payloads_with_mismatching_checksum_are_dropped<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+        let tunnel_config = TunnelConfig {
+            checksum_verification: Some(ChecksumAlgorithm::Crc32c),
+            ..Default::default()
+        };
+
+        // [[ HOST A ]]
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = Tunnel::<S>::create(&tunnel_config, &iox_config_a, &z_config_a).unwrap();
+        assert_that!(tunnel_a.connection_checksum_verification(), eq Some(ChecksumAlgorithm::Crc32c));
+
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = Tunnel::<S>::create(&tunnel_config, &iox_config_b, &z_config_b).unwrap();
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // A payload with a valid checksum is propagated
+        retry(
+            || {
+                iox_publisher_a.send_copy(42).unwrap();
+                tunnel_a.propagate();
+                tunnel_b.propagate();
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 42 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        while iox_subscriber_b.receive().unwrap().is_some() {}
+
+        // A corrupted payload is dropped and counted
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_publisher = z_session
+            .declare_publisher(keys::publish_subscribe(iox_service_a.service_id()))
+            .wait()
+            .unwrap();
+        retry(
+            || {
+                z_publisher.put([0u8; 12].to_vec()).wait().unwrap();
+                tunnel_b.propagate();
+                let corrupted_messages_count = tunnel_b
+                    .statistics()
+                    .get(iox_service_a.service_id())
+                    .map_or(0, |statistics| statistics.corrupted_messages_count);
+                if corrupted_messages_count > 0 {
+                    return Ok(());
+                }
+                Err("failed to drop corrupted payload")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(iox_subscriber_b.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn remove_connection_removes_tunneled_services<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        let removed = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let removed_sink = removed.clone();
+        let tunnel_config = TunnelConfig {
+            on_connection_removed: Some(std::sync::Arc::new(
+                move |id: &iceoryx2::service::service_id::ServiceId| {
+                    removed_sink.lock().unwrap().push(id.clone())
+                },
+            )),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.remove_connection(iox_publish_subscribe_service.service_id()), eq true);
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 0);
+        assert_that!(tunnel.remove_connection(iox_event_service.service_id()), eq true);
+        assert_that!(tunnel.num_total_event_connections(), eq 0);
+        assert_that!(tunnel.tunneled_service_infos(), is_empty);
+
+        // Removing a service that is not tunneled has no effect
+        assert_that!(tunnel.remove_connection(iox_event_service.service_id()), eq false);
+
+        let removed = removed.lock().unwrap().clone();
+        assert_that!(
+            removed,
+            eq vec![
+                iox_publish_subscribe_service.service_id().clone(),
+                iox_event_service.service_id().clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn service_names_are_sorted_alphabetically<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+        assert_that!(tunnel.service_names_sorted(), is_empty);
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let prefix = mock_service_name();
+        let names = ["c", "a", "b"].map(|suffix| format!("{}/{}", prefix.as_str(), suffix));
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&ServiceName::new(&names[0]).unwrap())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&ServiceName::new(&names[1]).unwrap())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _iox_other_event_service = iox_node
+            .service_builder(&ServiceName::new(&names[2]).unwrap())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let mut expected = names.to_vec();
+        expected.sort();
+        assert_that!(tunnel.service_names_sorted(), eq expected);
+    }
+
+    #[cfg(feature = "latency-tracking")]
+    #[test]
+    fn latency_of_remote_payloads_is_reported<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_a, &z_config_a).unwrap();
+
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_b, &z_config_b).unwrap();
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(tunnel_b.latency_report(), is_empty);
+
+        // ==================== TEST =====================
+
+        retry(
+            || {
+                iox_publisher_a.send_copy(42).unwrap();
+                tunnel_a.propagate();
+                tunnel_b.propagate();
+                if tunnel_b
+                    .latency_report()
+                    .contains_key(iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to record latency")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        let latency_stats = tunnel_b.latency_report()[iox_service_a.service_id()];
+        assert_that!(latency_stats.p50_us, le latency_stats.p95_us);
+        assert_that!(latency_stats.p95_us, le latency_stats.p99_us);
+        assert_that!(latency_stats.p99_us, le latency_stats.max_us);
+        assert_that!(tunnel_a.latency_report(), is_empty);
+    }
+
+    #[test]
+    fn deduplicated_discovery_connects_services_discovered_in_both_scopes_once<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+
+        let created = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let created_sink = created.clone();
+        let tunnel_config = TunnelConfig {
+            on_connection_created: Some(std::sync::Arc::new(move |info: &TunneledServiceInfo| {
+                created_sink.lock().unwrap().push(info.clone())
+            })),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+        tunnel.enable_discovery_deduplication();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Both).unwrap();
+        tunnel.discover(Scope::Both).unwrap();
+
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(
+            tunnel.service_discovery_source(iox_service.service_id()),
+            eq Some(Scope::Iceoryx)
+        );
+        let created = created.lock().unwrap().clone();
+        assert_that!(created, len 1);
+        assert_that!(created[0].service_id, eq iox_service.service_id().clone());
+
+        // Disabling the deduplication does not affect the tunneled services
+        tunnel.disable_discovery_deduplication();
+        tunnel.discover(Scope::Both).unwrap();
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+    }
+
+    #[test]
+    fn propagates_payloads_with_additional_zenoh_sessions<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+        let payload_data = [b'A'; 16];
+
+        // [[ HOST A ]]
+        // Tunnel
+        let tunnel_config_a = TunnelConfig {
+            zenoh_sessions: vec![zenoh::Config::default()],
+            ..Default::default()
+        };
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a
+            .publisher_builder()
+            .initial_max_slice_len(payload_data.len())
+            .create()
+            .unwrap();
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config_b, &z_config_b).unwrap();
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        let labels = tunnel_a.zenoh_session_labels();
+        assert_that!(labels, len 2);
+        assert_that!(labels[0], eq tunnel_a.zenoh_session_id());
+        assert_that!(labels[1], ne labels[0]);
+
+        retry(
+            || {
+                let iox_sample_sent_a = iox_publisher_a
+                    .loan_slice_uninit(payload_data.len())
+                    .unwrap();
+                iox_sample_sent_a
+                    .write_from_slice(&payload_data)
+                    .send()
+                    .unwrap();
+                tunnel_a.propagate();
+                tunnel_b.propagate();
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) => {
+                        if *iox_sample_received_b.payload() == payload_data {
+                            Ok(())
+                        } else {
+                            Err("received unexpected sample")
+                        }
+                    }
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn force_rediscover_recreates_connections_of_scope<S: Service>() {
+        // ==================== SETUP ====================
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_pubsub_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+
+        assert_that!(tunnel.set_connection_metadata(iox_pubsub_service.service_id(), "owner", String::from("a")), eq true);
+
+        // ==================== TEST =====================
+        tunnel.force_rediscover(Scope::Iceoryx).unwrap();
+
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+        let tunneled_services = tunnel.tunneled_service_infos();
+        assert_that!(tunneled_services.iter().any(|info| info.service_id == *iox_pubsub_service.service_id()), eq true);
+        assert_that!(tunneled_services.iter().any(|info| info.service_id == *iox_event_service.service_id()), eq true);
+        assert_that!(tunnel.service_discovery_source(iox_pubsub_service.service_id()), eq Some(Scope::Iceoryx));
+        // The connections were recreated
+        assert_that!(
+            tunnel.get_connection_metadata(iox_pubsub_service.service_id(), "owner"),
+            is_none
+        );
+    }
+
+    #[test]
+    fn creates_remote_services_with_configured_port_limits<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+        const MAX_PUBLISHERS: u64 = 3;
+        const MAX_SUBSCRIBERS: u64 = 4;
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config_a,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(8)
+            .max_subscribers(8)
+            .open_or_create()
+            .unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let tunnel_config_b = TunnelConfig {
+            iceoryx_max_publishers: Some(MAX_PUBLISHERS),
+            iceoryx_max_subscribers: Some(MAX_SUBSCRIBERS),
+            ..Default::default()
+        };
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &zenoh::Config::default())
+                .unwrap();
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // ==================== TEST =====================
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        assert_that!(iox_service_b.static_config().max_publishers(), eq MAX_PUBLISHERS as usize);
+        assert_that!(iox_service_b.static_config().max_subscribers(), eq MAX_SUBSCRIBERS as usize);
+    }
+
+    #[test]
+    fn exports_connection_state_machine_diagram<S: Service>() {
+        // ==================== SETUP ====================
+        let tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &generate_isolated_config(),
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // ==================== TEST =====================
+        let mermaid = tunnel.export_connection_state_machine_diagram(DiagramFormat::Mermaid);
+        assert_that!(mermaid.starts_with("stateDiagram-v2\n"), eq true);
+        assert_that!(mermaid.contains("    [*] --> Creating: service discovered\n"), eq true);
+        assert_that!(mermaid.contains("    Active --> Paused: "), eq true);
+        assert_that!(mermaid.contains("    Closed --> [*]: "), eq true);
+
+        let plantuml = tunnel.export_connection_state_machine_diagram(DiagramFormat::PlantUml);
+        assert_that!(plantuml.starts_with("@startuml\n"), eq true);
+        assert_that!(plantuml.ends_with("@enduml\n"), eq true);
+        assert_that!(plantuml.contains("    Active --> Resetting : "), eq true);
+        assert_that!(plantuml.lines().count(), eq mermaid.lines().count() + 1);
+    }
+
+    #[test]
+    fn discovers_services_of_tunnels_with_different_discovery_modes<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        let tunnel_config_a = TunnelConfig {
+            discovery_mode: DiscoveryMode::Queryable,
+            ..Default::default()
+        };
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &zenoh::Config::default())
+                .unwrap();
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config_b,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        tunnel_b.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST C ]]
+        let iox_config_c = generate_isolated_config();
+        let mut tunnel_c =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_c, &zenoh::Config::default())
+                .unwrap();
+
+        // ==================== TEST =====================
+
+        // Discovered via the services announced by the tunnel on host a
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service announced by host a")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Discovered via the registry of the tunnel on host a
+        retry(
+            || {
+                tunnel_c.discover(Scope::Zenoh).unwrap();
+                if tunnel_c
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service in registry of host a")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Tunnels in the per-service mode serve no registry
+        tunnel_c.discover(Scope::Zenoh).unwrap();
+        assert_that!(
+            tunnel_c
+                .tunneled_service_infos()
+                .iter()
+                .any(|info| info.service_id == *iox_service_b.service_id()),
+            eq false
+        );
+    }
+
+    #[test]
+    fn max_connections_connects_pending_services_once_there_is_room<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            max_connections: Some(2),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_services: Vec<_> = (0..3)
+            .map(|_| {
+                iox_node
+                    .service_builder(&mock_service_name())
+                    .event()
+                    .open_or_create()
+                    .unwrap()
+            })
+            .collect();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.num_total_event_connections(), eq 2);
+        assert_that!(tunnel.num_pending_connections(), eq 1);
+
+        // Discovering the pending service again does not queue it twice
+        tunnel.force_immediate_discovery(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.num_pending_connections(), eq 1);
+
+        let tunneled_services = tunnel.tunneled_service_infos();
+        let pending_service = iox_services
+            .iter()
+            .find(|iox_service| {
+                !tunneled_services
+                    .iter()
+                    .any(|info| info.service_id == *iox_service.service_id())
+            })
+            .unwrap();
+        let removed_service = iox_services
+            .iter()
+            .find(|iox_service| iox_service.service_id() != pending_service.service_id())
+            .unwrap();
+
+        assert_that!(tunnel.remove_connection(removed_service.service_id()), eq true);
+        assert_that!(tunnel.num_total_event_connections(), eq 2);
+        assert_that!(tunnel.num_pending_connections(), eq 0);
+        assert_that!(tunnel.service_discovery_source(pending_service.service_id()), eq Some(Scope::Iceoryx));
+    }
+
+    #[test]
+    fn message_ids_of_propagated_payloads_are_tracked<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+        let tunnel_config = TunnelConfig {
+            track_message_ids: true,
+            ..Default::default()
+        };
+
+        // [[ HOST A ]]
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = Tunnel::<S>::create(&tunnel_config, &iox_config_a, &z_config_a).unwrap();
+
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = Tunnel::<S>::create(&tunnel_config, &iox_config_b, &z_config_b).unwrap();
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b
+                    .tunneled_service_infos()
+                    .iter()
+                    .any(|info| info.service_id == *iox_service_a.service_id())
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+        assert_that!(
+            tunnel_b.last_received_message_id(iox_service_a.service_id()),
+            is_none
+        );
+
+        // ==================== TEST =====================
+
+        // The message id is stripped from the propagated payload
+        retry(
+            || {
+                iox_publisher_a.send_copy(42).unwrap();
+                tunnel_a.propagate();
+                tunnel_b.propagate();
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 42 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        while iox_subscriber_b.receive().unwrap().is_some() {}
+        let first_message_id = tunnel_b
+            .last_received_message_id(iox_service_a.service_id())
+            .unwrap();
+
+        // Each payload increments the message id
+        iox_publisher_a.send_copy(43).unwrap();
+        tunnel_a.propagate();
+        retry(
+            || {
+                tunnel_b.propagate();
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 43 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(tunnel_b.last_received_message_id(iox_service_a.service_id()), eq Some(first_message_id + 1));
+    }
+
+    #[test]
+    fn services_are_active_once_they_forwarded_a_payload<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Service
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Service that is never discovered by the tunnel
+        let iox_config_b = generate_isolated_config();
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel_a.service_is_active(iox_service_a.service_id()), eq None);
+
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        tunnel_a.propagate();
+        assert_that!(tunnel_a.service_is_active(iox_service_a.service_id()), eq Some(false));
+        assert_that!(tunnel_a.service_is_active(iox_service_b.service_id()), eq None);
+
+        iox_publisher_a.send_copy(42).unwrap();
+        tunnel_a.propagate();
+        assert_that!(tunnel_a.service_is_active(iox_service_a.service_id()), eq Some(true));
+    }
+
+    #[test]
+    fn merge_from_moves_connections_of_other_tunnel<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        let iox_service_b = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        tunnel_b.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(tunnel_b.num_total_publish_subscribe_connections(), eq 2);
+        let z_session_label_b = tunnel_b.zenoh_session_id();
+
+        // ==================== TEST =====================
+
+        let merged = tunnel_a.merge_from(tunnel_b, MergePolicy::Skip).unwrap();
+
+        // The service tunneled by both tunnels is skipped
+        assert_that!(merged, eq 1);
+        assert_that!(tunnel_a.num_total_publish_subscribe_connections(), eq 2);
+        assert_that!(tunnel_a.service_discovery_source(iox_service_a.service_id()), eq Some(Scope::Iceoryx));
+        assert_that!(tunnel_a.service_discovery_source(iox_service_b.service_id()), eq Some(Scope::Iceoryx));
+        assert_that!(tunnel_a.zenoh_session_labels(), len 2);
+        assert_that!(tunnel_a.zenoh_session_labels(), contains z_session_label_b);
+    }
+
+    #[test]
+    fn tunnel_limited_to_pattern_ignores_services_of_other_patterns<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create_limited_to_pattern(
+            &TunnelConfig::default(),
+            &iox_config,
+            &z_config,
+            MessagingPattern::Event,
+        )
+        .unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 0);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+        assert_that!(tunnel.service_discovery_source(iox_event_service.service_id()), eq Some(Scope::Iceoryx));
+    }
+
+    #[test]
+    fn effective_qos_reflects_current_connection_settings<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            qos_mapping: QosMapping::FromService,
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .open_or_create()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let qos = tunnel
+            .service_effective_qos(iox_service.service_id())
+            .unwrap();
+        assert_that!(qos.priority, eq zenoh::qos::Priority::Data);
+        assert_that!(qos.reliability, eq zenoh::qos::Reliability::BestEffort);
+        assert_that!(qos.congestion_control, eq zenoh::qos::CongestionControl::Drop);
+        assert_that!(qos.rate_limit, eq None);
+
+        assert_that!(tunnel.set_rate_limit(iox_service.service_id(), Some(10)), eq true);
+        let qos = tunnel
+            .service_effective_qos(iox_service.service_id())
+            .unwrap();
+        assert_that!(qos.rate_limit, eq Some(10.0));
+
+        let unknown = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        assert_that!(tunnel.service_effective_qos(unknown.service_id()), eq None);
+    }
+
+    #[test]
+    fn asserting_service_counts_checks_tunneled_services_per_pattern<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        tunnel.assert_service_count(2);
+        tunnel.assert_publish_subscribe_count(1);
+        tunnel.assert_event_count(1);
+
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            tunnel.assert_service_count(3);
+        }));
+        assert_that!(result, is_err);
+    }
+
+    #[test]
+    fn run_discovers_according_to_min_discovery_interval<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            min_discovery_interval: Some(Duration::from_secs(3600)),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let token = CancellationToken::new();
+        let canceller = {
+            let token = token.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                token.cancel();
+            })
+        };
+        let result = tunnel.run_until_cancelled(Scope::Iceoryx, Duration::from_millis(10), &token);
+        canceller.join().unwrap();
+
+        // The run loop does not discover within the interval
+        assert_that!(result, eq Ok(()));
+        assert_that!(tunnel.tunneled_service_infos(), len 0);
+
+        tunnel.force_immediate_discovery(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+
+    #[test]
+    fn debug_output_shows_number_of_connections<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let debug = format!("{tunnel:?}");
+        assert_that!(debug.contains("connections"), eq true);
+        assert_that!(debug.contains("publish_subscribe_connections: 1"), eq true);
+        assert_that!(debug.contains(&tunnel.zenoh_session_id()), eq true);
+    }
+
+    #[test]
+    fn propagate_with_context_passes_context_to_interceptor<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_accepted_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_accepted_publisher = iox_accepted_service.publisher_builder().create().unwrap();
+        let iox_intercepted_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_intercepted_publisher = iox_intercepted_service
+            .publisher_builder()
+            .create()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        iox_accepted_publisher.send_copy(1).unwrap();
+        iox_intercepted_publisher.send_copy(2).unwrap();
+
+        let accepted = iox_accepted_service.service_id().clone();
+        let interceptor =
+            |id: &iceoryx2::service::service_id::ServiceId,
+             accepted: &iceoryx2::service::service_id::ServiceId| *id == *accepted;
+        let report = tunnel.propagate_with_context(&accepted, &interceptor);
+
+        assert_that!(report.is_success(), eq true);
+        assert_that!(tunnel.service_is_active(iox_accepted_service.service_id()), eq Some(true));
+        assert_that!(tunnel.service_is_active(iox_intercepted_service.service_id()), eq Some(false));
+    }
+
+    #[cfg(feature = "toml-config")]
+    #[test]
+    fn tunnel_is_created_from_unified_toml_file<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            max_connections: Some(8),
+            ..Default::default()
+        };
+        let mut z_config = toml::Table::new();
+        z_config.insert(String::from("mode"), toml::Value::from("peer"));
+
+        let mut unified = toml::Table::new();
+        unified.insert(
+            String::from("tunnel"),
+            toml::Value::try_from(&tunnel_config).unwrap(),
+        );
+        unified.insert(
+            String::from("iceoryx"),
+            toml::Value::try_from(&iox_config).unwrap(),
+        );
+        unified.insert(String::from("zenoh"), toml::Value::Table(z_config));
+
+        let path = std::env::temp_dir().join(format!(
+            "iox2_unified_tunnel_config_{}.toml",
+            UniqueSystemId::new().unwrap().value()
+        ));
+        std::fs::write(&path, toml::to_string(&unified).unwrap()).unwrap();
+
+        // ==================== TEST =====================
+
+        let loaded = UnifiedTunnelConfig::from_toml_file(&path);
+        let tunnel = Tunnel::<S>::create_from_unified(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        assert_that!(loaded.tunnel.max_connections, eq Some(8));
+        assert_that!(loaded.iceoryx, eq iox_config);
+        assert_that!(loaded.zenoh.get_json("mode").unwrap(), eq "\"peer\"");
+        assert_that!(tunnel, is_ok);
+
+        let missing = Tunnel::<S>::create_from_unified(&path.with_extension("missing"));
+        assert_that!(
+            matches!(
+                missing,
+                Err(CreationError::ConfigLoadFailed(
+                    ConfigLoadError::FailedToReadConfigFileContents
+                ))
+            ),
+            eq true
+        );
+    }
+
+    #[test]
+    fn services_with_invalid_mapped_key_expressions_are_not_connected<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            key_expr_mapper: Some(std::sync::Arc::new(|iox_service_config: &StaticConfig| {
+                format!("plant/line_1/{}", iox_service_config.name())
+            })),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        // The empty chunk is not allowed in zenoh key expressions
+        let iox_service_name =
+            ServiceName::new(&format!("{}//front", mock_service_name().as_str())).unwrap();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_event_service = iox_node
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(services, len 1);
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.num_total_event_connections(), eq 0);
+        assert_that!(tunnel.failed_service_count(), eq 1);
+
+        let result = tunnel.add_event_connection_manually(services[0].clone());
+        match result {
+            Err(CreationError::InvalidKeyExpression {
+                service_name,
+                key_expr,
+                ..
+            }) => {
+                assert_that!(service_name, eq iox_service_name.as_str());
+                assert_that!(key_expr, eq format!("plant/line_1/{}", iox_service_name.as_str()));
+            }
+            _ => test_fail!("expected an invalid key expression"),
+        }
+        assert_that!(tunnel.num_total_event_connections(), eq 0);
+    }
+
+    #[test]
+    fn zenoh_subscribers_of_tunneled_services_are_alive<S: Service>() {
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // Services
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.service_zenoh_subscriber_is_alive(iox_publish_subscribe_service.service_id()), eq None);
+        assert_that!(tunnel.service_zenoh_subscriber_is_alive(iox_event_service.service_id()), eq None);
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        assert_that!(tunnel.service_zenoh_subscriber_is_alive(iox_publish_subscribe_service.service_id()), eq Some(true));
+        assert_that!(tunnel.service_zenoh_subscriber_is_alive(iox_event_service.service_id()), eq Some(true));
+    }
+
+    #[test]
+    fn tunnel_can_be_restored_from_snapshot<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let snapshot = tunnel.snapshot();
+        assert_that!(snapshot, len 2);
+        drop(tunnel);
+
+        let mut restarted_tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        assert_that!(restarted_tunnel.restore_from_snapshot(snapshot.clone()), eq Ok(2));
+        assert_that!(restarted_tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(restarted_tunnel.num_total_event_connections(), eq 1);
+        assert_that!(
+            restarted_tunnel.service_discovery_source(iox_publish_subscribe_service.service_id()),
+            eq Some(Scope::Iceoryx)
+        );
+        assert_that!(
+            restarted_tunnel.service_discovery_source(iox_event_service.service_id()),
+            eq Some(Scope::Iceoryx)
+        );
+
+        // Services that are tunneled already are skipped
+        assert_that!(restarted_tunnel.restore_from_snapshot(snapshot), eq Ok(0));
+    }
+
+    #[test]
+    fn connections_that_cannot_be_recovered_are_recorded_as_failed<S: Service>() {
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+        const MAX_RETRIES: usize = 25;
+
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // Services
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_publish_subscribe_service
+            .publisher_builder()
+            .create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .event_id_max_value(1)
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // Remote events exceeding the maximum event id of the service fail to propagate
+        let z_key = tunnel
+            .zenoh_key_for_service_id(iox_event_service.service_id())
+            .unwrap();
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_notifier = z_session.declare_publisher(z_key).wait().unwrap();
+
+        // ==================== TEST =====================
+
+        let report = tunnel.propagate_with_error_recovery(3);
+        assert_that!(report.is_success(), eq true);
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+
+        // Busy connections are not reset
+        iox_publisher.send_copy(1).unwrap();
+        tunnel.set_iceoryx_receive_timeout(Some(Duration::ZERO));
+        let report = tunnel.propagate_with_error_recovery(0);
+        assert_that!(report.failures(), len 2);
+        for failure in report.failures() {
+            assert_that!(failure.error(), eq PropagationError::ReceiveTimeout);
+        }
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(tunnel.num_total_event_connections(), eq 1);
+        tunnel.set_iceoryx_receive_timeout(None);
+
+        // Failing connections are
+        retry(
+            || {
+                z_notifier
+                    .put(usize::MAX.to_ne_bytes().to_vec())
+                    .wait()
+                    .unwrap();
+                tunnel.propagate_with_error_recovery(0);
+                if tunnel.num_total_event_connections() != 0 {
+                    return Err("invalid event did not reach the tunnel");
+                }
+                Ok(())
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(tunnel.num_total_publish_subscribe_connections(), eq 1);
+        assert_that!(
+            tunnel.failed_services(),
+            contains iox_event_service.service_id().as_str().to_string()
+        );
+    }
+
+    #[test]
+    fn backpressure_policy_is_applied_when_rate_limit_is_exhausted<S: Service>() {
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            backpressure_policy: Some(BackpressurePolicy::ReturnError),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // Slow down the propagation to zenoh to a single payload per second
+        assert_that!(tunnel.set_rate_limit(iox_service.service_id(), Some(1)), eq true);
+
+        // ==================== TEST =====================
+
+        iox_publisher.send_copy(1).unwrap();
+        iox_publisher.send_copy(2).unwrap();
+
+        let report = tunnel.propagate();
+        assert_that!(report.propagated(), eq 1);
+        assert_that!(report.failures(), len 1);
+        assert_that!(report.failures()[0].error(), eq PropagationError::Backpressure);
+        assert_that!(tunnel.num_pending_payloads(iox_service.service_id()), eq Some(0));
+
+        // The payload that remained queued is kept pending instead
+        tunnel.set_backpressure_policy(BackpressurePolicy::DropOldest);
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 0);
+        assert_that!(tunnel.num_pending_payloads(iox_service.service_id()), eq Some(1));
+    }
+
+    #[test]
+    fn services_can_be_listed_without_tunneling_them<S: Service>() {
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // Services
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        let listed: Vec<String> = tunnel
+            .list_services(Scope::Iceoryx)
+            .unwrap()
+            .iter()
+            .map(|iox_service_config| iox_service_config.service_id().as_str().to_string())
+            .collect();
+        assert_that!(listed, len 2);
+        assert_that!(listed, contains iox_publish_subscribe_service.service_id().as_str().to_string());
+        assert_that!(listed, contains iox_event_service.service_id().as_str().to_string());
+        assert_that!(tunnel.tunneled_service_infos(), len 0);
+
+        assert_that!(tunnel.list_services(Scope::None).unwrap(), len 0);
+    }
+
+    #[test]
+    fn services_violating_the_schema_registry_are_rejected<S: Service>() {
+        struct TestSchemaRegistry(HashMap<String, TypeDescriptor>);
+
+        impl SchemaRegistry for TestSchemaRegistry {
+            fn lookup(&self, service_name: &str) -> Option<TypeDescriptor> {
+                self.0.get(service_name).cloned()
+            }
+        }
+
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // Services
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_conforming_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_mismatching_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u32>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 2);
+
+        // Both services are registered with the message types of the conforming service
+        let iox_conforming_service_config = tunnel
+            .list_services(Scope::Iceoryx)
+            .unwrap()
+            .into_iter()
+            .find(|iox_service_config| {
+                iox_service_config.service_id() == iox_conforming_service.service_id()
+            })
+            .unwrap();
+        let expected = TypeDescriptor::of(&iox_conforming_service_config).unwrap();
+        let mut registry = HashMap::new();
+        registry.insert(iox_conforming_service.name().to_string(), expected.clone());
+        registry.insert(iox_mismatching_service.name().to_string(), expected);
+
+        // ==================== TEST =====================
+
+        let result =
+            tunnel.enforce_schema_registry(std::sync::Arc::new(TestSchemaRegistry(registry)));
+        assert_that!(
+            result,
+            eq Err(SchemaEnforcementError::ServicesRejected {
+                mismatched: vec![iox_mismatching_service.name().to_string()],
+                unknown: vec![],
+            })
+        );
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            tunnel.failed_services(),
+            contains iox_mismatching_service.service_id().as_str().to_string()
+        );
+
+        // The rejected service is not connected again
+        tunnel.retry_failed_services();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+    }
+
+    #[test]
+    fn idle_connections_publish_keep_alives_when_enabled<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_publisher = iox_service.publisher_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_subscriber = z_session
+            .declare_subscriber(keys::publish_subscribe(iox_service.service_id()))
+            .wait()
+            .unwrap();
+        let receive_keep_alive = |tunnel: &mut Tunnel<S>, attempts: usize| {
+            for _ in 0..attempts {
+                tunnel.propagate();
+                if let Ok(Some(z_sample)) = z_subscriber.recv_timeout(TIME_BETWEEN_RETRIES) {
+                    return z_sample.payload().is_empty();
+                }
+            }
+            false
+        };
+
+        // === TEST ===
+        assert_that!(receive_keep_alive(&mut tunnel, 10), eq false);
+
+        tunnel.enable_connection_keep_alive(Some(Duration::ZERO));
+        assert_that!(receive_keep_alive(&mut tunnel, MAX_RETRIES), eq true);
+    }
+
+    #[test]
+    fn connection_for_service_returns_the_connection_of_the_service<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(services, len 1);
+
+        // ==================== TEST =====================
+
+        assert_that!(
+            tunnel.connection_for_service(iox_service.service_id()),
+            is_none
+        );
+
+        tunnel
+            .add_connection_for_service(&services[0], Scope::Iceoryx)
+            .unwrap();
+
+        let connection = tunnel
+            .connection_for_service(iox_service.service_id())
+            .unwrap();
+        assert_that!(connection.messaging_pattern(), eq MessagingPattern::Event);
+        assert_that!(connection.health(), eq ConnectionHealth::Healthy);
+        assert_that!(connection.statistics(), eq TunnelStatistics::default());
+    }
+
+    #[test]
+    fn history_is_replayed_to_remote_tunnels_subscribing_later_on<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+
+        // === SETUP ===
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            replay_history_on_connect: true,
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .history_size(3)
+            .subscriber_max_buffer_size(3)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+
+        // propagated before any remote tunnel subscribed
+        iox_publisher.send_copy(1).unwrap();
+        iox_publisher.send_copy(2).unwrap();
+        tunnel.propagate();
+
+        // === TEST ===
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_subscriber = z_session
+            .declare_subscriber(keys::publish_subscribe(iox_service.service_id()))
+            .wait()
+            .unwrap();
+
+        let mut replayed = vec![];
+        for _ in 0..MAX_RETRIES {
+            let z_replies = z_session
+                .get(keys::publish_subscribe_history(iox_service.service_id()))
+                .consolidation(zenoh::query::ConsolidationMode::None)
+                .wait()
+                .unwrap();
+            while let Ok(z_reply) = z_replies.recv() {
+                if let Ok(z_sample) = z_reply.result() {
+                    replayed.push(z_sample.payload().to_bytes().to_vec());
+                }
+            }
+            if !replayed.is_empty() {
+                break;
+            }
+            std::thread::sleep(TIME_BETWEEN_RETRIES);
+        }
+
+        assert_that!(replayed, len 2);
+        assert_that!(replayed[0], eq 1u64.to_ne_bytes().to_vec());
+        assert_that!(replayed[1], eq 2u64.to_ne_bytes().to_vec());
+
+        // the history is only served to the querying tunnel
+        tunnel.propagate();
+        assert_that!(
+            z_subscriber.recv_timeout(TIME_BETWEEN_RETRIES).unwrap(),
+            is_none
+        );
+    }
+
+    #[test]
+    fn standby_of_ha_pair_is_activated_once_the_primary_failed<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(100);
+
+        // === SETUP ===
+        let (primary, mut controller) = Tunnel::<S>::create_ha_pair(
+            &generate_isolated_config(),
+            &generate_isolated_config(),
+            &TunnelConfig::default(),
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        assert_that!(primary.is_global_propagation_enabled(), eq true);
+        assert_that!(controller.is_standby_active(), eq false);
+        assert_that!(controller.standby().is_global_propagation_enabled(), eq false);
+
+        let mut primary_is_alive = false;
+        for _ in 0..MAX_RETRIES {
+            primary_is_alive = controller.is_primary_alive();
+            if primary_is_alive {
+                break;
+            }
+            std::thread::sleep(TIME_BETWEEN_RETRIES);
+        }
+        assert_that!(primary_is_alive, eq true);
+
+        // === TEST ===
+        assert_that!(controller.activate_standby(), eq Err(FailoverError::PrimaryAlive));
+        assert_that!(controller.is_standby_active(), eq false);
+
+        drop(primary);
+        let mut result = Err(FailoverError::PrimaryAlive);
+        for _ in 0..MAX_RETRIES {
+            result = controller.activate_standby();
+            if result.is_ok() {
+                break;
+            }
+            std::thread::sleep(TIME_BETWEEN_RETRIES);
+        }
+        assert_that!(result, eq Ok(()));
+        assert_that!(controller.is_standby_active(), eq true);
+        assert_that!(controller.standby().is_global_propagation_enabled(), eq true);
+        assert_that!(controller.activate_standby(), eq Err(FailoverError::AlreadyActive));
+    }
+
+    #[cfg(not(feature = "strict-errors"))]
+    #[test]
+    fn discovery_error_identifies_the_service_whose_connection_failed<S: Service>() {
+        struct Registry {
+            services: Vec<StaticConfig>,
+        }
+
+        impl<S: Service> Discovery<S> for Registry {
+            fn discover<OnDiscovered: FnMut(&StaticConfig)>(
+                &mut self,
+                on_discovered: &mut OnDiscovered,
+            ) -> Result<(), DiscoveryError> {
+                for iox_service_config in self.services.drain(..) {
+                    on_discovered(&iox_service_config);
+                }
+                Ok(())
+            }
+        }
+
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let iox_service_name = mock_service_name();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+
+        // The configuration of a service whose payload type no longer matches the service
+        let iox_outdated_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        drop(iox_outdated_service);
+        let _iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u32>()
+            .open_or_create()
+            .unwrap();
+
+        let mut tunnel = Tunnel::<S>::create_with_custom_discovery(
+            &TunnelConfig::default(),
+            &iox_config,
+            &z_config,
+            Registry { services },
+        )
+        .unwrap();
+
+        // ==================== TEST =====================
+
+        let error = tunnel.discover(Scope::Iceoryx).unwrap_err();
+        assert_that!(error.to_string().contains(iox_service_name.as_str()), eq true);
+    }
+
+    #[test]
+    fn payloads_exceeding_the_latency_budget_are_dropped<S: Service>() {
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.service_propagation_latency_budget(iox_service.service_id()), eq None);
+        assert_that!(tunnel.service_expired_messages_count(iox_service.service_id()), eq Some(0));
+
+        // Payloads within the budget are propagated
+        let budget = Duration::from_secs(3600);
+        assert_that!(tunnel.set_service_latency_budget(iox_service.service_id(), budget), eq true);
+        assert_that!(tunnel.service_propagation_latency_budget(iox_service.service_id()), eq Some(budget));
+
+        iox_publisher.send_copy(1).unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.propagated(), eq 1);
+        assert_that!(tunnel.service_expired_messages_count(iox_service.service_id()), eq Some(0));
+
+        // Every payload takes longer than an empty budget
+        assert_that!(tunnel.set_service_latency_budget(iox_service.service_id(), Duration::ZERO), eq true);
+
+        iox_publisher.send_copy(2).unwrap();
+        iox_publisher.send_copy(3).unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 0);
+        assert_that!(tunnel.service_expired_messages_count(iox_service.service_id()), eq Some(2));
+
+        let iox_untunneled_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        assert_that!(tunnel.set_service_latency_budget(iox_untunneled_service.service_id(), budget), eq false);
+        assert_that!(tunnel.service_expired_messages_count(iox_untunneled_service.service_id()), eq None);
+    }
+
+    #[test]
+    fn flushing_event_connections_propagates_the_pending_batches<S: Service>() {
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            event_batching: Some(EventBatching {
+                batch_size: 16,
+                batch_timeout: Duration::from_millis(100),
+            }),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        // Notifier
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier = iox_service.notifier_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        assert_that!(tunnel.flush_event_connections(Duration::ZERO), eq Ok(0));
+
+        iox_notifier
+            .notify_with_custom_event_id(EventId::new(1))
+            .unwrap();
+        iox_notifier
+            .notify_with_custom_event_id(EventId::new(2))
+            .unwrap();
+        let report = tunnel.propagate();
+        assert_that!(report.propagated(), eq 0);
+        assert_that!(tunnel.pending_events(iox_service.service_id()), eq Some(2));
+        assert_that!(tunnel.num_pending_events(), eq 2);
+
+        // The batch is not due before its timeout
+        assert_that!(tunnel.flush_event_connections(Duration::ZERO), eq Err(FlushError::Timeout));
+        assert_that!(tunnel.num_pending_events(), eq 2);
+
+        // Nothing is flushed while the global propagation is disabled
+        tunnel.set_global_propagation_enabled(false);
+        assert_that!(tunnel.flush_event_connections(Duration::from_secs(5)), eq Ok(0));
+        assert_that!(tunnel.num_pending_events(), eq 2);
+        tunnel.set_global_propagation_enabled(true);
+
+        assert_that!(tunnel.flush_event_connections(Duration::from_secs(5)), eq Ok(2));
+        assert_that!(tunnel.pending_events(iox_service.service_id()), eq Some(0));
+        assert_that!(tunnel.num_pending_events(), eq 0);
+    }
+
+    #[test]
+    fn service_quality_score_rates_the_expired_payloads<S: Service>() {
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // Nothing was rated down yet
+        assert_that!(tunnel.service_quality_score(iox_service.service_id()), eq Some(1.0));
+
+        // Every payload exceeds an empty latency budget, which costs the latency weight
+        assert_that!(tunnel.set_service_latency_budget(iox_service.service_id(), Duration::ZERO), eq true);
+        iox_publisher.send_copy(1).unwrap();
+        iox_publisher.send_copy(2).unwrap();
+        tunnel.propagate();
+        assert_that!(tunnel.service_expired_messages_count(iox_service.service_id()), eq Some(2));
+
+        let config = QualityScoreConfig::default();
+        let expected_score = 1.0
+            - config.latency_weight
+                / (config.error_rate_weight
+                    + config.latency_weight
+                    + config.throughput_weight
+                    + config.missed_samples_weight);
+        let score = tunnel
+            .service_quality_score(iox_service.service_id())
+            .unwrap();
+        assert_that!((score - expected_score).abs(), lt 1e-9);
+
+        let iox_untunneled_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        assert_that!(tunnel.service_quality_score(iox_untunneled_service.service_id()), eq None);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compressed_payloads_exceeding_the_payload_size_are_rejected<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber = iox_service.subscriber_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // An LZ4 payload claiming to decompress to far more than the size of a u64
+        let mut z_payload = vec![1u8];
+        z_payload.extend_from_slice(&u32::MAX.to_le_bytes());
+        z_payload.extend_from_slice(&[0u8; 8]);
+
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_publisher = z_session
+            .declare_publisher(keys::publish_subscribe(iox_service.service_id()))
+            .wait()
+            .unwrap();
+        retry(
+            || {
+                z_publisher.put(z_payload.clone()).wait().unwrap();
+                if tunnel.propagate().is_success() {
+                    return Err("failed to reject oversized payload");
+                }
+                Ok(())
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(iox_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_payloads_exceeding_the_payload_size_are_rejected<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+        // A Zstandard frame decompressing to 1024 zero bytes
+        const ZSTD_FRAME: [u8; 18] = [
+            0x28, 0xb5, 0x2f, 0xfd, 0x00, 0x58, 0x4d, 0x00, 0x00, 0x10, 0x00, 0x00, 0x01, 0x00,
+            0xfb, 0x2b, 0x80, 0x05,
+        ];
+
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_subscriber = iox_service.subscriber_builder().create().unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // A Zstandard payload decompressing to far more than the size of a u64
+        let mut z_payload = vec![2u8];
+        z_payload.extend_from_slice(&ZSTD_FRAME);
+
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let z_publisher = z_session
+            .declare_publisher(keys::publish_subscribe(iox_service.service_id()))
+            .wait()
+            .unwrap();
+        retry(
+            || {
+                z_publisher.put(z_payload.clone()).wait().unwrap();
+                if tunnel.propagate().is_success() {
+                    return Err("failed to reject oversized payload");
+                }
+                Ok(())
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(iox_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn services_mapped_to_the_same_zenoh_key_collide<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            key_expr_mapper: Some(std::sync::Arc::new(|_: &StaticConfig| {
+                String::from("plant/line_1/sensors")
+            })),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service_1 = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_service_2 = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+
+        let tunneled_service_id = tunnel.tunneled_service_infos()[0].service_id.clone();
+        assert_that!(
+            tunnel.zenoh_key_for_service_id(&tunneled_service_id),
+            eq Some(String::from("plant/line_1/sensors"))
+        );
+    }
+
+    #[test]
+    fn propagate_or_fail_fast_reports_the_service_of_the_failed_connection<S: Service>() {
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            backpressure_policy: Some(BackpressurePolicy::ReturnError),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.set_rate_limit(iox_service.service_id(), Some(1)), eq true);
+
+        // ==================== TEST =====================
+
+        iox_publisher.send_copy(1).unwrap();
+        iox_publisher.send_copy(2).unwrap();
+
+        let result = tunnel.propagate_or_fail_fast();
+        assert_that!(
+            result,
+            eq Err(ServicePropagationError::PublishSubscribeFailed {
+                service_id: iox_service.service_id().clone(),
+                source: PropagationError::Backpressure,
+            })
+        );
+
+        let error = result.unwrap_err();
+        assert_that!(error.service_id(), eq iox_service.service_id());
+        assert_that!(error.error(), eq PropagationError::Backpressure);
+    }
+
+    #[test]
+    fn malformed_requests_of_remote_clients_are_rejected<S: Service>() {
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+        const MAX_RETRIES: usize = 25;
+
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+
+        // Server
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_server = iox_service.server_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        let z_key = tunnel
+            .zenoh_key_for_service_id(iox_service.service_id())
+            .unwrap();
+
+        // Remote client
+        let z_session = zenoh::open(zenoh::Config::default()).wait().unwrap();
+        let malformed_request = || z_session.get(&z_key).payload(vec![0u8; 3]).wait().unwrap();
+
+        // ==================== TEST =====================
+
+        let mut z_replies = malformed_request();
+        let mut is_rejected = false;
+        retry(
+            || {
+                assert_that!(tunnel.propagate().failures(), len 0);
+
+                match z_replies.recv_timeout(TIME_BETWEEN_RETRIES) {
+                    Ok(Some(z_reply)) => {
+                        is_rejected = z_reply.result().is_err();
+                        Ok(())
+                    }
+                    Ok(None) => Err("no reply to the malformed request"),
+                    Err(_) => {
+                        // The request was finalized before it reached the tunnel
+                        z_replies = malformed_request();
+                        Err("malformed request did not reach the tunnel")
+                    }
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        assert_that!(is_rejected, eq true);
+        assert_that!(iox_server.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn health_check_includes_request_response_connections<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let health = tunnel.health_check();
+        assert_that!(health, len 1);
+        assert_that!(health.get(iox_service.service_id()), eq Some(&ConnectionHealth::Healthy));
+        assert_that!(tunnel.is_healthy(), eq true);
+    }
+
+    #[test]
+    fn payloads_are_propagated_reliably_unless_derived_from_the_service<S: Service>() {
+        // ==================== SETUP ====================
+
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let mut tunnel =
+            Tunnel::<S>::create(&TunnelConfig::default(), &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .open_or_create()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        let qos = tunnel
+            .service_effective_qos(iox_service.service_id())
+            .unwrap();
+        assert_that!(qos.priority, eq zenoh::qos::Priority::Data);
+        assert_that!(qos.reliability, eq zenoh::qos::Reliability::Reliable);
+        assert_that!(qos.congestion_control, eq zenoh::qos::CongestionControl::Block);
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        let iox_service_config = services
+            .iter()
+            .find(|service| service.service_id() == iox_service.service_id())
+            .unwrap();
+        assert_that!(
+            QosMapping::FromService.publisher_options(iox_service_config),
+            eq iox_qos_to_zenoh_publisher_options(iox_service_config)
+        );
+        assert_that!(
+            QosMapping::FromService
+                .publisher_options(iox_service_config)
+                .reliability,
+            eq zenoh::qos::Reliability::BestEffort
+        );
+    }
+
+    #[test]
+    fn add_connection_for_service_returns_why_a_service_is_not_connected<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_service_1 = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let _iox_service_2 = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let mut services = vec![];
+        S::list(&iox_config, |iox_service_details| {
+            services.push(iox_service_details.static_details);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(services, len 2);
+
+        let colliding_config = TunnelConfig {
+            key_expr_mapper: Some(std::sync::Arc::new(|_: &StaticConfig| {
+                String::from("plant/line_1/sensors")
+            })),
+            ..Default::default()
+        };
+        let mut colliding_tunnel =
+            Tunnel::<S>::create(&colliding_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        let invalid_config = TunnelConfig {
+            key_expr_mapper: Some(std::sync::Arc::new(|_: &StaticConfig| {
+                String::from("plant//sensors")
+            })),
+            ..Default::default()
+        };
+        let mut invalid_tunnel =
+            Tunnel::<S>::create(&invalid_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        // ==================== TEST =====================
+
+        let result = colliding_tunnel.add_connection_for_service(&services[0], Scope::Iceoryx);
+        assert_that!(result, eq Ok(()));
+        let result = colliding_tunnel.add_connection_for_service(&services[1], Scope::Iceoryx);
+        assert_that!(result, eq Err(ConnectionCreationError::KeyExpressionCollision));
+        assert_that!(colliding_tunnel.tunneled_service_infos(), len 1);
+
+        let result = invalid_tunnel.add_connection_for_service(&services[0], Scope::Iceoryx);
+        assert_that!(result, eq Err(ConnectionCreationError::InvalidKeyExpression));
+        assert_that!(invalid_tunnel.tunneled_service_infos(), len 0);
+    }
+
+    #[test]
+    fn pending_remote_services_that_vanished_are_not_connected<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = Tunnel::<S>::create(
+            &TunnelConfig::default(),
+            &iox_config_a,
+            &zenoh::Config::default(),
+        )
+        .unwrap();
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        let tunnel_config_b = TunnelConfig {
+            max_connections: Some(0),
+            service_name_allowlist: Some(vec![iox_service_name.to_string()]),
+            ..Default::default()
+        };
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &zenoh::Config::default())
+                .unwrap();
+
+        // ==================== TEST =====================
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b.num_pending_connections() == 1 {
+                    return Ok(());
+                }
+                Err("failed to queue remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        drop(tunnel_a);
+        drop(iox_service_a);
+
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                if tunnel_b.num_pending_connections() == 0 {
+                    return Ok(());
+                }
+                Err("failed to prune vanished remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(tunnel_b.tunneled_service_infos(), len 0);
+    }
+
+    #[test]
+    fn backpressure_policy_determines_zenoh_congestion_control<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let congestion_control_with = |backpressure_policy| {
+            let tunnel_config = TunnelConfig {
+                backpressure_policy,
+                ..Default::default()
+            };
+            let mut tunnel =
+                Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default())
+                    .unwrap();
+            tunnel.discover(Scope::Iceoryx).unwrap();
+            tunnel
+                .service_effective_qos(iox_service.service_id())
+                .unwrap()
+                .congestion_control
+        };
+
+        // ==================== TEST =====================
+
+        assert_that!(
+            congestion_control_with(None),
+            eq zenoh::qos::CongestionControl::Block
+        );
+        assert_that!(
+            congestion_control_with(Some(BackpressurePolicy::DropNewest)),
+            eq zenoh::qos::CongestionControl::Drop
+        );
+        assert_that!(
+            congestion_control_with(Some(BackpressurePolicy::Block {
+                timeout: Duration::from_secs(1)
+            })),
+            eq zenoh::qos::CongestionControl::Block
+        );
+    }
+
+    #[test]
+    fn blocking_backpressure_policy_defers_payloads_without_stalling_the_tunnel<S: Service>() {
+        const MAX_RETRIES: usize = 25;
+        const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+        // ==================== SETUP ====================
+
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            backpressure_policy: Some(BackpressurePolicy::Block {
+                timeout: Duration::from_secs(60),
+            }),
+            ..Default::default()
+        };
+        let mut tunnel =
+            Tunnel::<S>::create(&tunnel_config, &iox_config, &zenoh::Config::default()).unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // Slow down the propagation to zenoh to a single payload per second
+        assert_that!(tunnel.set_rate_limit(iox_service.service_id(), Some(1)), eq true);
+
+        // ==================== TEST =====================
+
+        iox_publisher.send_copy(1).unwrap();
+        iox_publisher.send_copy(2).unwrap();
+
+        let start = std::time::Instant::now();
+        let report = tunnel.propagate();
+        assert_that!(start.elapsed() < Duration::from_secs(1), eq true);
+        assert_that!(report.is_success(), eq true);
+        assert_that!(report.propagated(), eq 1);
+
+        // The deferred payload is propagated once the rate limit permits
+        retry(
+            || {
+                let report = tunnel.propagate();
+                assert_that!(report.is_success(), eq true);
+                if report.propagated() == 1 {
+                    return Ok(());
+                }
+                Err("The deferred payload was not propagated yet")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn services_recreated_with_a_different_pattern_replace_their_connection<S: Service>() {
+        // ==================== SETUP ====================
+
+        let iox_config = generate_isolated_config();
+        let z_config = zenoh::Config::default();
+        let tunnel_config = TunnelConfig {
+            discovery_service: Some("iox2://test/discovery/services/".into()),
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_discovery_publisher = iox_node
+            .service_builder(&ServiceName::new("iox2://test/discovery/services/").unwrap())
+            .publish_subscribe::<DiscoveryUpdate>()
+            .open_or_create()
+            .unwrap()
+            .publisher_builder()
+            .create()
+            .unwrap();
+        let iox_service_name = mock_service_name();
+        let iox_publish_subscribe_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let iox_sample = iox_discovery_publisher.loan_uninit().unwrap();
+        iox_sample
+            .write_payload(DiscoveryUpdate::Added(
+                iox_publish_subscribe_service.static_config().clone(),
+            ))
+            .send()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+
+        // ==================== TEST =====================
+
+        // The re-created service has a different id as the id covers the messaging pattern
+        let iox_event_service = iox_node
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        assert_that!(
+            iox_event_service.service_id() != iox_publish_subscribe_service.service_id(),
+            eq true
+        );
+
+        let iox_sample = iox_discovery_publisher.loan_uninit().unwrap();
+        iox_sample
+            .write_payload(DiscoveryUpdate::Removed(
+                iox_publish_subscribe_service.static_config().clone(),
+            ))
+            .send()
+            .unwrap();
+        let iox_sample = iox_discovery_publisher.loan_uninit().unwrap();
+        iox_sample
+            .write_payload(DiscoveryUpdate::Added(
+                iox_event_service.static_config().clone(),
+            ))
+            .send()
+            .unwrap();
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        assert_that!(tunnel.tunneled_service_infos(), len 1);
+        assert_that!(
+            tunnel.zenoh_key_for_service_id(iox_publish_subscribe_service.service_id()),
+            is_none
+        );
+        let connection = tunnel
+            .connection_for_service(iox_event_service.service_id())
+            .unwrap();
+        assert_that!(connection.messaging_pattern(), eq MessagingPattern::Event);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 